@@ -66,7 +66,7 @@ fn main() {
         }
 
         // Convert the graph into a buffer.
-        graph.triangulate();
+        graph.triangulate().unwrap();
         graph
             .to_mesh_by_face_with(|face, vertex| Vertex {
                 position: vertex.position().into_homogeneous().into(),
@@ -0,0 +1,41 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use decorum::R64;
+use nalgebra::Point3;
+use plexus::graph::MeshGraph;
+use plexus::index::HashIndexer;
+use plexus::prelude::*;
+use plexus::primitive::generate::Position;
+use plexus::primitive::sphere::UvSphere;
+use plexus::primitive::Trigon;
+
+type E3 = Point3<R64>;
+
+fn buffers() -> (Vec<Trigon<usize>>, Vec<E3>) {
+    UvSphere::new(256, 256)
+        .polygons::<Position<E3>>()
+        .triangulate()
+        .index_vertices::<Trigon<usize>, _>(HashIndexer::default())
+}
+
+fn construction(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("from_raw_buffers");
+    group.sample_size(10);
+    group.bench_function("checked", |bencher| {
+        bencher.iter_batched(
+            buffers,
+            |(indices, vertices)| MeshGraph::<E3>::from_raw_buffers(indices, vertices).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("unchecked", |bencher| {
+        bencher.iter_batched(
+            buffers,
+            |(indices, vertices)| MeshGraph::<E3>::from_raw_buffers_unchecked(indices, vertices),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, construction);
+criterion_main!(benches);
@@ -88,17 +88,22 @@ mod builder;
 
 use itertools::Itertools;
 use num::{Integer, NumCast, Unsigned};
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::iter::FromIterator;
 use std::vec;
 use theon::adjunct::{FromItems, Map};
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use theon::{AsPosition, Position};
 use thiserror::Error;
 use typenum::{self, NonZero, Unsigned as _, U3, U4};
 
 use crate::buffer::builder::BufferBuilder;
 use crate::builder::{Buildable, MeshBuilder};
 use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
+use crate::geometry::{bound, hull};
 use crate::geometry::{FromGeometry, IntoGeometry};
 use crate::index::{
     BufferOf, Flat, Flat3, Flat4, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexOf,
@@ -150,6 +155,9 @@ pub enum BufferError {
         /// The incompatible arity that was encountered.
         actual: usize,
     },
+    /// Geometry is incompatible or cannot be computed.
+    #[error("geometric operation failed")]
+    Geometry,
 }
 
 /// Triangular [`MeshBuffer`].
@@ -199,6 +207,30 @@ pub trait FromRawBuffers<N, G>: Sized {
         J: IntoIterator<Item = G>;
 }
 
+/// Conversion from [`MeshBuffer`]s.
+///
+/// Unlike conversions via `TryFrom`, this conversion borrows the
+/// [`MeshBuffer`] and reads its index and vertex buffers directly.
+///
+/// [`MeshBuffer`]: crate::buffer::MeshBuffer
+pub trait FromMeshBuffer<R, G>: Sized
+where
+    R: Grouping,
+{
+    type Error: Debug;
+
+    /// Creates a type from a [`MeshBuffer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the implementor cannot be constructed from the
+    /// buffer. This typically occurs if the topology of the buffer is
+    /// unsupported.
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    fn from_mesh_buffer(buffer: &MeshBuffer<R, G>) -> Result<Self, Self::Error>;
+}
+
 /// Conversion from raw buffers that do not encode their arity.
 pub trait FromRawBuffersWithArity<N, G>: Sized {
     type Error: Debug;
@@ -343,6 +375,75 @@ where
         }
     }
 
+    /// Computes the convex hull of the vertex positions in a `MeshBuffer`.
+    ///
+    /// Returns a triangular `MeshBuffer` that encloses the positions of the
+    /// vertices in the buffer. The triangles of the hull are consistently wound
+    /// such that their normals point outward. Only the vertex data that forms
+    /// the hull is copied into the resulting buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the buffer has fewer than four non-coplanar vertices
+    /// or if indices into the hull overflow.
+    pub fn convex_hull<N>(&self) -> Result<MeshBuffer3<N, G>, BufferError>
+    where
+        N: Copy + Integer + NumCast + Unsigned,
+        G: AsPosition + Clone,
+        Position<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<Position<G>>: Cross<Output = Vector<Position<G>>> + InnerSpace,
+    {
+        let positions = self
+            .vertices
+            .iter()
+            .map(|vertex| *vertex.as_position())
+            .collect::<Vec<_>>();
+        let triangles = hull::convex_hull(&positions).ok_or_else(|| BufferError::Geometry)?;
+        let mut vertices = vec![];
+        let mut indices = HashMap::new();
+        let triangles = triangles
+            .into_iter()
+            .map(|triangle| {
+                let mut index = |index: usize| {
+                    let n = indices.len();
+                    let n = *indices.entry(index).or_insert_with(|| {
+                        vertices.push(self.vertices[index].clone());
+                        n
+                    });
+                    <N as NumCast>::from(n).ok_or_else(|| BufferError::IndexOverflow)
+                };
+                Ok(Trigon::new(
+                    index(triangle[0])?,
+                    index(triangle[1])?,
+                    index(triangle[2])?,
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(MeshBuffer {
+            indices: triangles,
+            vertices,
+        })
+    }
+
+    /// Gets a bounding sphere that encloses the vertex positions in a
+    /// `MeshBuffer`.
+    ///
+    /// Returns the center and radius of the sphere. The sphere is approximate
+    /// and may be somewhat larger than the minimal enclosing sphere. Returns
+    /// `None` if the buffer has no vertices.
+    pub fn bounding_sphere(&self) -> Option<(Position<G>, Scalar<Position<G>>)>
+    where
+        G: AsPosition,
+        Position<G>: EuclideanSpace,
+    {
+        let positions = self
+            .vertices
+            .iter()
+            .map(|vertex| *vertex.as_position())
+            .collect::<Vec<_>>();
+        bound::bounding_sphere(&positions)
+    }
+
     /// Gets a slice over the index data.
     pub fn as_index_slice(&self) -> &[R::Group] {
         self.indices.as_slice()
@@ -541,6 +642,70 @@ where
         );
         Ok(())
     }
+
+    /// Removes duplicate vertices from a structured `MeshBuffer`.
+    ///
+    /// Vertices are indexed with a [`HashIndexer`] and the index buffer is
+    /// rewritten to refer to the first occurrence of equivalent vertex data.
+    /// Vertices are compared exactly; no tolerance is used. This is useful
+    /// after appending buffers that share vertex data.
+    ///
+    /// Returns the number of vertices that have been removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::N64;
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBuffer4;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<N64>;
+    ///
+    /// let mut buffer: MeshBuffer4<usize, E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// buffer
+    ///     .append(&mut Cube::new().polygons::<Position<E3>>().collect::<MeshBuffer4<usize, E3>>())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(8, buffer.deduplicate());
+    /// assert_eq!(8, buffer.as_vertex_slice().len());
+    /// ```
+    ///
+    /// [`HashIndexer`]: crate::index::HashIndexer
+    pub fn deduplicate(&mut self) -> usize
+    where
+        G: Clone + Eq + Hash,
+        <P as Grouping>::Group: Topological<Vertex = P::Vertex>,
+    {
+        let n = self.vertices.len();
+        let mut indexer = HashIndexer::<UnboundedPolygon<G>, G>::default();
+        let mut vertices = Vec::with_capacity(n);
+        let indices = self
+            .vertices
+            .drain(..)
+            .map(|vertex| {
+                let (index, vertex) = indexer.index(vertex, |vertex| vertex);
+                if let Some(vertex) = vertex {
+                    vertices.push(vertex);
+                }
+                index
+            })
+            .collect::<Vec<_>>();
+        for topology in self.indices.iter_mut() {
+            for index in topology.as_mut() {
+                let source = <usize as NumCast>::from(*index).expect("index overflow");
+                *index = <P::Vertex as NumCast>::from(indices[source]).expect("index overflow");
+            }
+        }
+        self.vertices = vertices;
+        n - self.vertices.len()
+    }
 }
 
 impl<P, Q, T, R, N, G> From<P> for MeshBuffer<R, G>
@@ -1207,6 +1372,27 @@ mod tests {
         assert_eq!(13, buffer.as_vertex_slice().len());
     }
 
+    #[test]
+    fn deduplicate_appended_buffers() {
+        let mut buffer: MeshBuffer4<usize, E3> = Cube::new().polygons::<Position<E3>>().collect();
+        buffer
+            .append(
+                &mut Cube::new()
+                    .polygons::<Position<E3>>()
+                    .collect::<MeshBuffer4<usize, E3>>(),
+            )
+            .unwrap();
+
+        assert_eq!(16, buffer.as_vertex_slice().len());
+        assert_eq!(8, buffer.deduplicate());
+        assert_eq!(12, buffer.as_index_slice().len());
+        assert_eq!(8, buffer.as_vertex_slice().len());
+        let (head, tail) = buffer.as_index_slice().split_at(6);
+        assert_eq!(head, tail);
+        // Deduplication is idempotent.
+        assert_eq!(0, buffer.deduplicate());
+    }
+
     #[test]
     fn convert_mesh_to_buffer_by_vertex() {
         let graph: MeshGraph<E3> = UvSphere::new(3, 2)
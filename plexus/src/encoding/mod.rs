@@ -7,19 +7,158 @@
 //!
 //! | Feature        | Default | Encoding | Read | Write |
 //! |----------------|---------|----------|------|-------|
+//! | `encoding-obj` | No      | [OBJ]    | Yes  | Yes   |
 //! | `encoding-ply` | No      | [PLY]    | Yes  | No    |
 //!
+//! Vertex colors are supported by some encodings as an optional attribute.
+//! Vertex data that implements [`AsColor`] can be written with colors and
+//! decoders like `ColorEncoding` read colors along with positions as a
+//! [`Color`].
+//! Colors can also be computed from scalar fields via a [`ColorRamp`], such as
+//! by [`MeshGraph::colorize_by`].
+//!
 //! This module provides traits used by all encodings. These traits describe the
 //! outputs and inputs of decoders and encoders, respectively. Generally, these
 //! traits should **not** be used directly. Instead, prefer the conversion
 //! traits exposed for specific encodings, such as `FromPly` when using [PLY].
 //!
+//! [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
 //! [PLY]: https://en.wikipedia.org/wiki/ply_(file_format)
+//!
+//! [`AsColor`]: crate::encoding::AsColor
+//! [`Color`]: crate::encoding::Color
+//! [`ColorRamp`]: crate::encoding::ColorRamp
+//! [`MeshGraph::colorize_by`]: crate::graph::MeshGraph::colorize_by
 
+pub mod obj;
 pub mod ply;
 
 use std::fmt::Debug;
 
+/// Color of a vertex.
+///
+/// Components are red, green, blue, and alpha in that order and are in the
+/// interval $[0,1]$. The default color is opaque white, which is used when an
+/// encoding does not provide a color for a vertex.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color(pub [f64; 4]);
+
+impl Color {
+    pub fn from_rgb(red: f64, green: f64, blue: f64) -> Self {
+        Color([red, green, blue, 1.0])
+    }
+
+    pub fn into_rgb(self) -> [f64; 3] {
+        let Color([red, green, blue, _]) = self;
+        [red, green, blue]
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Color([1.0; 4])
+    }
+}
+
+/// Vertex data that exposes a [`Color`].
+///
+/// [`Color`]: crate::encoding::Color
+pub trait AsColor {
+    fn as_color(&self) -> Color;
+}
+
+impl AsColor for Color {
+    fn as_color(&self) -> Color {
+        *self
+    }
+}
+
+/// Data that exposes a mutable [`Color`].
+///
+/// [`Color`]: crate::encoding::Color
+pub trait AsColorMut: AsColor {
+    fn as_color_mut(&mut self) -> &mut Color;
+}
+
+impl AsColorMut for Color {
+    fn as_color_mut(&mut self) -> &mut Color {
+        self
+    }
+}
+
+/// Gradient of [`Color`]s that maps scalars in the interval $[0,1]$ to colors.
+///
+/// Colors are spaced evenly over the interval and are interpolated linearly.
+/// The default ramp is a gradient from black to white.
+///
+/// [`Color`]: crate::encoding::Color
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorRamp {
+    colors: Vec<Color>,
+}
+
+impl ColorRamp {
+    /// Creates a ramp from colors that are spaced evenly from zero to one.
+    ///
+    /// Returns `None` if no colors are given.
+    pub fn new<I>(colors: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Color>,
+    {
+        let colors = colors.into_iter().collect::<Vec<_>>();
+        if colors.is_empty() {
+            None
+        }
+        else {
+            Some(ColorRamp { colors })
+        }
+    }
+
+    /// Creates a ramp from blue through green to red.
+    pub fn heat() -> Self {
+        ColorRamp {
+            colors: vec![
+                Color::from_rgb(0.0, 0.0, 1.0),
+                Color::from_rgb(0.0, 1.0, 0.0),
+                Color::from_rgb(1.0, 0.0, 0.0),
+            ],
+        }
+    }
+
+    /// Gets the color at the given scalar.
+    ///
+    /// The scalar is clamped to the interval $[0,1]$. `NaN` maps to the first
+    /// color of the ramp.
+    pub fn sample(&self, t: f64) -> Color {
+        let n = self.colors.len() - 1;
+        if n == 0 {
+            return self.colors[0];
+        }
+        // `NaN` fails this comparison and so is clamped to zero.
+        let t = if t > 0.0 { t.min(1.0) } else { 0.0 } * (n as f64);
+        let index = (t.floor() as usize).min(n - 1);
+        let t = t - (index as f64);
+        let Color(a) = self.colors[index];
+        let Color(b) = self.colors[index + 1];
+        let mut color = [0.0; 4];
+        for (component, (a, b)) in color.iter_mut().zip(a.iter().zip(b.iter())) {
+            *component = a + ((b - a) * t);
+        }
+        Color(color)
+    }
+}
+
+impl Default for ColorRamp {
+    fn default() -> Self {
+        ColorRamp {
+            colors: vec![
+                Color::from_rgb(0.0, 0.0, 0.0),
+                Color::from_rgb(1.0, 1.0, 1.0),
+            ],
+        }
+    }
+}
+
 pub trait VertexDecoder {
     type Output: IntoIterator<Item = Self::Vertex>;
     type Vertex;
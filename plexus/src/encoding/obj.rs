@@ -0,0 +1,1018 @@
+//! [OBJ] encoding.
+//!
+//! This module provides support for the [OBJ] format via the [`FromObj`] and
+//! [`ToObj`] traits. These traits can be used with a decoder to read and write
+//! mesh data structures to and from the [OBJ] format.
+//!
+//! Faces may be assigned materials via `usemtl` statements. Because graph data
+//! must be `Copy`, materials are represented by an index into the material
+//! names of an [OBJ] rather than the names themselves. Face data that
+//! implements [`AsMaterial`] is grouped by material when written and face data
+//! that implements [`FromGeometry`]`<`[`Material`]`>` receives the active
+//! material of each face when read, so material assignments are preserved
+//! across a round trip.
+//!
+//! Texture coordinates and normals of face corners can be written via
+//! [`ToObj::write_obj_with`], but are ignored when read.
+//! Vertex colors are written via [`ToObj::write_obj_with_colors`] using the
+//! common (but non-standard) `v x y z r g b` extension and are read via
+//! [`ColorEncoding`].
+//! Very large meshes can be written with bounded buffering via
+//! [`ToObj::write_obj_streaming`].
+//!
+//! # Examples
+//!
+//! Reading an [OBJ] file into a [`MeshGraph`] and writing it back out with its
+//! materials:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::encoding::obj::{FromObj, Material, PositionEncoding, ToObj};
+//! use plexus::graph::{GraphData, MeshGraph};
+//!
+//! type E3 = Point3<f64>;
+//!
+//! pub struct Data;
+//!
+//! impl GraphData for Data {
+//!     type Vertex = E3;
+//!     type Arc = ();
+//!     type Edge = ();
+//!     type Face = Material;
+//! }
+//!
+//! let obj: &[u8] = b"
+//!     v 0 0 0
+//!     v 1 0 0
+//!     v 1 1 0
+//!     v 0 1 0
+//!     usemtl stone
+//!     f 1 2 3 4
+//! ";
+//! let (graph, obj) =
+//!     MeshGraph::<Data>::from_obj_reader(PositionEncoding::<E3>::default(), obj).unwrap();
+//!
+//! let mut buffer = Vec::new();
+//! graph.write_obj(&obj.materials, &mut buffer).unwrap();
+//! ```
+//!
+//! [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+//!
+//! [`AsMaterial`]: crate::encoding::obj::AsMaterial
+//! [`ColorEncoding`]: crate::encoding::obj::ColorEncoding
+//! [`FromObj`]: crate::encoding::obj::FromObj
+//! [`Material`]: crate::encoding::obj::Material
+//! [`ToObj`]: crate::encoding::obj::ToObj
+//! [`ToObj::write_obj_streaming`]: crate::encoding::obj::ToObj::write_obj_streaming
+//! [`ToObj::write_obj_with`]: crate::encoding::obj::ToObj::write_obj_with
+//! [`ToObj::write_obj_with_colors`]: crate::encoding::obj::ToObj::write_obj_with_colors
+//! [`FromGeometry`]: crate::geometry::FromGeometry
+//! [`MeshGraph`]: crate::graph::MeshGraph
+
+#![cfg(feature = "encoding-obj")]
+
+use num::cast;
+use num::NumCast;
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::marker::PhantomData;
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use theon::AsPosition;
+use typenum::{NonZero, Unsigned, U2, U3};
+
+use crate::encoding::{AsColor, Color, FaceDecoder, FromEncoding, VertexDecoder};
+use crate::geometry::UnitGeometry;
+use crate::graph::{ArcKey, GraphData, GraphError, MeshGraph, VertexKey};
+
+/// Data read from an [OBJ] that is not part of a mesh.
+///
+/// Statements that are not recognized are ignored, such as texture
+/// coordinates, normals, and smoothing groups.
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+pub struct Obj {
+    /// Names of the materials used by faces in order of first use.
+    ///
+    /// [`Material`]s index into these names.
+    ///
+    /// [`Material`]: crate::encoding::obj::Material
+    pub materials: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum ObjError {
+    Parse { line: usize },
+    MaterialNotFound,
+    Encoding,
+    Io(io::Error),
+}
+
+impl From<io::Error> for ObjError {
+    fn from(error: io::Error) -> Self {
+        ObjError::Io(error)
+    }
+}
+
+impl From<GraphError> for ObjError {
+    fn from(_: GraphError) -> Self {
+        ObjError::Encoding
+    }
+}
+
+/// Material of a face.
+///
+/// Materials are an index into the material names of an [OBJ] (see
+/// [`Obj::materials`]) or `None` if a face has not been assigned a material.
+/// `Material` can be elided into `()`, so face data need not support materials
+/// when reading an [OBJ].
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+///
+/// [`Obj::materials`]: crate::encoding::obj::Obj::materials
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Material(pub Option<usize>);
+
+impl UnitGeometry for Material {}
+
+/// Face data that exposes a [`Material`].
+///
+/// [`Material`]: crate::encoding::obj::Material
+pub trait AsMaterial {
+    fn as_material(&self) -> Material;
+}
+
+impl AsMaterial for () {
+    fn as_material(&self) -> Material {
+        Material(None)
+    }
+}
+
+impl AsMaterial for Material {
+    fn as_material(&self) -> Material {
+        *self
+    }
+}
+
+pub trait FromObj<E>: Sized {
+    fn from_obj_reader<R>(decoder: E, read: R) -> Result<(Self, Obj), ObjError>
+    where
+        R: BufRead;
+}
+
+impl<T, P, N> FromObj<PositionEncoding<P>> for T
+where
+    T: FromEncoding<PositionEncoding<P>>,
+    ObjError: From<<T as FromEncoding<PositionEncoding<P>>>::Error>,
+    P: DecodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+{
+    fn from_obj_reader<R>(_: PositionEncoding<P>, read: R) -> Result<(Self, Obj), ObjError>
+    where
+        R: BufRead,
+    {
+        let (vertices, faces, obj) = read_obj(read, P::decode_position)?;
+        let mesh = T::from_encoding(vertices, faces)?;
+        Ok((mesh, obj))
+    }
+}
+
+impl<T, P, N> FromObj<ColorEncoding<P>> for T
+where
+    T: FromEncoding<ColorEncoding<P>>,
+    ObjError: From<<T as FromEncoding<ColorEncoding<P>>>::Error>,
+    P: DecodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+{
+    fn from_obj_reader<R>(_: ColorEncoding<P>, read: R) -> Result<(Self, Obj), ObjError>
+    where
+        R: BufRead,
+    {
+        let (vertices, faces, obj) = read_obj(read, |coordinates| {
+            // Colors follow three coordinates. Vertices without a color are
+            // assigned the default color.
+            let color = match *coordinates {
+                [_, _, _, red, green, blue] => Color::from_rgb(red, green, blue),
+                _ => Color::default(),
+            };
+            P::decode_position(coordinates).map(|position| (position, color))
+        })?;
+        let mesh = T::from_encoding(vertices, faces)?;
+        Ok((mesh, obj))
+    }
+}
+
+/// Attributes of a face corner written to an [OBJ].
+///
+/// Texture coordinates and normals are written as `vt` and `vn` statements,
+/// respectively. Equal attributes are written once and shared by the corners
+/// that use them. Attributes that are `None` are not written for a corner.
+///
+/// See [`ToObj::write_obj_with`].
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+///
+/// [`ToObj::write_obj_with`]: crate::encoding::obj::ToObj::write_obj_with
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CornerAttributes {
+    pub uv: Option<[f64; 2]>,
+    pub normal: Option<[f64; 3]>,
+}
+
+pub trait ToObj {
+    /// Vertex data of the mesh.
+    ///
+    /// Vertex colors can be written if this type implements [`AsColor`].
+    ///
+    /// [`AsColor`]: crate::encoding::AsColor
+    type Vertex;
+
+    /// Identifies a face corner when writing corner attributes.
+    ///
+    /// For [`MeshGraph`]s, this is the key of an arc, which identifies its
+    /// source vertex within its face (see [`ArcView::corner_data`]).
+    ///
+    /// [`ArcView::corner_data`]: crate::graph::ArcView::corner_data
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    type Corner;
+
+    /// Writes an [OBJ] to the given writer.
+    ///
+    /// Faces are grouped by material. Each group is preceded by `g` and
+    /// `usemtl` statements naming the material of its faces. Faces without a
+    /// material are written first and are not grouped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face has a material that is not in `materials` or
+    /// writing fails.
+    ///
+    /// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+    fn write_obj<T, W>(&self, materials: &[T], write: W) -> Result<(), ObjError>
+    where
+        T: AsRef<str>,
+        W: Write,
+    {
+        self.write_obj_with(materials, write, |_| CornerAttributes::default())
+    }
+
+    /// Writes an [OBJ] with texture coordinates and normals to the given
+    /// writer.
+    ///
+    /// The given function is called for each corner of each face and returns
+    /// the texture coordinates and normal of that corner. Positions, texture
+    /// coordinates, and normals are indexed independently, so each distinct
+    /// texture coordinate and normal is written once and faces refer to them
+    /// via `f v/vt/vn` statements. See [`write_obj`] for how faces are grouped
+    /// by material.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face has a material that is not in `materials` or
+    /// writing fails.
+    ///
+    /// # Examples
+    ///
+    /// Writing the arc data of a [`MeshGraph`] as normals:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::encoding::obj::{CornerAttributes, ToObj};
+    /// use plexus::graph::{GraphData, MeshGraph};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// pub struct Data;
+    ///
+    /// impl GraphData for Data {
+    ///     type Vertex = E3;
+    ///     type Arc = [f64; 3];
+    ///     type Edge = ();
+    ///     type Face = ();
+    /// }
+    ///
+    /// let graph: MeshGraph<Data> = Cube::new().polygons::<Position<E3>>().collect();
+    ///
+    /// let mut buffer = Vec::new();
+    /// graph
+    ///     .write_obj_with(&[] as &[&str], &mut buffer, |key| CornerAttributes {
+    ///         normal: graph.arc(key).unwrap().corner_data().cloned(),
+    ///         ..Default::default()
+    ///     })
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+    ///
+    /// [`write_obj`]: crate::encoding::obj::ToObj::write_obj
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    fn write_obj_with<T, W, F>(&self, materials: &[T], write: W, f: F) -> Result<(), ObjError>
+    where
+        T: AsRef<str>,
+        W: Write,
+        F: FnMut(Self::Corner) -> CornerAttributes;
+
+    /// Writes an [OBJ] with vertex colors to the given writer.
+    ///
+    /// Colors are written after the coordinates of each `v` statement as red,
+    /// green, and blue components. This is a common extension of [OBJ] that is
+    /// not part of the format, so some readers may not support it. Alpha is
+    /// not written. See [`write_obj`] for how faces are grouped by material.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face has a material that is not in `materials` or
+    /// writing fails.
+    ///
+    /// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+    ///
+    /// [`write_obj`]: crate::encoding::obj::ToObj::write_obj
+    fn write_obj_with_colors<T, W>(&self, materials: &[T], write: W) -> Result<(), ObjError>
+    where
+        Self::Vertex: AsColor,
+        T: AsRef<str>,
+        W: Write;
+
+    /// Writes an [OBJ] to the given writer in a single pass over vertices
+    /// followed by a single pass over faces.
+    ///
+    /// Unlike [`write_obj`], faces are not fully grouped by material. Faces
+    /// without a material are written first and the remaining faces are
+    /// written in iteration order, with `g` and `usemtl` statements written
+    /// whenever the material changes between consecutive faces. No faces are
+    /// buffered, so the only memory used in proportion to the size of the mesh
+    /// is a map from vertices to their indices in the [OBJ]. Corner attributes
+    /// are not written. The writer is flushed periodically, so it should be
+    /// buffered (e.g., via [`BufWriter`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face has a material that is not in `materials` or
+    /// writing fails. Statements that have already been written are not
+    /// retracted if an error occurs.
+    ///
+    /// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+    ///
+    /// [`BufWriter`]: std::io::BufWriter
+    /// [`write_obj`]: crate::encoding::obj::ToObj::write_obj
+    fn write_obj_streaming<T, W>(&self, materials: &[T], write: W) -> Result<(), ObjError>
+    where
+        T: AsRef<str>,
+        W: Write;
+}
+
+impl<G, N> ToObj for MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    G::Face: AsMaterial,
+    <G::Vertex as AsPosition>::Position: EncodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+{
+    type Vertex = G::Vertex;
+    type Corner = ArcKey;
+
+    fn write_obj_with<T, W, F>(&self, materials: &[T], write: W, f: F) -> Result<(), ObjError>
+    where
+        T: AsRef<str>,
+        W: Write,
+        F: FnMut(Self::Corner) -> CornerAttributes,
+    {
+        write_graph(self, materials, write, |_| None, f)
+    }
+
+    fn write_obj_with_colors<T, W>(&self, materials: &[T], write: W) -> Result<(), ObjError>
+    where
+        Self::Vertex: AsColor,
+        T: AsRef<str>,
+        W: Write,
+    {
+        write_graph(
+            self,
+            materials,
+            write,
+            |vertex| Some(vertex.as_color()),
+            |_| CornerAttributes::default(),
+        )
+    }
+    fn write_obj_streaming<T, W>(&self, materials: &[T], mut write: W) -> Result<(), ObjError>
+    where
+        T: AsRef<str>,
+        W: Write,
+    {
+        // The number of statements written between flushes.
+        const FLUSH_INTERVAL: usize = 1 << 16;
+
+        let mut indices = HashMap::<VertexKey, usize>::with_capacity(self.vertex_count());
+        for (index, vertex) in self.vertices().enumerate() {
+            let [x, y, z] = vertex.position().encode_position()?;
+            writeln!(write, "v {} {} {}", x, y, z)?;
+            indices.insert(vertex.key(), index + 1);
+            if (index + 1) % FLUSH_INTERVAL == 0 {
+                write.flush()?;
+            }
+        }
+        // Faces without a material are written first, because there is no
+        // statement that clears the active material.
+        let mut count = 0;
+        let mut active = Material(None);
+        let faces = self
+            .faces()
+            .filter(|face| face.data.as_material().0.is_none())
+            .chain(
+                self.faces()
+                    .filter(|face| face.data.as_material().0.is_some()),
+            );
+        for face in faces {
+            let material = face.data.as_material();
+            if material != active {
+                let name = material
+                    .0
+                    .and_then(|index| materials.get(index))
+                    .ok_or_else(|| ObjError::MaterialNotFound)?
+                    .as_ref();
+                writeln!(write, "g {}", name)?;
+                writeln!(write, "usemtl {}", name)?;
+                active = material;
+            }
+            write!(write, "f")?;
+            for vertex in face.adjacent_vertices() {
+                write!(write, " {}", indices[&vertex.key()])?;
+            }
+            writeln!(write)?;
+            count += 1;
+            if count % FLUSH_INTERVAL == 0 {
+                write.flush()?;
+            }
+        }
+        write.flush()?;
+        Ok(())
+    }
+}
+
+fn write_graph<G, N, T, W, C, F>(
+    graph: &MeshGraph<G>,
+    materials: &[T],
+    mut write: W,
+    mut color: C,
+    mut f: F,
+) -> Result<(), ObjError>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    G::Face: AsMaterial,
+    <G::Vertex as AsPosition>::Position: EncodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+    T: AsRef<str>,
+    W: Write,
+    C: FnMut(&G::Vertex) -> Option<Color>,
+    F: FnMut(ArcKey) -> CornerAttributes,
+{
+    let mut indices = HashMap::<VertexKey, usize>::with_capacity(graph.vertex_count());
+    for (index, vertex) in graph.vertices().enumerate() {
+        let [x, y, z] = vertex.position().encode_position()?;
+        match color(&vertex.data) {
+            Some(color) => {
+                let [red, green, blue] = color.into_rgb();
+                writeln!(write, "v {} {} {} {} {} {}", x, y, z, red, green, blue)?;
+            }
+            None => writeln!(write, "v {} {} {}", x, y, z)?,
+        }
+        indices.insert(vertex.key(), index + 1);
+    }
+    // Texture coordinates and normals are indexed independently of
+    // positions and of each other. Attributes are deduplicated by the bits
+    // of their components.
+    let mut uvs = HashMap::<[u64; 2], usize>::new();
+    let mut normals = HashMap::<[u64; 3], usize>::new();
+    let mut corners = HashMap::with_capacity(graph.arc_count());
+    for face in graph.faces() {
+        for arc in face.adjacent_arcs() {
+            let CornerAttributes { uv, normal } = f(arc.key());
+            let uv = uv.map(|[u, v]| {
+                let n = uvs.len() + 1;
+                *uvs.entry([u.to_bits(), v.to_bits()]).or_insert(n)
+            });
+            let normal = normal.map(|[x, y, z]| {
+                let n = normals.len() + 1;
+                *normals
+                    .entry([x.to_bits(), y.to_bits(), z.to_bits()])
+                    .or_insert(n)
+            });
+            corners.insert(arc.key(), (uv, normal));
+        }
+    }
+    let mut uvs = uvs.into_iter().collect::<Vec<_>>();
+    uvs.sort_unstable_by_key(|(_, index)| *index);
+    for ([u, v], _) in uvs {
+        writeln!(write, "vt {} {}", f64::from_bits(u), f64::from_bits(v))?;
+    }
+    let mut normals = normals.into_iter().collect::<Vec<_>>();
+    normals.sort_unstable_by_key(|(_, index)| *index);
+    for ([x, y, z], _) in normals {
+        writeln!(
+            write,
+            "vn {} {} {}",
+            f64::from_bits(x),
+            f64::from_bits(y),
+            f64::from_bits(z)
+        )?;
+    }
+    // Group faces by material in order of first use.
+    let mut groups = Vec::<(Material, Vec<_>)>::new();
+    for face in graph.faces() {
+        let material = face.data.as_material();
+        match groups.iter_mut().find(|(group, _)| *group == material) {
+            Some((_, faces)) => faces.push(face),
+            None => groups.push((material, vec![face])),
+        }
+    }
+    groups.sort_by_key(|(material, _)| material.0.is_some());
+    for (material, faces) in groups {
+        if let Material(Some(index)) = material {
+            let name = materials
+                .get(index)
+                .ok_or_else(|| ObjError::MaterialNotFound)?
+                .as_ref();
+            writeln!(write, "g {}", name)?;
+            writeln!(write, "usemtl {}", name)?;
+        }
+        for face in faces {
+            write!(write, "f")?;
+            for arc in face.adjacent_arcs() {
+                let vertex = indices[&arc.source_vertex().key()];
+                match corners[&arc.key()] {
+                    (Some(uv), Some(normal)) => write!(write, " {}/{}/{}", vertex, uv, normal)?,
+                    (Some(uv), None) => write!(write, " {}/{}", vertex, uv)?,
+                    (None, Some(normal)) => write!(write, " {}//{}", vertex, normal)?,
+                    (None, None) => write!(write, " {}", vertex)?,
+                }
+            }
+            writeln!(write)?;
+        }
+    }
+    Ok(())
+}
+
+pub trait DecodePosition<N>: FiniteDimensional<N = N> + Sized
+where
+    N: NonZero + Unsigned,
+{
+    fn decode_position(coordinates: &[f64]) -> Option<Self>;
+}
+
+impl<T> DecodePosition<U2> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    fn decode_position(coordinates: &[f64]) -> Option<Self> {
+        match *coordinates {
+            [x, y, ..] => Some(EuclideanSpace::from_xy(cast(x)?, cast(y)?)),
+            _ => None,
+        }
+    }
+}
+
+impl<T> DecodePosition<U3> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn decode_position(coordinates: &[f64]) -> Option<Self> {
+        match *coordinates {
+            [x, y, z, ..] => Some(EuclideanSpace::from_xyz(cast(x)?, cast(y)?, cast(z)?)),
+            _ => None,
+        }
+    }
+}
+
+pub trait EncodePosition<N>: FiniteDimensional<N = N>
+where
+    N: NonZero + Unsigned,
+{
+    fn encode_position(&self) -> Result<[f64; 3], ObjError>;
+}
+
+impl<T> EncodePosition<U2> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    fn encode_position(&self) -> Result<[f64; 3], ObjError> {
+        let (x, y) = self.into_xy();
+        Ok([num_cast_scalar(x)?, num_cast_scalar(y)?, 0.0])
+    }
+}
+
+impl<T> EncodePosition<U3> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn encode_position(&self) -> Result<[f64; 3], ObjError> {
+        let (x, y, z) = self.into_xyz();
+        Ok([
+            num_cast_scalar(x)?,
+            num_cast_scalar(y)?,
+            num_cast_scalar(z)?,
+        ])
+    }
+}
+
+pub struct PositionEncoding<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> Default for PositionEncoding<T> {
+    fn default() -> Self {
+        PositionEncoding {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> FaceDecoder for PositionEncoding<T> {
+    type Output = Vec<(Self::Index, Self::Face)>;
+    type Index = SmallVec<[usize; 4]>;
+    type Face = Material;
+}
+
+impl<T> VertexDecoder for PositionEncoding<T> {
+    type Output = Vec<Self::Vertex>;
+    type Vertex = T;
+}
+
+/// Decoder of positions and vertex colors.
+///
+/// Vertices are decoded as a position and a [`Color`]. Vertices without a
+/// color are assigned the default color.
+///
+/// [`Color`]: crate::encoding::Color
+pub struct ColorEncoding<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> Default for ColorEncoding<T> {
+    fn default() -> Self {
+        ColorEncoding {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> FaceDecoder for ColorEncoding<T> {
+    type Output = Vec<(Self::Index, Self::Face)>;
+    type Index = SmallVec<[usize; 4]>;
+    type Face = Material;
+}
+
+impl<T> VertexDecoder for ColorEncoding<T> {
+    type Output = Vec<Self::Vertex>;
+    type Vertex = (T, Color);
+}
+
+fn read_obj<R, V, F>(
+    read: R,
+    mut decode: F,
+) -> Result<(Vec<V>, Vec<(SmallVec<[usize; 4]>, Material)>, Obj), ObjError>
+where
+    R: BufRead,
+    F: FnMut(&[f64]) -> Option<V>,
+{
+    let mut vertices = vec![];
+    let mut faces = vec![];
+    let mut materials = vec![];
+    let mut material = Material(None);
+    for (line, text) in read.lines().enumerate() {
+        let text = text?;
+        let error = || ObjError::Parse { line: line + 1 };
+        let mut tokens = text.split_whitespace();
+        match tokens.next() {
+            Some("v") => {
+                let coordinates = tokens
+                    .map(|token| token.parse::<f64>().map_err(|_| error()))
+                    .collect::<Result<SmallVec<[_; 6]>, _>>()?;
+                vertices.push(decode(&coordinates).ok_or_else(error)?);
+            }
+            Some("f") => {
+                let perimeter = tokens
+                    .map(|token| {
+                        // Only the position index is used. Texture and normal
+                        // indices are ignored.
+                        let index = token
+                            .split('/')
+                            .next()
+                            .and_then(|index| index.parse::<isize>().ok())
+                            .ok_or_else(error)?;
+                        // Indices are one-based. Negative indices are relative
+                        // to the most recently read vertex.
+                        match index {
+                            index if index > 0 => Ok(index as usize - 1),
+                            index if index < 0 => vertices
+                                .len()
+                                .checked_sub(index.wrapping_abs() as usize)
+                                .ok_or_else(error),
+                            _ => Err(error()),
+                        }
+                    })
+                    .collect::<Result<SmallVec<[_; 4]>, _>>()?;
+                faces.push((perimeter, material));
+            }
+            Some("usemtl") => {
+                let name = tokens.next().ok_or_else(error)?;
+                let index = materials
+                    .iter()
+                    .position(|material| material == name)
+                    .unwrap_or_else(|| {
+                        materials.push(name.to_owned());
+                        materials.len() - 1
+                    });
+                material = Material(Some(index));
+            }
+            _ => {}
+        }
+    }
+    if faces
+        .iter()
+        .flat_map(|(perimeter, _)| perimeter.iter())
+        .any(|index| *index >= vertices.len())
+    {
+        return Err(ObjError::Encoding);
+    }
+    Ok((vertices, faces, Obj { materials }))
+}
+
+fn num_cast_scalar<T>(value: T) -> Result<f64, ObjError>
+where
+    T: NumCast,
+{
+    cast::cast(value).ok_or_else(|| ObjError::Encoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::encoding::obj::{
+        ColorEncoding, CornerAttributes, FromObj, Material, PositionEncoding, ToObj,
+    };
+    use crate::encoding::{AsColor, Color};
+    use crate::geometry::{AsPosition, FromGeometry};
+    use crate::graph::{GraphData, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<f64>;
+
+    struct Data;
+
+    impl GraphData for Data {
+        type Vertex = E3;
+        type Arc = ();
+        type Edge = ();
+        type Face = Material;
+    }
+
+    #[test]
+    fn decode_into_graph() {
+        let obj: &[u8] = b"
+            # Square pyramid.
+            v 0 0 0
+            v 1 0 0
+            v 1 1 0
+            v 0 1 0
+            v 0.5 0.5 1
+            f 4 3 2 1
+            f 1/1 2/2 5/3
+            f 2//1 3//1 5//1
+            f -3 -2 -1
+            f 4 1 5
+        ";
+        let (graph, obj) =
+            MeshGraph::<E3>::from_obj_reader(PositionEncoding::<E3>::default(), obj).unwrap();
+
+        assert!(obj.materials.is_empty());
+        assert_eq!(5, graph.vertex_count());
+        assert_eq!(8, graph.edge_count());
+        assert_eq!(5, graph.face_count());
+    }
+
+    #[test]
+    fn material_round_trip() {
+        let mut graph: MeshGraph<Data> = Cube::new().polygons::<Position<E3>>().collect();
+        for (index, mut face) in graph.face_orphans().enumerate() {
+            face.data = Material(if index == 0 { None } else { Some(index % 2) });
+        }
+        let materials = ["wood", "stone"];
+
+        let mut obj = Vec::new();
+        graph.write_obj(&materials, &mut obj).unwrap();
+        let text = String::from_utf8(obj.clone()).unwrap();
+        assert_eq!(1, text.matches("usemtl wood").count());
+        assert_eq!(1, text.matches("usemtl stone").count());
+
+        let (graph, obj) =
+            MeshGraph::<Data>::from_obj_reader(PositionEncoding::<E3>::default(), obj.as_slice())
+                .unwrap();
+        let count = |name: &str| {
+            graph
+                .faces()
+                .filter(|face| {
+                    face.data
+                        .0
+                        .map_or(false, |index| obj.materials[index] == name)
+                })
+                .count()
+        };
+        assert_eq!(6, graph.face_count());
+        assert_eq!(
+            1,
+            graph.faces().filter(|face| face.data.0.is_none()).count()
+        );
+        assert_eq!(2, count("wood"));
+        assert_eq!(3, count("stone"));
+    }
+
+    #[test]
+    fn write_streaming_round_trip() {
+        let mut graph: MeshGraph<Data> = Cube::new().polygons::<Position<E3>>().collect();
+        for (index, mut face) in graph.face_orphans().enumerate() {
+            face.data = Material(if index == 0 { None } else { Some(index % 2) });
+        }
+        let materials = ["wood", "stone"];
+
+        let mut obj = Vec::new();
+        graph.write_obj_streaming(&materials, &mut obj).unwrap();
+        let text = String::from_utf8(obj.clone()).unwrap();
+        // The face without a material is written before any `usemtl`
+        // statement.
+        let first = text
+            .lines()
+            .position(|line| line.starts_with("f "))
+            .unwrap();
+        let usemtl = text
+            .lines()
+            .position(|line| line.starts_with("usemtl "))
+            .unwrap();
+        assert!(first < usemtl);
+
+        let (decoded, obj) =
+            MeshGraph::<Data>::from_obj_reader(PositionEncoding::<E3>::default(), obj.as_slice())
+                .unwrap();
+        let count = |name: &str| {
+            decoded
+                .faces()
+                .filter(|face| {
+                    face.data
+                        .0
+                        .map_or(false, |index| obj.materials[index] == name)
+                })
+                .count()
+        };
+        assert_eq!(graph.vertex_count(), decoded.vertex_count());
+        assert_eq!(graph.arc_count(), decoded.arc_count());
+        assert_eq!(6, decoded.face_count());
+        assert_eq!(
+            1,
+            decoded.faces().filter(|face| face.data.0.is_none()).count()
+        );
+        assert_eq!(2, count("wood"));
+        assert_eq!(3, count("stone"));
+    }
+
+    #[test]
+    fn color_round_trip() {
+        #[derive(Clone, Copy)]
+        struct Vertex {
+            position: E3,
+            color: Color,
+        }
+
+        impl AsColor for Vertex {
+            fn as_color(&self) -> Color {
+                self.color
+            }
+        }
+
+        impl AsPosition for Vertex {
+            type Position = E3;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        impl FromGeometry<(E3, Color)> for Vertex {
+            fn from_geometry((position, color): (E3, Color)) -> Self {
+                Vertex { position, color }
+            }
+        }
+
+        struct Colored;
+
+        impl GraphData for Colored {
+            type Vertex = Vertex;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+        }
+
+        let obj: &[u8] = b"
+            v 0 0 0 1 0 0
+            v 1 0 0 0 1 0
+            v 0 1 0 0 0 1
+            v 1 1 0
+            f 1 2 3
+            f 3 2 4
+        ";
+        let (graph, _) =
+            MeshGraph::<Colored>::from_obj_reader(ColorEncoding::<E3>::default(), obj).unwrap();
+        assert_eq!(4, graph.vertex_count());
+
+        let mut obj = Vec::new();
+        graph
+            .write_obj_with_colors(&[] as &[&str], &mut obj)
+            .unwrap();
+        let text = String::from_utf8(obj).unwrap();
+        for line in text.lines().filter(|line| line.starts_with("v ")) {
+            assert_eq!(7, line.split_whitespace().count());
+        }
+
+        let (decoded, _) =
+            MeshGraph::<Colored>::from_obj_reader(ColorEncoding::<E3>::default(), text.as_bytes())
+                .unwrap();
+        let colors = |graph: &MeshGraph<Colored>| {
+            let mut colors = graph
+                .vertices()
+                .map(|vertex| vertex.data.color.into_rgb())
+                .collect::<Vec<_>>();
+            colors.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            colors
+        };
+        assert_eq!(colors(&graph), colors(&decoded));
+        assert!(colors(&decoded).contains(&[1.0, 1.0, 1.0]));
+    }
+
+    #[test]
+    fn write_corner_attributes() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        let mut obj = Vec::new();
+        graph
+            .write_obj_with(&[] as &[&str], &mut obj, |key| {
+                let arc = graph.arc(key).unwrap();
+                let position = *arc.source_vertex().position();
+                // The normal of each face of the unit cube is twice its
+                // centroid.
+                let face = arc.face().unwrap();
+                let centroid = face.adjacent_vertices().fold([0.0; 3], |sum, vertex| {
+                    let position = vertex.position();
+                    [
+                        sum[0] + position.x,
+                        sum[1] + position.y,
+                        sum[2] + position.z,
+                    ]
+                });
+                CornerAttributes {
+                    uv: Some([position.x + 0.5, position.y + 0.5]),
+                    normal: Some([centroid[0] / 2.0, centroid[1] / 2.0, centroid[2] / 2.0]),
+                }
+            })
+            .unwrap();
+        let text = String::from_utf8(obj).unwrap();
+        let count = |statement: &str| {
+            text.lines()
+                .filter(|line| line.starts_with(statement))
+                .count()
+        };
+
+        assert_eq!(8, count("v "));
+        assert_eq!(4, count("vt "));
+        assert_eq!(6, count("vn "));
+        assert_eq!(6, count("f "));
+        for line in text.lines().filter(|line| line.starts_with("f ")) {
+            for corner in line.split_whitespace().skip(1) {
+                let indices = corner
+                    .split('/')
+                    .map(|index| index.parse::<usize>().unwrap())
+                    .collect::<Vec<_>>();
+                assert_eq!(3, indices.len());
+                assert!(indices[0] <= 8 && indices[1] <= 4 && indices[2] <= 6);
+            }
+        }
+
+        // Positions are read back from faces with corner attributes.
+        let (graph, _) =
+            MeshGraph::<E3>::from_obj_reader(PositionEncoding::<E3>::default(), text.as_bytes())
+                .unwrap();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(6, graph.face_count());
+    }
+}
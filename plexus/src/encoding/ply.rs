@@ -55,7 +55,7 @@ use theon::space::{EuclideanSpace, FiniteDimensional};
 use typenum::{NonZero, Unsigned, U2, U3};
 
 use crate::buffer::BufferError;
-use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
+use crate::encoding::{Color, FaceDecoder, FromEncoding, VertexDecoder};
 use crate::graph::GraphError;
 
 pub use ply_rs::ply::{
@@ -370,6 +370,97 @@ where
     }
 }
 
+/// Decoder of positions and vertex colors.
+///
+/// Vertices are decoded as a position and a [`Color`] from the `red`, `green`,
+/// `blue`, and (optionally) `alpha` properties of vertex elements. Integer
+/// components are normalized by the maximum of their type, so both `uchar` and
+/// `float` colors are supported. Vertices without a color are assigned the
+/// default color.
+///
+/// [`Color`]: crate::encoding::Color
+pub struct ColorEncoding<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> Default for ColorEncoding<T> {
+    fn default() -> Self {
+        ColorEncoding {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> FaceDecoder for ColorEncoding<T> {
+    type Output = Vec<(Self::Index, Self::Face)>;
+    type Index = SmallVec<[usize; 4]>;
+    type Face = ();
+}
+
+impl<T> FaceElementDecoder for ColorEncoding<T> {}
+
+impl<T> FacePropertyDecoder for ColorEncoding<T> {
+    fn decode_face_properties<'a, I>(
+        &self,
+        definition: &'a ElementDefinition,
+        elements: I,
+    ) -> Result<<Self as FaceDecoder>::Output, PlyError>
+    where
+        I: IntoIterator<Item = &'a Element>,
+    {
+        PositionEncoding::<T>::default().decode_face_properties(definition, elements)
+    }
+}
+
+impl<T> VertexDecoder for ColorEncoding<T> {
+    type Output = Vec<Self::Vertex>;
+    type Vertex = (T, Color);
+}
+
+impl<T> VertexElementDecoder for ColorEncoding<T> {}
+
+impl<T, N> VertexPropertyDecoder for ColorEncoding<T>
+where
+    T: DecodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+{
+    fn decode_vertex_properties<'a, I>(
+        &self,
+        _: &'a ElementDefinition,
+        elements: I,
+    ) -> Result<<Self as VertexDecoder>::Output, PlyError>
+    where
+        I: IntoIterator<Item = &'a Element>,
+    {
+        elements
+            .into_iter()
+            .map(|element| {
+                let position = T::decode_position(element)?;
+                let color = match (
+                    element.get("red"),
+                    element.get("green"),
+                    element.get("blue"),
+                ) {
+                    (Some(red), Some(green), Some(blue)) => {
+                        let alpha = match element.get("alpha") {
+                            Some(alpha) => decode_color_component(alpha)?,
+                            _ => 1.0,
+                        };
+                        Color([
+                            decode_color_component(red)?,
+                            decode_color_component(green)?,
+                            decode_color_component(blue)?,
+                            alpha,
+                        ])
+                    }
+                    _ => Color::default(),
+                };
+                Ok((position, color))
+            })
+            .collect()
+    }
+}
+
 pub fn decode_elements<'a, K>(
     definitions: &'a Header,
     elements: &'a Payload,
@@ -415,6 +506,21 @@ where
         .and_then(|(definition, elements)| decoder.decode_face_properties(definition, elements))
 }
 
+fn decode_color_component(property: &Property) -> Result<f64, PlyError> {
+    let (value, max) = match *property {
+        Property::Char(value) => (value.into(), i8::MAX.into()),
+        Property::UChar(value) => (value.into(), u8::MAX.into()),
+        Property::Short(value) => (value.into(), i16::MAX.into()),
+        Property::UShort(value) => (value.into(), u16::MAX.into()),
+        Property::Int(value) => (value.into(), i32::MAX.into()),
+        Property::UInt(value) => (value.into(), u32::MAX.into()),
+        Property::Float(value) => (value.into(), 1.0),
+        Property::Double(value) => (value, 1.0),
+        _ => return Err(PlyError::Encoding),
+    };
+    Ok(value / max)
+}
+
 fn num_cast_scalar<T, U>(value: T) -> Result<U, PlyError>
 where
     T: NumCast,
@@ -440,8 +546,9 @@ mod tests {
     use nalgebra::Point3;
 
     use crate::buffer::MeshBuffer;
-    use crate::encoding::ply::{FromPly, PositionEncoding};
-    use crate::graph::MeshGraph;
+    use crate::encoding::ply::{ColorEncoding, FromPly, PositionEncoding};
+    use crate::encoding::Color;
+    use crate::graph::{GraphData, MeshGraph};
     use crate::primitive::Tetragon;
 
     type E3 = Point3<f64>;
@@ -458,6 +565,47 @@ mod tests {
         assert_eq!(6, buffer.as_index_slice().len());
     }
 
+    #[test]
+    fn decode_colors_into_graph() {
+        struct Colored;
+
+        impl GraphData for Colored {
+            type Vertex = (E3, Color);
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+        }
+
+        let ply: &[u8] = b"ply
+format ascii 1.0
+element vertex 4
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+element face 1
+property list uchar int vertex_index
+end_header
+0 0 0 255 0 0
+1 0 0 0 255 0
+1 1 0 0 0 255
+0 1 0 255 255 255
+4 0 1 2 3
+";
+        let (graph, _) =
+            MeshGraph::<Colored>::from_ply(ColorEncoding::<E3>::default(), ply).unwrap();
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+        assert!(graph
+            .vertices()
+            .any(|vertex| vertex.data.1 == Color::from_rgb(1.0, 0.0, 0.0)));
+        assert!(graph
+            .vertices()
+            .any(|vertex| vertex.data.1 == Color::default()));
+    }
+
     #[test]
     fn decode_into_graph() {
         let graph = {
@@ -6,7 +6,7 @@ pub mod view;
 
 use thiserror::Error;
 
-use crate::entity::storage::{Get, OpaqueKey, Remove, Sequence};
+use crate::entity::storage::{Capacity, Get, OpaqueKey, Remove, Sequence};
 
 #[derive(Debug, Error, PartialEq)]
 pub enum EntityError {
@@ -16,7 +16,11 @@ pub enum EntityError {
     Geometry,
 }
 
+// Storage is chosen per entity type. Graph entities fix their storage (slot
+// maps for vertices, edges, and faces and hash maps for arcs), so alternative
+// backends such as a `Vec`-based arena cannot be selected by `GraphData` types
+// without breaking every implementation of that trait.
 pub trait Entity: Copy + Sized {
     type Key: OpaqueKey;
-    type Storage: Default + Get<Self> + Remove<Self> + Sequence<Self>;
+    type Storage: Capacity + Default + Get<Self> + Remove<Self> + Sequence<Self>;
 }
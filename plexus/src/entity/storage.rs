@@ -109,6 +109,10 @@ where
     fn keys<'a>(&'a self) -> Box<dyn ExactSizeIterator<Item = E::Key> + 'a>;
 }
 
+pub trait Capacity {
+    fn capacity(&self) -> usize;
+}
+
 pub trait Get<E>
 where
     E: Entity,
@@ -183,6 +187,16 @@ where
     }
 }
 
+impl<K, E, H> Capacity for HashMap<K, E, H>
+where
+    K: Eq + Hash,
+    H: BuildHasher,
+{
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+}
+
 impl<E, H> Remove<E> for HashMap<InnerKey<E::Key>, E, H>
 where
     E: Entity,
@@ -247,6 +261,15 @@ where
     }
 }
 
+impl<K, E> Capacity for HopSlotMap<K, E>
+where
+    K: SlotKey,
+{
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+}
+
 impl<E> Remove<E> for HopSlotMap<InnerKey<E::Key>, E>
 where
     E: Entity,
@@ -298,6 +321,10 @@ where
         self.inner.len()
     }
 
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
     // TODO: Use a `Clone` bound.
     pub fn iter(&self) -> impl ExactSizeIterator<Item = (E::Key, &E)> {
         self.inner.iter()
@@ -324,6 +351,30 @@ where
         self.inner.get_mut(key)
     }
 
+    /// Gets mutable references to the entities with the given keys.
+    ///
+    /// References are returned with their keys in the order in which the keys
+    /// first occur. Repeated keys are skipped, so the references never alias.
+    /// Returns `None` if any key is not present in the storage.
+    ///
+    /// Each entity is fetched by its key, but repeated keys are found by
+    /// comparing keys with each other. This is intended for the small sets of
+    /// keys produced by circulators.
+    pub fn get_disjoint_mut(&mut self, keys: &[E::Key]) -> Option<Vec<(E::Key, &mut E)>> {
+        let inner: *mut E::Storage = &mut self.inner;
+        keys.iter()
+            .enumerate()
+            .filter(|(index, key)| !keys[..*index].contains(key))
+            .map(|(_, key)| {
+                // The keys are distinct, so each reference is to a distinct
+                // entity. Getting an entity never moves entities within the
+                // storage, so the references remain valid for as long as the
+                // storage is mutably borrowed.
+                unsafe { (*inner).get_mut(key) }.map(|entity| (*key, entity))
+            })
+            .collect()
+    }
+
     pub fn insert(&mut self, entity: E) -> E::Key
     where
         E::Storage: Insert<E>,
@@ -314,6 +314,26 @@ where
         Orphan::bind(storage, key).map(T::from)
     }
 
+    /// Binds orphan views to the entities with the given keys.
+    ///
+    /// Returns `None` if any key is not present in the storage. Repeated keys
+    /// are bound only once.
+    pub fn bind_disjoint_into<T, M>(storage: &'a mut M, keys: &[E::Key]) -> Option<Vec<T>>
+    where
+        T: From<Self>,
+        M: AsStorageMut<E>,
+    {
+        storage
+            .as_storage_mut()
+            .get_disjoint_mut(keys)
+            .map(|entities| {
+                entities
+                    .into_iter()
+                    .map(|(key, entity)| Orphan::bind_unchecked(entity, key).into())
+                    .collect()
+            })
+    }
+
     pub fn key(&self) -> E::Key {
         self.key
     }
@@ -0,0 +1,222 @@
+use arrayvec::ArrayVec;
+use num::Zero;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use theon::space::{EuclideanSpace, Scalar};
+
+/// Computes the faces of a planar straight-line graph.
+///
+/// The graph is given by points in two dimensions and undirected edges that
+/// index into the points. Edges must only meet at their endpoints and every
+/// point must be incident to at least two edges. Components of the graph that
+/// are disconnected from the first point are bridged to it by two edges each,
+/// so that faces have no holes. Returns the bounded faces as loops of indices
+/// that are wound counter-clockwise. Returns `None` if a component cannot be
+/// bridged.
+pub fn faces<S>(
+    points: &[(Scalar<S>, Scalar<S>)],
+    edges: &[(usize, usize)],
+) -> Option<Vec<Vec<usize>>>
+where
+    S: EuclideanSpace,
+{
+    fn find(roots: &mut [usize], mut index: usize) -> usize {
+        while roots[index] != index {
+            roots[index] = roots[roots[index]];
+            index = roots[index];
+        }
+        index
+    }
+
+    let zero = Scalar::<S>::zero();
+    let mut edges = edges.to_vec();
+    let mut roots = (0..points.len()).collect::<Vec<_>>();
+    for (a, b) in edges.iter() {
+        let (a, b) = (find(&mut roots, *a), find(&mut roots, *b));
+        roots[a] = b;
+    }
+    // Bridge disconnected components to the component of the first point. A
+    // bridge must not cross any edge and two bridges may not share a point,
+    // which splits the region between the components into two faces.
+    loop {
+        let anchor = find(&mut roots, 0);
+        let component = match (0..points.len()).find(|index| find(&mut roots, *index) != anchor) {
+            Some(index) => find(&mut roots, index),
+            _ => break,
+        };
+        let inner = (0..points.len())
+            .filter(|index| find(&mut roots, *index) == component)
+            .collect::<Vec<_>>();
+        let outer = (0..points.len())
+            .filter(|index| find(&mut roots, *index) == anchor)
+            .collect::<Vec<_>>();
+        let mut bridges = Vec::<(usize, usize)>::with_capacity(2);
+        'search: for a in inner.iter().cloned() {
+            for b in outer.iter().cloned() {
+                if bridges.iter().any(|(c, d)| *c == a || *d == b) {
+                    continue;
+                }
+                if edges.iter().chain(bridges.iter()).any(|(c, d)| {
+                    is_crossing::<S>([points[a], points[b]], [points[*c], points[*d]])
+                }) {
+                    continue;
+                }
+                bridges.push((a, b));
+                if bridges.len() == 2 {
+                    break 'search;
+                }
+            }
+        }
+        if bridges.len() < 2 {
+            return None;
+        }
+        roots[component] = anchor;
+        edges.extend(bridges);
+    }
+    // Sort the neighbors of each point counter-clockwise about the point.
+    let mut neighbors = vec![vec![]; points.len()];
+    for (a, b) in edges.iter() {
+        neighbors[*a].push(*b);
+        neighbors[*b].push(*a);
+    }
+    for (index, neighbors) in neighbors.iter_mut().enumerate() {
+        let (ox, oy) = points[index];
+        let is_lower = |(x, y): (Scalar<S>, Scalar<S>)| y < zero || (y == zero && x < zero);
+        neighbors.sort_by(|a, b| {
+            let (a, b) = (points[*a], points[*b]);
+            let (a, b) = ((a.0 - ox, a.1 - oy), (b.0 - ox, b.1 - oy));
+            is_lower(a).cmp(&is_lower(b)).then_with(|| {
+                let determinant = (a.0 * b.1) - (a.1 * b.0);
+                if determinant > zero {
+                    Ordering::Less
+                }
+                else if determinant < zero {
+                    Ordering::Greater
+                }
+                else {
+                    Ordering::Equal
+                }
+            })
+        });
+    }
+    // Trace the face to the left of each directed edge. The unbounded face is
+    // wound clockwise and is discarded.
+    let mut is_visited = HashSet::with_capacity(edges.len() * 2);
+    let mut faces = vec![];
+    for (a, b) in edges
+        .iter()
+        .flat_map(|(a, b)| ArrayVec::from([(*a, *b), (*b, *a)]))
+    {
+        if is_visited.contains(&(a, b)) {
+            continue;
+        }
+        let mut face = vec![];
+        let (mut a, mut b) = (a, b);
+        while is_visited.insert((a, b)) {
+            face.push(a);
+            let neighbors = &neighbors[b];
+            let index = neighbors.iter().position(|c| *c == a)?;
+            let c = neighbors[(index + neighbors.len() - 1) % neighbors.len()];
+            a = b;
+            b = c;
+        }
+        let area = face
+            .iter()
+            .zip(face.iter().cycle().skip(1))
+            .fold(zero, |area, (a, b)| {
+                let ((ax, ay), (bx, by)) = (points[*a], points[*b]);
+                area + ((ax * by) - (ay * bx))
+            });
+        if area > zero {
+            faces.push(face);
+        }
+    }
+    Some(faces)
+}
+
+/// Finds an ear of a simple polygon.
+///
+/// The polygon is given by indices into points in two dimensions and must be
+/// wound counter-clockwise. Returns the indices of a convex vertex and its
+/// neighbors such that no other vertex of the polygon lies within the triangle
+/// that they form. The centroid of this triangle is in the interior of the
+/// polygon. Returns `None` if the polygon has no such vertex.
+pub fn ear<S>(points: &[(Scalar<S>, Scalar<S>)], polygon: &[usize]) -> Option<[usize; 3]>
+where
+    S: EuclideanSpace,
+{
+    let zero = Scalar::<S>::zero();
+    let n = polygon.len();
+    (0..n)
+        .map(|index| {
+            [
+                polygon[(index + n - 1) % n],
+                polygon[index],
+                polygon[(index + 1) % n],
+            ]
+        })
+        .find(|[a, b, c]| {
+            let (pa, pb, pc) = (points[*a], points[*b], points[*c]);
+            orientation::<S>(pa, pb, pc) > zero
+                && polygon
+                    .iter()
+                    .filter(|index| *index != a && *index != b && *index != c)
+                    .all(|index| {
+                        let p = points[*index];
+                        orientation::<S>(pa, pb, p) < zero
+                            || orientation::<S>(pb, pc, p) < zero
+                            || orientation::<S>(pc, pa, p) < zero
+                    })
+        })
+}
+
+/// Returns `true` if a point is in the interior of a simple polygon.
+///
+/// The polygon is given by indices into points in two dimensions and may be
+/// wound in either direction. Points on the boundary of the polygon may be
+/// classified either way.
+pub fn contains<S>(
+    points: &[(Scalar<S>, Scalar<S>)],
+    polygon: &[usize],
+    (x, y): (Scalar<S>, Scalar<S>),
+) -> bool
+where
+    S: EuclideanSpace,
+{
+    // Count the edges that cross a ray cast from the point along the x-axis.
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .fold(false, |is_inside, (a, b)| {
+            let ((ax, ay), (bx, by)) = (points[*a], points[*b]);
+            if (ay > y) != (by > y) && x < ax + ((bx - ax) * (y - ay) / (by - ay)) {
+                !is_inside
+            }
+            else {
+                is_inside
+            }
+        })
+}
+
+fn orientation<S>(
+    (ax, ay): (Scalar<S>, Scalar<S>),
+    (bx, by): (Scalar<S>, Scalar<S>),
+    (cx, cy): (Scalar<S>, Scalar<S>),
+) -> Scalar<S>
+where
+    S: EuclideanSpace,
+{
+    ((bx - ax) * (cy - ay)) - ((by - ay) * (cx - ax))
+}
+
+// Segments that only touch or overlap are not considered crossing.
+fn is_crossing<S>([a, b]: [(Scalar<S>, Scalar<S>); 2], [c, d]: [(Scalar<S>, Scalar<S>); 2]) -> bool
+where
+    S: EuclideanSpace,
+{
+    let zero = Scalar::<S>::zero();
+    let is_straddling =
+        |p: Scalar<S>, q: Scalar<S>| (p > zero && q < zero) || (p < zero && q > zero);
+    is_straddling(orientation::<S>(a, b, c), orientation::<S>(a, b, d))
+        && is_straddling(orientation::<S>(c, d, a), orientation::<S>(c, d, b))
+}
@@ -0,0 +1,40 @@
+use num::{One, Zero};
+use theon::space::{EuclideanSpace, InnerSpace, Scalar};
+
+/// Computes an approximate bounding sphere of points.
+///
+/// The sphere is computed using Ritter's algorithm, which is typically within
+/// several percent of the minimal enclosing sphere. Returns the center and
+/// radius of the sphere. Returns `None` if there are no points.
+pub fn bounding_sphere<S>(points: &[S]) -> Option<(S, Scalar<S>)>
+where
+    S: EuclideanSpace,
+{
+    let farthest = |from: S| {
+        let mut max = (from, Zero::zero());
+        for point in points.iter() {
+            let distance = (*point - from).magnitude();
+            if distance > max.1 {
+                max = (*point, distance);
+            }
+        }
+        max
+    };
+    let half = Scalar::<S>::one() / (Scalar::<S>::one() + One::one());
+    // Begin with a sphere about the point farthest from an arbitrary point and
+    // the point farthest from that point.
+    let (a, _) = farthest(*points.first()?);
+    let (b, diameter) = farthest(a);
+    let mut center = a + ((b - a) * half);
+    let mut radius = diameter * half;
+    // Grow the sphere to enclose any points outside of it.
+    for point in points.iter() {
+        let distance = (*point - center).magnitude();
+        if distance > radius {
+            let grown = (radius + distance) * half;
+            center = center + ((*point - center) * ((grown - radius) / distance));
+            radius = grown;
+        }
+    }
+    Some((center, radius))
+}
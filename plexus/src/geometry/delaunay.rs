@@ -0,0 +1,96 @@
+use arrayvec::ArrayVec;
+use num::{NumCast, One, Zero};
+use std::collections::HashSet;
+use theon::space::{EuclideanSpace, Scalar};
+
+// TODO: Circumcircle containment is determined by exact comparisons with zero.
+//       Cocircular and nearly collinear points may produce slivers.
+// TODO: Triangles that share a vertex with the super-triangle are discarded,
+//       which can leave concavities along the convex hull of the points when
+//       the points are nearly collinear at the hull.
+/// Computes the Delaunay triangulation of points in two dimensions.
+///
+/// Triangulation uses the Bowyer-Watson algorithm. Returns triangles that
+/// index into the given points. The triangles are wound counter-clockwise.
+/// Points that coincide with a prior point are not triangulated. Returns
+/// `None` if there are fewer than three non-collinear points.
+pub fn delaunay<S>(points: &[(Scalar<S>, Scalar<S>)]) -> Option<Vec<[usize; 3]>>
+where
+    S: EuclideanSpace,
+{
+    if points.len() < 3 {
+        return None;
+    }
+    let (mut min, mut max) = (points[0], points[0]);
+    for &(x, y) in points.iter() {
+        min = (
+            if x < min.0 { x } else { min.0 },
+            if y < min.1 { y } else { min.1 },
+        );
+        max = (
+            if x > max.0 { x } else { max.0 },
+            if y > max.1 { y } else { max.1 },
+        );
+    }
+    let (width, height) = (max.0 - min.0, max.1 - min.1);
+    let extent = if width > height { width } else { height };
+    if extent <= Zero::zero() {
+        return None;
+    }
+    // Enclose the points in a super-triangle that is large enough that its
+    // vertices do not affect the triangulation of the points.
+    let two = Scalar::<S>::one() + One::one();
+    let scale = extent * <Scalar<S> as NumCast>::from(20).unwrap();
+    let (x, y) = ((min.0 + max.0) / two, (min.1 + max.1) / two);
+    let mut vertices = points.to_vec();
+    vertices.extend(ArrayVec::from([
+        (x - scale, y - extent),
+        (x + scale, y - extent),
+        (x, y + scale),
+    ]));
+    let n = points.len();
+    let is_enclosed = |[a, b, c]: [usize; 3], index: usize| {
+        let (px, py) = vertices[index];
+        let relative = |index: usize| {
+            let (x, y) = vertices[index];
+            (x - px, y - py)
+        };
+        let ((ax, ay), (bx, by), (cx, cy)) = (relative(a), relative(b), relative(c));
+        let determinant = ((ax * ax) + (ay * ay)) * ((bx * cy) - (cx * by))
+            - ((bx * bx) + (by * by)) * ((ax * cy) - (cx * ay))
+            + ((cx * cx) + (cy * cy)) * ((ax * by) - (bx * ay));
+        determinant > Zero::zero()
+    };
+    let mut triangles = vec![[n, n + 1, n + 2]];
+    for index in 0..n {
+        let (bad, good): (Vec<_>, Vec<_>) = triangles
+            .into_iter()
+            .partition(|triangle| is_enclosed(*triangle, index));
+        triangles = good;
+        if bad.is_empty() {
+            continue;
+        }
+        // The cavity is bounded by the edges of enclosing triangles that are
+        // not shared with any other enclosing triangle.
+        let edges = bad
+            .iter()
+            .flat_map(|&[a, b, c]| ArrayVec::from([(a, b), (b, c), (c, a)]))
+            .collect::<HashSet<_>>();
+        triangles.extend(
+            edges
+                .iter()
+                .filter(|(a, b)| !edges.contains(&(*b, *a)))
+                .map(|(a, b)| [*a, *b, index]),
+        );
+    }
+    let triangles = triangles
+        .into_iter()
+        .filter(|triangle| triangle.iter().all(|index| *index < n))
+        .collect::<Vec<_>>();
+    if triangles.is_empty() {
+        None
+    }
+    else {
+        Some(triangles)
+    }
+}
@@ -0,0 +1,79 @@
+use arrayvec::ArrayVec;
+use num::Zero;
+use std::collections::HashSet;
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use typenum::U3;
+
+// TODO: Visibility is determined by exact comparisons with zero. Nearly
+//       coplanar points may produce slivers or redundant triangles.
+/// Computes the convex hull of points in three dimensions.
+///
+/// Returns triangles that index into the given points. The triangles are
+/// consistently wound such that their normals point outward from the hull.
+/// Returns `None` if there are fewer than four non-coplanar points.
+pub fn convex_hull<S>(points: &[S]) -> Option<Vec<[usize; 3]>>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>> + InnerSpace,
+{
+    let normal = |[a, b, c]: [usize; 3]| (points[b] - points[a]).cross(points[c] - points[a]);
+    let height = |face: [usize; 3], point: S| normal(face).dot(point - points[face[0]]);
+    let farthest = |f: &dyn Fn(S) -> Scalar<S>| {
+        let mut max = None;
+        for (index, point) in points.iter().enumerate() {
+            let distance = f(*point);
+            if distance > Zero::zero() && max.map_or(true, |(_, max)| distance > max) {
+                max = Some((index, distance));
+            }
+        }
+        max.map(|(index, _)| index)
+    };
+    // Find an initial tetrahedron from the first point, the point farthest
+    // from it, the point farthest from the line between them, and the point
+    // farthest from the plane through all three.
+    if points.is_empty() {
+        return None;
+    }
+    let a = 0;
+    let b = farthest(&|point| {
+        let ap = point - points[a];
+        ap.dot(ap)
+    })?;
+    let c = farthest(&|point| {
+        let n = (points[b] - points[a]).cross(point - points[a]);
+        n.dot(n)
+    })?;
+    let d = farthest(&|point| {
+        let h = height([a, b, c], point);
+        h * h
+    })?;
+    let mut faces = if height([a, b, c], points[d]) > Zero::zero() {
+        vec![[a, c, b], [a, b, d], [b, c, d], [c, a, d]]
+    }
+    else {
+        vec![[a, b, c], [a, d, b], [b, d, c], [c, d, a]]
+    };
+    for (index, point) in points.iter().enumerate() {
+        let (visible, hidden): (Vec<_>, Vec<_>) = faces
+            .into_iter()
+            .partition(|face| height(*face, *point) > Zero::zero());
+        faces = hidden;
+        if visible.is_empty() {
+            continue;
+        }
+        // The horizon is formed by the edges of visible faces that are not
+        // shared with any other visible face.
+        let edges = visible
+            .iter()
+            .flat_map(|&[a, b, c]| ArrayVec::from([(a, b), (b, c), (c, a)]))
+            .collect::<HashSet<_>>();
+        faces.extend(
+            edges
+                .iter()
+                .filter(|(a, b)| !edges.contains(&(*b, *a)))
+                .map(|(a, b)| [*a, *b, index]),
+        );
+    }
+    Some(faces)
+}
@@ -10,8 +10,15 @@
 //! [`integration`]: crate::integration
 
 use num::{One, Zero};
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use typenum::{U2, U3};
 
+pub(crate) mod arrangement;
+pub(crate) mod bound;
+pub(crate) mod delaunay;
+pub(crate) mod hull;
 pub mod partition;
+pub(crate) mod ray;
 
 pub use theon::query::*;
 pub use theon::space::{Scalar, Vector};
@@ -85,6 +92,80 @@ where
     }
 }
 
+/// Texture coordinates.
+///
+/// Exposes texture coordinates in vertex data, much like [`AsPosition`]
+/// exposes positions. Texture coordinates are points in two dimensions.
+///
+/// [`AsPosition`]: crate::geometry::AsPosition
+pub trait AsUv {
+    type Uv: EuclideanSpace + FiniteDimensional<N = U2>;
+
+    fn as_uv(&self) -> &Self::Uv;
+}
+
+/// Mutable texture coordinates.
+///
+/// See [`AsUv`].
+///
+/// [`AsUv`]: crate::geometry::AsUv
+pub trait AsUvMut: AsUv {
+    fn as_uv_mut(&mut self) -> &mut Self::Uv;
+}
+
+pub type Uv<T> = <T as AsUv>::Uv;
+
+/// Texture seams.
+///
+/// Exposes whether or not arc data is marked as a seam in a texture layout.
+/// Faces on either side of a seam belong to different islands (or charts) of
+/// the layout. See [`MeshGraph::uv_islands`].
+///
+/// [`MeshGraph::uv_islands`]: crate::graph::MeshGraph::uv_islands
+pub trait AsSeam {
+    fn is_seam(&self) -> bool;
+}
+
+impl AsSeam for bool {
+    fn is_seam(&self) -> bool {
+        *self
+    }
+}
+
 pub trait Metric: Eq + One + Ord + Zero {}
 
 impl<Q> Metric for Q where Q: Eq + One + Ord + Zero {}
+
+/// Projection onto the $xy$-plane.
+///
+/// This trait is implemented for the dimensions of Euclidean spaces that can
+/// be projected onto the $xy$-plane by discarding any other coordinates. It is
+/// used to bound operations that triangulate points in the plane, such as
+/// [`MeshGraph::from_points_delaunay_2d`].
+///
+/// [`MeshGraph::from_points_delaunay_2d`]: crate::graph::MeshGraph::from_points_delaunay_2d
+pub trait ProjectXy<S>
+where
+    S: EuclideanSpace,
+{
+    fn project_xy(point: S) -> (Scalar<S>, Scalar<S>);
+}
+
+impl<S> ProjectXy<S> for U2
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    fn project_xy(point: S) -> (Scalar<S>, Scalar<S>) {
+        point.into_xy()
+    }
+}
+
+impl<S> ProjectXy<S> for U3
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn project_xy(point: S) -> (Scalar<S>, Scalar<S>) {
+        let (x, y, _) = point.into_xyz();
+        (x, y)
+    }
+}
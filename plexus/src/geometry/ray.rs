@@ -0,0 +1,77 @@
+use decorum::Real;
+use num::{One, Zero};
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use typenum::U3;
+
+/// Gets the time of impact of a ray with a triangle.
+///
+/// The ray is given by an origin and a direction, which need not be
+/// normalized. Returns `None` if the ray does not intersect the triangle, is
+/// parallel to the triangle, or intersects the triangle at or behind its
+/// origin.
+///
+/// Rays that pass exactly through an edge or vertex are resolved as if the
+/// origin of the ray were displaced by an infinitesimal offset that depends
+/// only on the direction of the ray. Each edge and vertex is therefore half-open
+/// and belongs to exactly one of the triangles of a consistently wound surface
+/// that share it, so hits are neither counted twice nor missed when casting a
+/// ray against the triangles of a mesh.
+pub fn triangle_time_of_impact<S>(
+    origin: S,
+    direction: Vector<S>,
+    [a, b, c]: [S; 3],
+) -> Option<Scalar<S>>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>> + InnerSpace,
+{
+    let zero = Scalar::<S>::zero();
+    let one = Scalar::<S>::one();
+    // Displace the origin along the axis that is most orthogonal to the
+    // direction and then along a direction that is orthogonal to both. Only
+    // the components of these displacements that are orthogonal to the
+    // direction are used.
+    let (x, y, z) = (S::origin() + direction).into_xyz();
+    let (x, y, z) = (Real::abs(x), Real::abs(y), Real::abs(z));
+    let axis = if x <= y && x <= z {
+        S::from_xyz(one, zero, zero)
+    }
+    else if y <= z {
+        S::from_xyz(zero, one, zero)
+    }
+    else {
+        S::from_xyz(zero, zero, one)
+    } - S::origin();
+    let first = direction.cross(axis);
+    let second = direction.cross(first);
+    // Gets the side of the ray on which an edge passes. If the ray intersects
+    // the edge, then the side is determined by the displacements instead.
+    let side = |p: S, q: S| {
+        let pq = p - q;
+        [
+            direction.dot((p - origin).cross(q - origin)),
+            pq.dot(first),
+            pq.dot(second),
+        ]
+        .iter()
+        .find(|w| **w != zero)
+        .map(|w| *w > zero)
+    };
+    let ab = side(a, b)?;
+    if side(b, c)? != ab || side(c, a)? != ab {
+        return None;
+    }
+    let normal = (b - a).cross(c - a);
+    let determinant = direction.dot(normal);
+    if determinant == zero {
+        return None;
+    }
+    let t = (a - origin).dot(normal) / determinant;
+    if t > zero {
+        Some(t)
+    }
+    else {
+        None
+    }
+}
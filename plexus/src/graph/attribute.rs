@@ -0,0 +1,304 @@
+use std::collections::hash_map;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+use crate::graph::data::GraphData;
+use crate::graph::edge::{ArcKey, EdgeKey};
+use crate::graph::face::FaceKey;
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphRekeying, MeshGraph};
+
+/// Key of an entity that can be associated with values in an
+/// [`AttributeMap`].
+///
+/// [`AttributeMap`]: crate::graph::AttributeMap
+pub trait AttributeKey: Copy + Eq + Hash {
+    /// Gets the key of the entity after compaction.
+    fn rekey(self, rekeying: &GraphRekeying) -> Option<Self>;
+
+    /// Returns `true` if the entity is in the given graph.
+    fn is_in<G>(self, graph: &MeshGraph<G>) -> bool
+    where
+        G: GraphData;
+}
+
+impl AttributeKey for VertexKey {
+    fn rekey(self, rekeying: &GraphRekeying) -> Option<Self> {
+        rekeying.vertex(self)
+    }
+
+    fn is_in<G>(self, graph: &MeshGraph<G>) -> bool
+    where
+        G: GraphData,
+    {
+        graph.vertex(self).is_some()
+    }
+}
+
+impl AttributeKey for ArcKey {
+    fn rekey(self, rekeying: &GraphRekeying) -> Option<Self> {
+        rekeying.arc(self)
+    }
+
+    fn is_in<G>(self, graph: &MeshGraph<G>) -> bool
+    where
+        G: GraphData,
+    {
+        graph.arc(self).is_some()
+    }
+}
+
+impl AttributeKey for EdgeKey {
+    fn rekey(self, rekeying: &GraphRekeying) -> Option<Self> {
+        rekeying.edge(self)
+    }
+
+    fn is_in<G>(self, graph: &MeshGraph<G>) -> bool
+    where
+        G: GraphData,
+    {
+        graph.edge(self).is_some()
+    }
+}
+
+impl AttributeKey for FaceKey {
+    fn rekey(self, rekeying: &GraphRekeying) -> Option<Self> {
+        rekeying.face(self)
+    }
+
+    fn is_in<G>(self, graph: &MeshGraph<G>) -> bool
+    where
+        G: GraphData,
+    {
+        graph.face(self).is_some()
+    }
+}
+
+/// Sparse values associated with the entities of a [`MeshGraph`].
+///
+/// An attribute map stores data outside of a graph, keyed by the keys of its
+/// entities. This is useful for optional or transient data, such as selection
+/// state or weights, that need not be part of the [`GraphData`] of the graph.
+///
+/// Keys are never rekeyed by mutations, so values remain associated with their
+/// entities until those entities are removed. Use [`retain_in`] to discard the
+/// values of removed entities and [`rekey`] to follow the keys of entities
+/// through [`MeshGraph::compact`].
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::graph::{AttributeMap, FaceKey, MeshGraph};
+/// use plexus::prelude::*;
+/// use plexus::primitive::cube::Cube;
+/// use plexus::primitive::generate::Position;
+///
+/// type E3 = Point3<R64>;
+///
+/// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+/// let mut selection = AttributeMap::<FaceKey, bool>::new();
+/// let key = graph.faces().nth(0).unwrap().key();
+/// selection.insert(key, true);
+///
+/// let rekeying = graph.compact();
+/// selection.rekey(&rekeying);
+/// assert_eq!(Some(&true), selection.get(rekeying.face(key).unwrap()));
+/// ```
+///
+/// [`GraphData`]: crate::graph::GraphData
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::compact`]: crate::graph::MeshGraph::compact
+/// [`rekey`]: crate::graph::AttributeMap::rekey
+/// [`retain_in`]: crate::graph::AttributeMap::retain_in
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttributeMap<K, V>
+where
+    K: AttributeKey,
+{
+    values: HashMap<K, V>,
+}
+
+impl<K, V> AttributeMap<K, V>
+where
+    K: AttributeKey,
+{
+    pub fn new() -> Self {
+        AttributeMap {
+            values: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn contains_key(&self, key: K) -> bool {
+        self.values.contains_key(&key)
+    }
+
+    pub fn get(&self, key: K) -> Option<&V> {
+        self.values.get(&key)
+    }
+
+    pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        self.values.get_mut(&key)
+    }
+
+    /// Associates a value with an entity.
+    ///
+    /// Returns the value previously associated with the entity, if any.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.values.insert(key, value)
+    }
+
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        self.values.remove(&key)
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear()
+    }
+
+    pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = (K, &V)> {
+        self.values.iter().map(|(key, value)| (*key, value))
+    }
+
+    pub fn iter_mut(&mut self) -> impl '_ + ExactSizeIterator<Item = (K, &mut V)> {
+        self.values.iter_mut().map(|(key, value)| (*key, value))
+    }
+
+    /// Discards the values of entities that are not in the given graph.
+    ///
+    /// Returns the number of discarded values.
+    pub fn retain_in<G>(&mut self, graph: &MeshGraph<G>) -> usize
+    where
+        G: GraphData,
+    {
+        let n = self.values.len();
+        self.values.retain(|key, _| key.is_in(graph));
+        n - self.values.len()
+    }
+
+    /// Rekeys the map after its graph has been compacted.
+    ///
+    /// Values of entities that have no key after compaction are discarded.
+    ///
+    /// See [`MeshGraph::compact`].
+    ///
+    /// [`MeshGraph::compact`]: crate::graph::MeshGraph::compact
+    pub fn rekey(&mut self, rekeying: &GraphRekeying) {
+        self.values = self
+            .values
+            .drain()
+            .filter_map(|(key, value)| key.rekey(rekeying).map(|key| (key, value)))
+            .collect();
+    }
+}
+
+impl<K, V> Default for AttributeMap<K, V>
+where
+    K: AttributeKey,
+{
+    fn default() -> Self {
+        AttributeMap::new()
+    }
+}
+
+impl<K, V> Extend<(K, V)> for AttributeMap<K, V>
+where
+    K: AttributeKey,
+{
+    fn extend<I>(&mut self, input: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        self.values.extend(input)
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for AttributeMap<K, V>
+where
+    K: AttributeKey,
+{
+    fn from_iter<I>(input: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        AttributeMap {
+            values: input.into_iter().collect(),
+        }
+    }
+}
+
+impl<K, V> IntoIterator for AttributeMap<K, V>
+where
+    K: AttributeKey,
+{
+    type Item = (K, V);
+    type IntoIter = hash_map::IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::{AttributeMap, EdgeKey, FaceKey, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn retain_removed_entities() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut selection: AttributeMap<FaceKey, bool> =
+            graph.faces().map(|face| (face.key(), true)).collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+
+        assert_eq!(1, selection.retain_in(&graph));
+        assert_eq!(5, selection.len());
+        assert!(!selection.contains_key(key));
+    }
+
+    #[test]
+    fn rekey_after_compaction() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut weights: AttributeMap<EdgeKey, usize> = graph
+            .edges()
+            .enumerate()
+            .map(|(index, edge)| (edge.key(), index))
+            .collect();
+        let keys = weights.iter().map(|(key, _)| key).collect::<Vec<_>>();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().poke_at_centroid();
+
+        let rekeying = graph.compact();
+        let previous = weights.clone();
+        weights.rekey(&rekeying);
+
+        assert_eq!(12, weights.len());
+        for key in keys {
+            let rekey = rekeying.edge(key).unwrap();
+            assert!(graph.edge(rekey).is_some());
+            assert_eq!(previous.get(key), weights.get(rekey));
+        }
+    }
+}
@@ -0,0 +1,468 @@
+use arrayvec::ArrayVec;
+use smallvec::SmallVec;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use theon::{AsPosition, AsPositionMut};
+use typenum::U3;
+
+use crate::geometry::{arrangement, ray};
+use crate::graph::data::GraphData;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Mutation};
+use crate::graph::{GraphError, MeshGraph};
+use crate::transact::Transact;
+
+/// Boolean operation on the volumes enclosed by [`MeshGraph`]s.
+///
+/// See [`MeshGraph::boolean`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::boolean`]: crate::graph::MeshGraph::boolean
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BooleanOp {
+    /// The volume enclosed by either graph.
+    Union,
+    /// The volume enclosed by both graphs.
+    Intersection,
+    /// The volume enclosed by the initiating graph but not the other graph.
+    Difference,
+}
+
+// TODO: Degenerate intersections are not supported. This includes faces
+//       that are coplanar with and overlap faces of the other graph and
+//       vertices or edges that lie exactly on the surface of the other
+//       graph.
+pub fn boolean<G>(
+    graph: &MeshGraph<G>,
+    other: &MeshGraph<G>,
+    op: BooleanOp,
+) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+{
+    // Points are either vertices of a graph or crossings of a segment of a
+    // graph with a triangle of the other graph. Segments are the edges of
+    // the triangles of faces and so include their diagonals. Each is
+    // identified by the graph (`0` or `1`) and the indices of its vertices
+    // and triangles.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    enum Point {
+        Vertex(usize, usize),
+        Crossing(usize, (usize, usize), usize),
+    }
+
+    struct Triangle<S>
+    where
+        S: EuclideanSpace,
+    {
+        face: usize,
+        indices: [usize; 3],
+        positions: [S; 3],
+        normal: Vector<S>,
+        bounds: ([Scalar<S>; 3], [Scalar<S>; 3]),
+    }
+
+    let zero = Scalar::<VertexPosition<G>>::zero();
+    let mut vertices = vec![];
+    let mut faces = vec![];
+    let mut triangles = vec![];
+    for graph in [graph, other].iter() {
+        let mut indices = HashMap::with_capacity(graph.vertex_count());
+        let data = graph
+            .vertices()
+            .enumerate()
+            .map(|(index, vertex)| {
+                indices.insert(vertex.key(), index);
+                vertex.data
+            })
+            .collect::<Vec<_>>();
+        let perimeters = graph
+            .faces()
+            .map(|face| {
+                let perimeter = face
+                    .adjacent_vertices()
+                    .map(|vertex| indices[&vertex.key()])
+                    .collect::<SmallVec<[_; 4]>>();
+                (face.data, perimeter)
+            })
+            .collect::<Vec<_>>();
+        let mut fan = vec![];
+        for (face, (_, perimeter)) in perimeters.iter().enumerate() {
+            for index in 1..perimeter.len().saturating_sub(1) {
+                let indices = [perimeter[0], perimeter[index], perimeter[index + 1]];
+                let positions = [
+                    *data[indices[0]].as_position(),
+                    *data[indices[1]].as_position(),
+                    *data[indices[2]].as_position(),
+                ];
+                let [a, b, c] = positions;
+                let (ax, ay, az) = a.into_xyz();
+                let mut bounds = ([ax, ay, az], [ax, ay, az]);
+                for position in [b, c].iter() {
+                    let (x, y, z) = position.into_xyz();
+                    for (axis, value) in [x, y, z].iter().enumerate() {
+                        if *value < bounds.0[axis] {
+                            bounds.0[axis] = *value;
+                        }
+                        if *value > bounds.1[axis] {
+                            bounds.1[axis] = *value;
+                        }
+                    }
+                }
+                fan.push(Triangle {
+                    face,
+                    indices,
+                    positions,
+                    normal: (b - a).cross(c - a),
+                    bounds,
+                });
+            }
+        }
+        vertices.push(data);
+        faces.push(perimeters);
+        triangles.push(fan);
+    }
+
+    // Intersect the triangles of the graphs. Crossings are computed once
+    // from the segment that they split, so that the triangles that share
+    // the segment agree on their positions.
+    let mut crossings = HashMap::new();
+    let mut segments = vec![
+        vec![SmallVec::<[(Point, Point); 2]>::new(); triangles[0].len()],
+        vec![SmallVec::<[(Point, Point); 2]>::new(); triangles[1].len()],
+    ];
+    for (i, t) in triangles[0].iter().enumerate() {
+        'pairs: for (j, u) in triangles[1].iter().enumerate() {
+            if (0..3).any(|axis| {
+                t.bounds.1[axis] < u.bounds.0[axis] || u.bounds.1[axis] < t.bounds.0[axis]
+            }) {
+                continue;
+            }
+            // Find where each triangle crosses the plane of the other and
+            // parameterize these crossings along the line where the planes
+            // intersect.
+            let line = t.normal.cross(u.normal);
+            let mut intervals = SmallVec::<[_; 2]>::new();
+            for (side, triangle, target, index) in [(0, t, u, j), (1, u, t, i)].iter().cloned() {
+                let origin = target.positions[0];
+                let distance = |position: VertexPosition<G>| target.normal.dot(position - origin);
+                let distances = [
+                    distance(triangle.positions[0]),
+                    distance(triangle.positions[1]),
+                    distance(triangle.positions[2]),
+                ];
+                if distances.iter().all(|distance| *distance > zero)
+                    || distances.iter().all(|distance| *distance < zero)
+                {
+                    continue 'pairs;
+                }
+                if distances.iter().any(|distance| *distance == zero) {
+                    return Err(GraphError::Geometry);
+                }
+                let mut interval = SmallVec::<[_; 2]>::new();
+                for k in 0..3 {
+                    let (m, n) = (k, (k + 1) % 3);
+                    if (distances[m] > zero) == (distances[n] > zero) {
+                        continue;
+                    }
+                    let (a, b) = (triangle.indices[m], triangle.indices[n]);
+                    let (a, b) = (a.min(b), a.max(b));
+                    let point = Point::Crossing(side, (a, b), index);
+                    let position = *crossings.entry(point).or_insert_with(|| {
+                        let pa = *vertices[side][a].as_position();
+                        let pb = *vertices[side][b].as_position();
+                        let (da, db) = (distance(pa), distance(pb));
+                        pa + ((pb - pa) * (da / (da - db)))
+                    });
+                    interval.push((point, line.dot(position - VertexPosition::<G>::origin())));
+                }
+                if interval[0].1 > interval[1].1 {
+                    interval.swap(0, 1);
+                }
+                intervals.push((interval[0], interval[1]));
+            }
+            let ((t0, t1), (u0, u1)) = (intervals[0], intervals[1]);
+            if [u0.1, u1.1].iter().any(|x| *x == t0.1 || *x == t1.1) {
+                return Err(GraphError::Geometry);
+            }
+            let start = if t0.1 > u0.1 { t0 } else { u0 };
+            let end = if t1.1 < u1.1 { t1 } else { u1 };
+            if start.1 < end.1 {
+                segments[0][i].push((start.0, end.0));
+                segments[1][j].push((start.0, end.0));
+            }
+        }
+    }
+    let position = |point: &Point| match *point {
+        Point::Vertex(side, index) => *vertices[side][index].as_position(),
+        _ => crossings[point],
+    };
+
+    // Determine which faces are retained (by whether or not they are inside
+    // of the other graph) and whether or not faces of the other graph are
+    // reversed.
+    let ((is_inside_self, is_inside_other), is_reversed) = match op {
+        BooleanOp::Union => ((false, false), false),
+        BooleanOp::Intersection => ((true, true), false),
+        BooleanOp::Difference => ((false, true), true),
+    };
+    let mut mutation = Mutation::from(MeshGraph::new());
+    let mut keys = HashMap::new();
+    for (side, keep, flip) in [
+        (0, is_inside_self, false),
+        (1, is_inside_other, is_reversed),
+    ]
+    .iter()
+    .cloned()
+    {
+        // Faces that are not split are retained as is. Otherwise, each
+        // triangle of a split face is partitioned by the segments of the
+        // intersection that cross it. The interior point and normal of
+        // each polygon are used to cast a ray.
+        let is_split = triangles[side]
+            .iter()
+            .zip(segments[side].iter())
+            .filter(|(_, segments)| !segments.is_empty())
+            .map(|(triangle, _)| triangle.face)
+            .collect::<HashSet<_>>();
+        let mut is_visited = HashSet::with_capacity(faces[side].len());
+        let mut polygons = vec![];
+        for triangle in triangles[side].iter() {
+            if is_split.contains(&triangle.face) || !is_visited.insert(triangle.face) {
+                continue;
+            }
+            let perimeter = faces[side][triangle.face]
+                .1
+                .iter()
+                .map(|index| Point::Vertex(side, *index))
+                .collect::<SmallVec<[_; 4]>>();
+            let origin = VertexPosition::<G>::centroid(triangle.positions.iter().cloned())
+                .ok_or_else(|| GraphError::Geometry)?;
+            polygons.push((triangle.face, perimeter, origin, triangle.normal));
+        }
+        for (triangle, segments) in triangles[side].iter().zip(segments[side].iter()) {
+            if !is_split.contains(&triangle.face) {
+                continue;
+            }
+            let mut points = vec![];
+            let mut edges = vec![];
+            {
+                let mut indices = HashMap::new();
+                let mut insert = |point: Point| {
+                    *indices.entry(point).or_insert_with(|| {
+                        points.push(point);
+                        points.len() - 1
+                    })
+                };
+                // Insert the crossings along each edge of the triangle in
+                // order.
+                for k in 0..3 {
+                    let (a, b) = (triangle.indices[k], triangle.indices[(k + 1) % 3]);
+                    let (pa, pb) = (triangle.positions[k], triangle.positions[(k + 1) % 3]);
+                    let mut boundary = segments
+                        .iter()
+                        .flat_map(|(p, q)| ArrayVec::from([*p, *q]))
+                        .filter(|point| {
+                            matches!(
+                                *point,
+                                Point::Crossing(target, ab, _)
+                                    if target == side && ab == (a.min(b), a.max(b))
+                            )
+                        })
+                        .map(|point| (point, (position(&point) - pa).dot(pb - pa)))
+                        .collect::<SmallVec<[_; 4]>>();
+                    boundary.sort_by(|(_, x), (_, y)| x.partial_cmp(y).unwrap_or(Ordering::Equal));
+                    let mut previous = insert(Point::Vertex(side, a));
+                    for (point, _) in boundary {
+                        let next = insert(point);
+                        edges.push((previous, next));
+                        previous = next;
+                    }
+                    edges.push((previous, insert(Point::Vertex(side, b))));
+                }
+                for (p, q) in segments.iter() {
+                    edges.push((insert(*p), insert(*q)));
+                }
+            }
+            // Project the points into the plane of the triangle such that
+            // the triangle is wound counter-clockwise.
+            let [a, b, c] = triangle.positions;
+            let x = b - a;
+            let y = x.cross(c - a).cross(x);
+            let planar = points
+                .iter()
+                .map(|point| {
+                    let offset = position(point) - a;
+                    (offset.dot(x), offset.dot(y))
+                })
+                .collect::<Vec<_>>();
+            for polygon in arrangement::faces::<VertexPosition<G>>(&planar, &edges)
+                .ok_or_else(|| GraphError::Geometry)?
+            {
+                let ear = arrangement::ear::<VertexPosition<G>>(&planar, &polygon)
+                    .ok_or_else(|| GraphError::Geometry)?;
+                let origin = VertexPosition::<G>::centroid(
+                    ear.iter().map(|index| position(&points[*index])),
+                )
+                .ok_or_else(|| GraphError::Geometry)?;
+                let perimeter = polygon
+                    .iter()
+                    .map(|index| points[*index])
+                    .collect::<SmallVec<[_; 4]>>();
+                polygons.push((triangle.face, perimeter, origin, triangle.normal));
+            }
+        }
+        for (face, mut perimeter, origin, direction) in polygons {
+            let n = triangles[1 - side]
+                .iter()
+                .filter(|triangle| {
+                    ray::triangle_time_of_impact(origin, direction, triangle.positions).is_some()
+                })
+                .count();
+            if (n % 2 == 1) != keep {
+                continue;
+            }
+            if flip {
+                perimeter.reverse();
+            }
+            let perimeter = perimeter
+                .iter()
+                .map(|point| {
+                    *keys.entry(*point).or_insert_with(|| {
+                        let data = match *point {
+                            Point::Vertex(side, index) => vertices[side][index],
+                            Point::Crossing(side, (index, _), _) => {
+                                let mut data = vertices[side][index];
+                                *data.as_position_mut() = crossings[point];
+                                data
+                            }
+                        };
+                        mutation::vertex::insert(&mut mutation, data)
+                    })
+                })
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            let data = faces[side][face].0;
+            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))?;
+        }
+    }
+    mutation.commit()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point3, Vector3};
+    use num::Zero;
+
+    use crate::graph::{BooleanOp, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    // Computes the volume enclosed by a graph from the signed volumes of the
+    // tetrahedra formed by the origin and the (fan triangulated) faces.
+    fn volume(graph: &MeshGraph<E3>) -> f64 {
+        let mut volume = 0.0;
+        for face in graph.faces() {
+            let positions = face
+                .adjacent_vertices()
+                .map(|vertex| vertex.position().coords.map(|x| x.into_inner()))
+                .collect::<Vec<_>>();
+            for index in 1..positions.len() - 1 {
+                volume += positions[0].dot(&positions[index].cross(&positions[index + 1])) / 6.0;
+            }
+        }
+        volume
+    }
+
+    #[test]
+    fn boolean_disjoint() {
+        let a: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let b: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>()
+            .map_vertices(|position| {
+                position + Vector3::new(R64::from_inner(2.0), Zero::zero(), Zero::zero())
+            })
+            .collect();
+
+        // The cubes do not overlap, so their union includes all faces and
+        // their intersection includes none.
+        let union = a.boolean(&b, BooleanOp::Union).unwrap();
+        assert_eq!(12, union.face_count());
+        assert_eq!(16, union.vertex_count());
+        let intersection = a.boolean(&b, BooleanOp::Intersection).unwrap();
+        assert_eq!(0, intersection.face_count());
+        let difference = a.boolean(&b, BooleanOp::Difference).unwrap();
+        assert_eq!(6, difference.face_count());
+    }
+
+    #[test]
+    fn boolean_overlapping() {
+        let a: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let b: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>()
+            .map_vertices(|position| {
+                position
+                    + Vector3::new(
+                        R64::from_inner(0.5),
+                        R64::from_inner(0.25),
+                        R64::from_inner(0.375),
+                    )
+            })
+            .collect();
+
+        // The cubes overlap in a box with a volume of 0.5 x 0.75 x 0.625. The
+        // faces of the cubes are split where they cross, so the results are
+        // closed and enclose exact volumes.
+        for (op, expected) in [
+            (BooleanOp::Union, 1.765_625),
+            (BooleanOp::Intersection, 0.234_375),
+            (BooleanOp::Difference, 0.765_625),
+        ]
+        .iter()
+        {
+            let graph = a.boolean(&b, *op).unwrap();
+            assert!(graph.is_closed());
+            assert!((volume(&graph) - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn boolean_pierced() {
+        let a: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let b: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>()
+            .map_vertices(|position| {
+                (position * R64::from_inner(0.2))
+                    + Vector3::new(
+                        R64::from_inner(0.5),
+                        R64::from_inner(0.35),
+                        R64::from_inner(-0.05),
+                    )
+            })
+            .collect();
+
+        // The smaller cube pierces a single face of the larger cube, so the
+        // intersection forms a loop within a single triangle of that face.
+        for (op, expected) in [
+            (BooleanOp::Union, 1.004),
+            (BooleanOp::Intersection, 0.004),
+            (BooleanOp::Difference, 0.996),
+        ]
+        .iter()
+        {
+            let graph = a.boolean(&b, *op).unwrap();
+            assert!(graph.is_closed());
+            assert!((volume(&graph) - expected).abs() < 1e-9);
+        }
+    }
+}
@@ -1,13 +1,19 @@
+use smallvec::SmallVec;
+use std::collections::HashSet;
+
 use crate::builder::{FacetBuilder, MeshBuilder, SurfaceBuilder};
+use crate::entity::storage::{AsStorageOf, Fuse, Storage};
 use crate::geometry::{FromGeometry, IntoGeometry};
+use crate::graph::core::{Core, OwnedCore};
 use crate::graph::data::GraphData;
-use crate::graph::face::FaceKey;
+use crate::graph::edge::{Arc, ArcKey, Edge, EdgeKey};
+use crate::graph::face::{Face, FaceKey};
 use crate::graph::mutation::face::{self, FaceInsertCache};
-use crate::graph::mutation::vertex;
-use crate::graph::mutation::Mutation;
-use crate::graph::vertex::VertexKey;
+use crate::graph::mutation::{edge, vertex, Mutation};
+use crate::graph::vertex::{Vertex, VertexKey};
 use crate::graph::{GraphError, MeshGraph};
 use crate::transact::{ClosedInput, Transact};
+use crate::IteratorExt as _;
 
 pub struct GraphBuilder<G>
 where
@@ -27,6 +33,152 @@ where
     }
 }
 
+impl<G> GraphBuilder<G>
+where
+    G: GraphData,
+{
+    /// Inserts a facet, computing the data of its arcs from their endpoints.
+    ///
+    /// The given function is called with the source and destination vertices
+    /// of each arc that is inserted, including the opposite arcs of any new
+    /// edges. Arcs that already exist are not modified. See
+    /// [`MeshGraph::insert_face_with_arc_data`].
+    ///
+    /// [`MeshGraph::insert_face_with_arc_data`]: crate::graph::MeshGraph::insert_face_with_arc_data
+    pub fn insert_facet_with_arc_data<T, U, F>(
+        &mut self,
+        keys: T,
+        geometry: U,
+        f: F,
+    ) -> Result<FaceKey, GraphError>
+    where
+        G::Face: FromGeometry<U>,
+        T: AsRef<[VertexKey]>,
+        F: FnMut(VertexKey, VertexKey) -> G::Arc,
+    {
+        let cache = FaceInsertCache::from_storage(&self.mutation, keys.as_ref())?;
+        let geometry = geometry.into_geometry();
+        face::insert_with_arc_data(&mut self.mutation, cache, move || geometry, f)
+    }
+
+    /// Connects two vertices with an arc.
+    ///
+    /// The arc from the source to the destination vertex is given the data
+    /// `geometry`. If the edge of the arc does not yet exist, then it is
+    /// inserted along with the opposite arc, which is given default data. If
+    /// the arc already exists, such as the opposite arc of a previously
+    /// connected arc, then only its data is written.
+    ///
+    /// Arcs must be joined into rings by [`assemble_facet`] before the builder
+    /// is committed. Committing fails if any arc is neither part of a facet
+    /// nor the opposite of such an arc.
+    ///
+    /// Returns the key of the arc.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertices are the same or are not found.
+    ///
+    /// [`assemble_facet`]: Self::assemble_facet
+    pub fn connect_arc(
+        &mut self,
+        source: VertexKey,
+        destination: VertexKey,
+        geometry: G::Arc,
+    ) -> Result<ArcKey, GraphError> {
+        edge::connect_arc(&mut self.mutation, (source, destination), geometry)
+    }
+
+    /// Inserts a facet bounded by a ring of arcs.
+    ///
+    /// The arcs must exist (see [`connect_arc`]) and must be given in order
+    /// such that the destination vertex of each arc is the source vertex of
+    /// the next arc and the destination vertex of the last arc is the source
+    /// vertex of the first. The data of the arcs is preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than three arcs, if an arc is not
+    /// found, if the arcs do not form a ring, or if the facet cannot be
+    /// inserted (see [`insert_facet`]).
+    ///
+    /// [`connect_arc`]: Self::connect_arc
+    /// [`insert_facet`]: crate::builder::FacetBuilder::insert_facet
+    pub fn assemble_facet<T, U>(&mut self, arcs: T, geometry: U) -> Result<FaceKey, GraphError>
+    where
+        G::Face: FromGeometry<U>,
+        T: AsRef<[ArcKey]>,
+    {
+        let arcs = arcs.as_ref();
+        if arcs.len() < 3 {
+            return Err(GraphError::ArityNonPolygonal);
+        }
+        let storage = self.mutation.as_storage_of::<Arc<G>>();
+        let mut perimeter = SmallVec::<[VertexKey; 4]>::with_capacity(arcs.len());
+        for (ab, bc) in arcs.iter().cloned().perimeter() {
+            if !storage.contains_key(&ab) {
+                return Err(GraphError::TopologyNotFound);
+            }
+            let (a, b): (VertexKey, VertexKey) = ab.into();
+            let (c, _): (VertexKey, VertexKey) = bc.into();
+            if b != c {
+                return Err(GraphError::TopologyMalformed);
+            }
+            perimeter.push(a);
+        }
+        self.insert_facet(perimeter, geometry)
+    }
+
+    /// Closes the surface by inserting a facet into each of its holes.
+    ///
+    /// Each ring of boundary arcs is capped by a facet with default data, so
+    /// the surface built so far becomes closed. This is convenient for
+    /// producing watertight meshes, such as extruded profiles, without
+    /// tracking their boundaries.
+    ///
+    /// Returns the number of inserted facets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a hole cannot be capped by a simple facet, such as
+    /// when a boundary passes through a vertex more than once. Holes that are
+    /// visited before such a hole remain capped.
+    pub fn close(&mut self) -> Result<usize, GraphError> {
+        let arcs = self.mutation.as_storage_of::<Arc<G>>();
+        let mut visited = HashSet::new();
+        let mut perimeters = vec![];
+        for (key, arc) in arcs.iter() {
+            if arc.face.is_some() || !visited.insert(key) {
+                continue;
+            }
+            // Boundary arcs are joined into rings, so the perimeter of the
+            // hole is formed by the source vertices of the arcs in the ring.
+            let mut perimeter = vec![];
+            let mut ab = key;
+            loop {
+                let (a, _): (VertexKey, VertexKey) = ab.into();
+                perimeter.push(a);
+                ab = arcs
+                    .get(&ab)
+                    .and_then(|arc| arc.next)
+                    .ok_or_else(|| GraphError::TopologyMalformed)?;
+                if ab == key {
+                    break;
+                }
+                if !visited.insert(ab) {
+                    return Err(GraphError::TopologyMalformed);
+                }
+            }
+            perimeters.push(perimeter);
+        }
+        for perimeter in perimeters.iter() {
+            let cache = FaceInsertCache::from_storage(&self.mutation, perimeter)?;
+            face::insert_with(&mut self.mutation, cache, Default::default)?;
+        }
+        Ok(perimeters.len())
+    }
+}
+
 impl<G> ClosedInput for GraphBuilder<G>
 where
     G: GraphData,
@@ -108,3 +260,233 @@ where
         face::insert_with(&mut self.mutation, cache, || (Default::default(), geometry))
     }
 }
+
+/// Graph storage with explicitly linked topology.
+///
+/// `CoreBuilder` inserts entities and sets the links between them without
+/// maintaining consistency. This allows importers that already encode
+/// half-edge connectivity to preserve their topology rather than rebuilding it
+/// from index buffers. The links are verified when a graph is created by
+/// [`MeshGraph::from_core_checked`].
+///
+/// The key of an arc is determined by its source and destination vertices.
+///
+/// # Examples
+///
+/// Linking a triangle:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point2;
+/// use plexus::graph::{CoreBuilder, MeshGraph};
+///
+/// let mut core = CoreBuilder::<Point2<f64>>::new();
+/// let vertices = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]
+///     .iter()
+///     .map(|&(x, y)| core.insert_vertex(Point2::new(x, y)))
+///     .collect::<Vec<_>>();
+/// let n = vertices.len();
+/// let mut interior = Vec::with_capacity(n);
+/// let mut exterior = Vec::with_capacity(n);
+/// for i in 0..n {
+///     let (a, b) = (vertices[i], vertices[(i + 1) % n]);
+///     interior.push(core.insert_arc(a, b, ()).unwrap());
+///     exterior.push(core.insert_arc(b, a, ()).unwrap());
+///     core.insert_edge(interior[i], ()).unwrap();
+/// }
+/// let face = core.insert_face(interior[0], ()).unwrap();
+/// for i in 0..n {
+///     let j = (i + 1) % n;
+///     core.set_leading_arc(vertices[i], interior[i]).unwrap();
+///     core.set_arc_face(interior[i], face).unwrap();
+///     // Exterior arcs wind opposite to interior arcs.
+///     core.join_arcs(interior[i], interior[j]).unwrap();
+///     core.join_arcs(exterior[j], exterior[i]).unwrap();
+/// }
+///
+/// let graph = MeshGraph::from_core_checked(core).unwrap();
+/// assert_eq!(1, graph.face_count());
+/// assert_eq!(3, graph.edge_count());
+/// ```
+///
+/// [`MeshGraph::from_core_checked`]: crate::graph::MeshGraph::from_core_checked
+pub struct CoreBuilder<G>
+where
+    G: GraphData,
+{
+    core: OwnedCore<G>,
+}
+
+impl<G> Default for CoreBuilder<G>
+where
+    G: GraphData,
+{
+    fn default() -> Self {
+        CoreBuilder {
+            core: Core::empty()
+                .fuse(Storage::<Vertex<G>>::new())
+                .fuse(Storage::<Arc<G>>::new())
+                .fuse(Storage::<Edge<G>>::new())
+                .fuse(Storage::<Face<G>>::new()),
+        }
+    }
+}
+
+impl<G> CoreBuilder<G>
+where
+    G: GraphData,
+{
+    /// Creates an empty `CoreBuilder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a vertex with no leading arc.
+    pub fn insert_vertex(&mut self, data: G::Vertex) -> VertexKey {
+        self.core
+            .as_storage_mut_of::<Vertex<G>>()
+            .insert(Vertex::new(data))
+    }
+
+    /// Inserts an arc from the source vertex to the destination vertex with no
+    /// links.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either vertex is not found or if the arc already
+    /// exists.
+    pub fn insert_arc(
+        &mut self,
+        source: VertexKey,
+        destination: VertexKey,
+        data: G::Arc,
+    ) -> Result<ArcKey, GraphError> {
+        let ab = ArcKey::from((source, destination));
+        for key in &[source, destination] {
+            if !self.core.as_storage_of::<Vertex<G>>().contains_key(key) {
+                return Err(GraphError::TopologyNotFound.with_entity(*key, "arc insertion"));
+            }
+        }
+        let arcs = self.core.as_storage_mut_of::<Arc<G>>();
+        if arcs.contains_key(&ab) {
+            return Err(GraphError::TopologyConflict.with_entity(ab, "arc insertion"));
+        }
+        arcs.insert_with_key(ab, Arc::new(data));
+        Ok(ab)
+    }
+
+    /// Inserts the edge of an arc and its opposite arc.
+    ///
+    /// The given arc becomes the leading arc of the edge and both arcs are
+    /// linked to the edge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc or its opposite arc is not found.
+    pub fn insert_edge(&mut self, ab: ArcKey, data: G::Edge) -> Result<EdgeKey, GraphError> {
+        let ba = ab.into_opposite();
+        for key in &[ab, ba] {
+            if !self.core.as_storage_of::<Arc<G>>().contains_key(key) {
+                return Err(GraphError::TopologyNotFound.with_entity(*key, "edge insertion"));
+            }
+        }
+        let ab_ba = self
+            .core
+            .as_storage_mut_of::<Edge<G>>()
+            .insert(Edge::new(ab, data));
+        for key in &[ab, ba] {
+            self.with_arc_mut(*key, "edge insertion", |arc| arc.edge = Some(ab_ba))?;
+        }
+        Ok(ab_ba)
+    }
+
+    /// Inserts a face with the given leading arc.
+    ///
+    /// Arcs in the ring of the face must be linked to it by [`set_arc_face`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc is not found.
+    ///
+    /// [`set_arc_face`]: crate::graph::CoreBuilder::set_arc_face
+    pub fn insert_face(&mut self, ab: ArcKey, data: G::Face) -> Result<FaceKey, GraphError> {
+        if !self.core.as_storage_of::<Arc<G>>().contains_key(&ab) {
+            return Err(GraphError::TopologyNotFound.with_entity(ab, "face insertion"));
+        }
+        Ok(self
+            .core
+            .as_storage_mut_of::<Face<G>>()
+            .insert(Face::new(ab, data)))
+    }
+
+    /// Sets the leading arc of a vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex or arc is not found.
+    pub fn set_leading_arc(&mut self, a: VertexKey, ab: ArcKey) -> Result<(), GraphError> {
+        if !self.core.as_storage_of::<Arc<G>>().contains_key(&ab) {
+            return Err(GraphError::TopologyNotFound.with_entity(ab, "vertex link"));
+        }
+        let vertex = self
+            .core
+            .as_storage_mut_of::<Vertex<G>>()
+            .get_mut(&a)
+            .ok_or_else(|| GraphError::TopologyNotFound.with_entity(a, "vertex link"))?;
+        vertex.arc = Some(ab);
+        Ok(())
+    }
+
+    /// Joins two arcs such that the second arc is the next arc of the first
+    /// and the first arc is the previous arc of the second.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either arc is not found.
+    pub fn join_arcs(&mut self, ab: ArcKey, bc: ArcKey) -> Result<(), GraphError> {
+        for key in &[ab, bc] {
+            if !self.core.as_storage_of::<Arc<G>>().contains_key(key) {
+                return Err(GraphError::TopologyNotFound.with_entity(*key, "arc link"));
+            }
+        }
+        self.with_arc_mut(ab, "arc link", |arc| arc.next = Some(bc))?;
+        self.with_arc_mut(bc, "arc link", |arc| arc.previous = Some(ab))
+    }
+
+    /// Sets the face of an arc.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc or face is not found.
+    pub fn set_arc_face(&mut self, ab: ArcKey, abc: FaceKey) -> Result<(), GraphError> {
+        if !self.core.as_storage_of::<Face<G>>().contains_key(&abc) {
+            return Err(GraphError::TopologyNotFound.with_entity(abc, "arc link"));
+        }
+        self.with_arc_mut(ab, "arc link", |arc| arc.face = Some(abc))
+    }
+
+    pub(in crate::graph) fn into_core(self) -> OwnedCore<G> {
+        let CoreBuilder { core } = self;
+        core
+    }
+
+    fn with_arc_mut<F>(
+        &mut self,
+        ab: ArcKey,
+        operation: &'static str,
+        f: F,
+    ) -> Result<(), GraphError>
+    where
+        F: FnOnce(&mut Arc<G>),
+    {
+        let arc = self
+            .core
+            .as_storage_mut_of::<Arc<G>>()
+            .get_mut(&ab)
+            .ok_or_else(|| GraphError::TopologyNotFound.with_entity(ab, operation))?;
+        f(arc);
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, Storage};
 use crate::graph::data::{GraphData, Parametric};
 use crate::graph::edge::{Arc, Edge};
 use crate::graph::face::Face;
+use crate::graph::geometry::{GeometryPolicy, Handedness};
 use crate::graph::vertex::Vertex;
 
 /// A complete core that owns all of its storage.
@@ -40,6 +41,13 @@ pub type RefCore<'a, G> = Core<
 /// expose storage to yet unfused entities.
 ///
 /// A `Core` with no unfused fields is _complete_.
+///
+/// Cores also carry the number of boundary arcs in their arc storage. This
+/// count is not computed when storage is fused. Instead, it is maintained by
+/// the mutation API as arcs are inserted, removed, and connected to faces.
+/// Cores that are assembled from arbitrary storage must count their boundary
+/// arcs (see `Core::with_boundary_arc_count` and
+/// `Core::recount_boundary_arcs`).
 pub struct Core<G, V = (), A = (), E = (), F = ()>
 where
     G: GraphData,
@@ -48,6 +56,7 @@ where
     arcs: A,
     edges: E,
     faces: F,
+    boundary_arc_count: usize,
     phantom: PhantomData<G>,
 }
 
@@ -61,6 +70,7 @@ where
             arcs: (),
             edges: (),
             faces: (),
+            boundary_arc_count: 0,
             phantom: PhantomData,
         }
     }
@@ -80,6 +90,35 @@ where
         } = self;
         (vertices, arcs, edges, faces)
     }
+
+    pub fn boundary_arc_count(&self) -> usize {
+        self.boundary_arc_count
+    }
+
+    pub fn with_boundary_arc_count(self, boundary_arc_count: usize) -> Self {
+        Core {
+            boundary_arc_count,
+            ..self
+        }
+    }
+}
+
+impl<G, V, A, E, F> Core<G, V, A, E, F>
+where
+    A: AsStorage<Arc<G>>,
+    G: GraphData,
+{
+    // This is $O(n)$ in the number of arcs and should only be used for cores
+    // that are not built by the mutation API.
+    pub fn recount_boundary_arcs(self) -> Self {
+        let boundary_arc_count = self
+            .arcs
+            .as_storage()
+            .iter()
+            .filter(|(_, arc)| arc.face.is_none())
+            .count();
+        self.with_boundary_arc_count(boundary_arc_count)
+    }
 }
 
 impl<G, V, A, E, F> AsStorage<Vertex<G>> for Core<G, V, A, E, F>
@@ -171,13 +210,18 @@ where
 
     fn fuse(self, vertices: V) -> Self::Output {
         let Core {
-            arcs, edges, faces, ..
+            arcs,
+            edges,
+            faces,
+            boundary_arc_count,
+            ..
         } = self;
         Core {
             vertices,
             arcs,
             edges,
             faces,
+            boundary_arc_count,
             phantom: PhantomData,
         }
     }
@@ -195,6 +239,7 @@ where
             vertices,
             edges,
             faces,
+            boundary_arc_count,
             ..
         } = self;
         Core {
@@ -202,6 +247,7 @@ where
             arcs,
             edges,
             faces,
+            boundary_arc_count,
             phantom: PhantomData,
         }
     }
@@ -219,6 +265,7 @@ where
             vertices,
             arcs,
             faces,
+            boundary_arc_count,
             ..
         } = self;
         Core {
@@ -226,6 +273,7 @@ where
             arcs,
             edges,
             faces,
+            boundary_arc_count,
             phantom: PhantomData,
         }
     }
@@ -243,6 +291,7 @@ where
             vertices,
             arcs,
             edges,
+            boundary_arc_count,
             ..
         } = self;
         Core {
@@ -250,6 +299,7 @@ where
             arcs,
             edges,
             faces,
+            boundary_arc_count,
             phantom: PhantomData,
         }
     }
@@ -270,4 +320,11 @@ where
     type Arc = G::Arc;
     type Edge = G::Edge;
     type Face = G::Face;
+
+    const GEOMETRY_POLICY: GeometryPolicy = G::GEOMETRY_POLICY;
+    const HANDEDNESS: Handedness = G::HANDEDNESS;
+
+    fn blend_vertices(vertices: &[Self::Vertex]) -> Self::Vertex {
+        G::blend_vertices(vertices)
+    }
 }
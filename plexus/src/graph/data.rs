@@ -1,9 +1,16 @@
 use crate::entity::borrow::Reborrow;
+use crate::graph::geometry::{GeometryPolicy, Handedness};
 
 pub type Data<M> = <M as Parametric>::Data;
 
-// TODO: Require `Clone` instead of `Copy` once non-`Copy` types are supported
-//       by the slotmap crate. See https://github.com/orlp/slotmap/issues/27
+// Graph data is `Copy` rather than `Clone`. The slot maps that store vertices,
+// edges, and faces only support `Copy` values in the version of slotmap in use
+// (see https://github.com/orlp/slotmap/issues/27), and graph operations copy
+// data out of views throughout (e.g., `vertex.data`). A `Clone`-only backend
+// selected via `Entity::Storage` would not lift this bound, because graph
+// entities fix their storage and `GraphData` cannot select it without breaking
+// every implementation. Relaxing this bound requires both a storage that
+// supports `Clone` values and explicit clones in graph operations.
 /// Graph data.
 ///
 /// Specifies the types used to represent data in vertices, arcs, edges, and
@@ -70,6 +77,80 @@ pub trait GraphData: Sized {
     type Arc: Copy + Default;
     type Edge: Copy + Default;
     type Face: Copy + Default;
+
+    /// Policy for non-finite results of geometric operations.
+    ///
+    /// By default, non-finite results are returned as is (see
+    /// [`GeometryPolicy::Lenient`]).
+    ///
+    /// [`GeometryPolicy::Lenient`]: crate::graph::GeometryPolicy::Lenient
+    const GEOMETRY_POLICY: GeometryPolicy = GeometryPolicy::Lenient;
+
+    /// Winding convention of faces.
+    ///
+    /// By default, counter-clockwise winding is front-facing (see
+    /// [`Handedness::Right`]). Graphs built from clockwise faces should use
+    /// [`Handedness::Left`] so that face normals point outward.
+    ///
+    /// [`Handedness::Left`]: crate::graph::Handedness::Left
+    /// [`Handedness::Right`]: crate::graph::Handedness::Right
+    const HANDEDNESS: Handedness = Handedness::Right;
+
+    /// Blends the data of vertices into the data of an inserted vertex.
+    ///
+    /// Operations that insert a vertex amongst existing vertices, such as
+    /// [`ArcView::split_at_midpoint`] and [`FaceView::poke_at_centroid`], use
+    /// this function to compute the data of the inserted vertex from the data
+    /// of the vertices that surround it. The given slice is never empty. The
+    /// position of the inserted vertex is always computed by the operation
+    /// and written after blending, so implementations need only blend other
+    /// data, such as colors.
+    ///
+    /// By default, the data of the first vertex is copied.
+    ///
+    /// # Examples
+    ///
+    /// Blending vertex colors:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::{Point3, Vector4};
+    /// use plexus::graph::GraphData;
+    ///
+    /// #[derive(Clone, Copy)]
+    /// pub struct Vertex {
+    ///     pub position: Point3<R64>,
+    ///     pub color: Vector4<R64>,
+    /// }
+    ///
+    /// impl GraphData for Vertex {
+    ///     type Vertex = Self;
+    ///     type Arc = ();
+    ///     type Edge = ();
+    ///     type Face = ();
+    ///
+    ///     fn blend_vertices(vertices: &[Self::Vertex]) -> Self::Vertex {
+    ///         let n = R64::from_inner(vertices.len() as f64);
+    ///         let color = vertices
+    ///             .iter()
+    ///             .fold(Vector4::zeros(), |sum, vertex| sum + vertex.color);
+    ///         Vertex {
+    ///             color: color / n,
+    ///             ..vertices[0]
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`ArcView::split_at_midpoint`]: crate::graph::ArcView::split_at_midpoint
+    /// [`FaceView::poke_at_centroid`]: crate::graph::FaceView::poke_at_centroid
+    fn blend_vertices(vertices: &[Self::Vertex]) -> Self::Vertex {
+        vertices[0]
+    }
 }
 
 impl GraphData for () {
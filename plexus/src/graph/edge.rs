@@ -4,7 +4,6 @@ use fool::BoolExt;
 use slotmap::DefaultKey;
 use std::borrow::Borrow;
 use std::hash::{Hash, Hasher};
-use std::mem;
 use std::ops::{Deref, DerefMut};
 use theon::space::{EuclideanSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
@@ -17,13 +16,14 @@ use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::face::{Face, FaceKey, FaceOrphan, FaceView, Ring};
 use crate::graph::geometry::{ArcNormal, EdgeMidpoint, VertexPosition};
 use crate::graph::mutation::edge::{
-    self, ArcBridgeCache, ArcExtrudeCache, EdgeRemoveCache, EdgeSplitCache,
+    self, ArcBridgeCache, ArcExtrudeCache, EdgeCollapseCache, EdgeRemoveCache, EdgeSplitCache,
 };
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
 use crate::graph::{GraphError, OptionExt as _, ResultExt as _, Selector};
-use crate::transact::{Mutate, Transact};
+use crate::transact::Transact;
+use crate::IteratorExt as _;
 
 pub trait ToArc<B>: Sized
 where
@@ -191,6 +191,50 @@ where
     pub fn is_boundary_arc(&self) -> bool {
         self.face.is_none()
     }
+
+    /// Gets the corner data of the arc.
+    ///
+    /// An arc uniquely identifies a corner: its source vertex within its face.
+    /// Arc data can therefore represent per-corner (or _wedge_) attributes,
+    /// such as normals and texture coordinates that are discontinuous across
+    /// hard edges and seams. Returns `None` if the arc is a boundary arc, which
+    /// has no face and therefore no corner.
+    ///
+    /// Note that [`MeshBuffer`] conversions read vertex data and do not export
+    /// arc data, so corner attributes are not preserved when a graph is
+    /// converted. To preserve seams, split vertices along seams such that each
+    /// vertex has uniform attributes before converting. Corner attributes can
+    /// be written to OBJ via `ToObj::write_obj_with`.
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    pub fn corner_data(&self) -> Option<&<Data<B> as GraphData>::Arc> {
+        if self.is_boundary_arc() {
+            None
+        }
+        else {
+            Some(&self.data)
+        }
+    }
+}
+
+impl<B, M> ArcView<B>
+where
+    B: ReborrowMut<Target = M>,
+    M: AsStorageMut<Arc<Data<B>>> + Parametric,
+{
+    /// Gets the mutable corner data of the arc.
+    ///
+    /// Returns `None` if the arc is a boundary arc. See [`corner_data`].
+    ///
+    /// [`corner_data`]: crate::graph::ArcView::corner_data
+    pub fn corner_data_mut(&mut self) -> Option<&mut <Data<B> as GraphData>::Arc> {
+        if self.is_boundary_arc() {
+            None
+        }
+        else {
+            Some(&mut self.data)
+        }
+    }
 }
 
 impl<B, M> ArcView<B>
@@ -313,6 +357,27 @@ where
         self.into_reachable_previous_arc().expect_consistent()
     }
 
+    /// Converts the arc into the next outgoing arc about its source vertex.
+    ///
+    /// This is the next arc of the opposite arc. For faces that are wound
+    /// counter-clockwise, this rotates clockwise about the source vertex. If
+    /// the opposite arc is a boundary arc, then the rotation crosses the
+    /// boundary.
+    pub fn into_next_around_source(self) -> Self {
+        self.into_opposite_arc().into_next_arc()
+    }
+
+    /// Converts the arc into the previous outgoing arc about its source
+    /// vertex.
+    ///
+    /// This is the opposite arc of the previous arc and is the inverse of
+    /// [`into_next_around_source`].
+    ///
+    /// [`into_next_around_source`]: crate::graph::ArcView::into_next_around_source
+    pub fn into_previous_around_source(self) -> Self {
+        self.into_previous_arc().into_opposite_arc()
+    }
+
     /// Gets the ring of the arc.
     pub fn ring(&self) -> Ring<&M> {
         self.to_ref().into()
@@ -337,6 +402,131 @@ where
     pub fn previous_arc(&self) -> ArcView<&M> {
         self.to_ref().into_previous_arc()
     }
+
+    /// Converts the arc into its opposite arc.
+    ///
+    /// Unlike [`into_opposite_arc`], this function does not panic if the arc
+    /// cannot be found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc cannot be found, which indicates that the
+    /// graph is malformed.
+    ///
+    /// [`into_opposite_arc`]: crate::graph::ArcView::into_opposite_arc
+    pub fn try_into_opposite_arc(self) -> Result<Self, GraphError> {
+        self.into_reachable_opposite_arc()
+            .ok_or_else(|| GraphError::TopologyMalformed)
+    }
+
+    /// Converts the arc into its next arc.
+    ///
+    /// Unlike [`into_next_arc`], this function does not panic if the arc
+    /// cannot be found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc cannot be found, which indicates that the
+    /// graph is malformed.
+    ///
+    /// [`into_next_arc`]: crate::graph::ArcView::into_next_arc
+    pub fn try_into_next_arc(self) -> Result<Self, GraphError> {
+        self.into_reachable_next_arc()
+            .ok_or_else(|| GraphError::TopologyMalformed)
+    }
+
+    /// Converts the arc into its previous arc.
+    ///
+    /// Unlike [`into_previous_arc`], this function does not panic if the arc
+    /// cannot be found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc cannot be found, which indicates that the
+    /// graph is malformed.
+    ///
+    /// [`into_previous_arc`]: crate::graph::ArcView::into_previous_arc
+    pub fn try_into_previous_arc(self) -> Result<Self, GraphError> {
+        self.into_reachable_previous_arc()
+            .ok_or_else(|| GraphError::TopologyMalformed)
+    }
+
+    /// Gets the opposite arc.
+    ///
+    /// See [`try_into_opposite_arc`].
+    ///
+    /// [`try_into_opposite_arc`]: crate::graph::ArcView::try_into_opposite_arc
+    pub fn try_opposite_arc(&self) -> Result<ArcView<&M>, GraphError> {
+        self.to_ref().try_into_opposite_arc()
+    }
+
+    /// Gets the next arc.
+    ///
+    /// See [`try_into_next_arc`].
+    ///
+    /// [`try_into_next_arc`]: crate::graph::ArcView::try_into_next_arc
+    pub fn try_next_arc(&self) -> Result<ArcView<&M>, GraphError> {
+        self.to_ref().try_into_next_arc()
+    }
+
+    /// Gets the previous arc.
+    ///
+    /// See [`try_into_previous_arc`].
+    ///
+    /// [`try_into_previous_arc`]: crate::graph::ArcView::try_into_previous_arc
+    pub fn try_previous_arc(&self) -> Result<ArcView<&M>, GraphError> {
+        self.to_ref().try_into_previous_arc()
+    }
+
+    /// Gets the next outgoing arc about the source vertex.
+    ///
+    /// This is equivalent to `arc.opposite_arc().next_arc()`. See
+    /// [`into_next_around_source`].
+    ///
+    /// # Examples
+    ///
+    /// Circulating about the source vertex of an arc:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let arc = graph.arcs().nth(0).unwrap();
+    ///
+    /// let mut next = arc.next_around_source();
+    /// let mut n = 1;
+    /// while next.key() != arc.key() {
+    ///     next = next.next_around_source();
+    ///     n += 1;
+    /// }
+    /// assert_eq!(3, n);
+    /// ```
+    ///
+    /// [`into_next_around_source`]: crate::graph::ArcView::into_next_around_source
+    pub fn next_around_source(&self) -> ArcView<&M> {
+        self.to_ref().into_next_around_source()
+    }
+
+    /// Gets the previous outgoing arc about the source vertex.
+    ///
+    /// This is equivalent to `arc.previous_arc().opposite_arc()`. See
+    /// [`into_previous_around_source`].
+    ///
+    /// [`into_previous_around_source`]: crate::graph::ArcView::into_previous_around_source
+    pub fn previous_around_source(&self) -> ArcView<&M> {
+        self.to_ref().into_previous_around_source()
+    }
 }
 
 /// Reachable API.
@@ -392,6 +582,56 @@ where
     pub fn destination_vertex(&self) -> VertexView<&M> {
         self.to_ref().into_destination_vertex()
     }
+
+    /// Converts the arc into its source vertex.
+    ///
+    /// Unlike [`into_source_vertex`], this function does not panic if the
+    /// vertex cannot be found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex cannot be found, which indicates that
+    /// the graph is malformed.
+    ///
+    /// [`into_source_vertex`]: crate::graph::ArcView::into_source_vertex
+    pub fn try_into_source_vertex(self) -> Result<VertexView<B>, GraphError> {
+        self.into_reachable_source_vertex()
+            .ok_or_else(|| GraphError::TopologyMalformed)
+    }
+
+    /// Converts the arc into its destination vertex.
+    ///
+    /// Unlike [`into_destination_vertex`], this function does not panic if
+    /// the vertex cannot be found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex cannot be found, which indicates that
+    /// the graph is malformed.
+    ///
+    /// [`into_destination_vertex`]: crate::graph::ArcView::into_destination_vertex
+    pub fn try_into_destination_vertex(self) -> Result<VertexView<B>, GraphError> {
+        self.into_reachable_destination_vertex()
+            .ok_or_else(|| GraphError::TopologyMalformed)
+    }
+
+    /// Gets the source vertex of the arc.
+    ///
+    /// See [`try_into_source_vertex`].
+    ///
+    /// [`try_into_source_vertex`]: crate::graph::ArcView::try_into_source_vertex
+    pub fn try_source_vertex(&self) -> Result<VertexView<&M>, GraphError> {
+        self.to_ref().try_into_source_vertex()
+    }
+
+    /// Gets the destination vertex of the arc.
+    ///
+    /// See [`try_into_destination_vertex`].
+    ///
+    /// [`try_into_destination_vertex`]: crate::graph::ArcView::try_into_destination_vertex
+    pub fn try_destination_vertex(&self) -> Result<VertexView<&M>, GraphError> {
+        self.to_ref().try_into_destination_vertex()
+    }
 }
 
 /// Reachable API.
@@ -422,6 +662,31 @@ where
     pub fn edge(&self) -> EdgeView<&M> {
         self.to_ref().into_edge()
     }
+
+    /// Converts the arc into its edge.
+    ///
+    /// Unlike [`into_edge`], this function does not panic if the edge cannot
+    /// be found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the edge cannot be found, which indicates that the
+    /// graph is malformed.
+    ///
+    /// [`into_edge`]: crate::graph::ArcView::into_edge
+    pub fn try_into_edge(self) -> Result<EdgeView<B>, GraphError> {
+        self.into_reachable_edge()
+            .ok_or_else(|| GraphError::TopologyMalformed)
+    }
+
+    /// Gets the edge of the arc.
+    ///
+    /// See [`try_into_edge`].
+    ///
+    /// [`try_into_edge`]: crate::graph::ArcView::try_into_edge
+    pub fn try_edge(&self) -> Result<EdgeView<&M>, GraphError> {
+        self.to_ref().try_into_edge()
+    }
 }
 
 /// Reachable API.
@@ -456,6 +721,22 @@ where
     pub fn face(&self) -> Option<FaceView<&M>> {
         self.to_ref().into_face()
     }
+
+    /// Converts the arc into the face of its opposite arc.
+    ///
+    /// This is the face on the other side of the arc's edge. If the opposite
+    /// arc is a boundary arc, then `None` is returned.
+    pub fn into_neighbor_face(self) -> Option<FaceView<B>> {
+        self.into_opposite_arc().into_face()
+    }
+
+    /// Gets the face of the opposite arc.
+    ///
+    /// This is the face on the other side of the arc's edge. If the opposite
+    /// arc is a boundary arc, then `None` is returned.
+    pub fn neighbor_face(&self) -> Option<FaceView<&M>> {
+        self.to_ref().into_neighbor_face()
+    }
 }
 
 impl<B, M, G> ArcView<B>
@@ -550,7 +831,15 @@ where
     pub fn into_adjacent_vertex_orphans(
         self,
     ) -> impl ExactSizeIterator<Item = VertexOrphan<'a, G>> {
-        VertexCirculator::from(self)
+        let keys = self
+            .to_ref()
+            .adjacent_vertices()
+            .keys()
+            .collect::<ArrayVec<[_; 2]>>();
+        let (storage, _) = self.unbind();
+        Orphan::bind_disjoint_into(storage, &keys)
+            .expect_consistent()
+            .into_iter()
     }
 }
 
@@ -573,7 +862,15 @@ where
     G: 'a + GraphData,
 {
     pub fn into_adjacent_face_orphans(self) -> impl ExactSizeIterator<Item = FaceOrphan<'a, G>> {
-        FaceCirculator::from(self)
+        let keys = self
+            .to_ref()
+            .adjacent_faces()
+            .keys()
+            .collect::<ArrayVec<[_; 2]>>();
+        let (storage, _) = self.unbind();
+        Orphan::bind_disjoint_into(storage, &keys)
+            .expect_consistent()
+            .into_iter()
     }
 }
 
@@ -594,7 +891,6 @@ where
         + AsStorage<Edge<G>>
         + AsStorage<Face<G>>
         + AsStorage<Vertex<G>>
-        + Default
         + Mutable<Data = G>,
     G: GraphData,
 {
@@ -607,6 +903,13 @@ where
     /// $\overrightarrow{MB}$ and is a part of the same ring as the initiating
     /// arc.
     ///
+    /// Splitting does not split the faces of the composite edge. Instead, $M$
+    /// is inserted into the rings of both arcs and so the arity of each
+    /// adjacent face increases by one. For example, splitting an edge between
+    /// two triangles yields two quadrilaterals. Faces can be split afterwards
+    /// as needed, such as by connecting $M$ to another vertex via
+    /// [`FaceView::split`].
+    ///
     /// Returns the inserted vertex.
     ///
     /// # Examples
@@ -634,25 +937,47 @@ where
     /// let vertex = graph.arc_mut(key).unwrap().split_with(|| 0.1);
     /// ```
     ///
+    /// [`FaceView::split`]: crate::graph::FaceView::split
     /// [`MeshGraph`]: crate::graph::MeshGraph
     pub fn split_with<F>(self, f: F) -> VertexView<&'a mut M>
     where
         F: FnOnce() -> G::Vertex,
     {
-        // This should never fail here.
-        let cache = EdgeSplitCache::from_arc(self.to_ref()).expect_consistent();
+        self.try_split_with(f).expect_consistent()
+    }
+
+    /// Splits the composite edge of the arc into two adjacent edges that share
+    /// a vertex.
+    ///
+    /// Unlike [`split_with`], this function does not panic if the mutation
+    /// fails.
+    ///
+    /// Returns the inserted vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the topology of the arc is malformed. The topology
+    /// is validated before the graph is modified, so the graph is not
+    /// modified in this case.
+    ///
+    /// [`split_with`]: crate::graph::ArcView::split_with
+    pub fn try_split_with<F>(self, f: F) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        F: FnOnce() -> G::Vertex,
+    {
+        let cache = EdgeSplitCache::from_arc(self.to_ref())?;
         let (storage, _) = self.unbind();
-        Mutation::replace(storage, Default::default())
+        Mutation::from_graph(storage)
             .commit_with(|mutation| edge::split_with(mutation, cache, f))
             .map(|(storage, m)| Bind::bind(storage, m).expect_consistent())
-            .expect_consistent()
     }
 
     /// Splits the composite edge of the arc at its midpoint.
     ///
-    /// Splitting inserts a new vertex with the data of the arc's source vertex
-    /// but modified such that the position of the vertex is the computed
-    /// midpoint of both of the arc's vertices.
+    /// Splitting inserts a new vertex with the data of the arc's vertices
+    /// blended by [`GraphData::blend_vertices`] but modified such that the
+    /// position of the vertex is the computed midpoint of both of the arc's
+    /// vertices.
     ///
     /// Splitting inserts a new vertex with data provided by the given function.
     /// Splitting an arc $\overrightarrow{AB}$ returns a vertex $M$ that
@@ -660,6 +985,9 @@ where
     /// $\overrightarrow{MB}$ and is a part of the same ring as the initiating
     /// arc.
     ///
+    /// As with [`split_with`], the faces of the composite edge are not split and
+    /// their arity increases by one.
+    ///
     /// This function is only available if a [`MeshGraph`] exposes positional
     /// data in its vertices and that data supports interpolation. See the
     /// [`EdgeMidpoint`] trait.
@@ -689,13 +1017,16 @@ where
     /// ```
     ///
     /// [`EdgeMidpoint`]: crate::graph::EdgeMidpoint
+    /// [`GraphData::blend_vertices`]: crate::graph::GraphData::blend_vertices
     /// [`MeshGraph`]: crate::graph::MeshGraph
+    /// [`split_with`]: crate::graph::ArcView::split_with
     pub fn split_at_midpoint(self) -> VertexView<&'a mut M>
     where
         G: EdgeMidpoint,
         G::Vertex: AsPositionMut,
     {
-        let mut geometry = self.source_vertex().data;
+        let mut geometry =
+            G::blend_vertices(&[self.source_vertex().data, self.destination_vertex().data]);
         let midpoint = self.midpoint();
         self.split_with(move || {
             *geometry.as_position_mut() = midpoint;
@@ -789,10 +1120,9 @@ where
         })?;
         let cache = ArcBridgeCache::from_arc(self.to_ref(), destination)?;
         let (storage, _) = self.unbind();
-        Ok(Mutation::replace(storage, Default::default())
+        Mutation::from_graph(storage)
             .commit_with(|mutation| edge::bridge(mutation, cache))
             .map(|(storage, face)| Bind::bind(storage, face).expect_consistent())
-            .expect_consistent())
     }
 
     /// Extrudes the arc along its normal.
@@ -891,10 +1221,9 @@ where
     {
         let cache = ArcExtrudeCache::from_arc(self.to_ref())?;
         let (storage, _) = self.unbind();
-        Ok(Mutation::replace(storage, Default::default())
+        Mutation::from_graph(storage)
             .commit_with(|mutation| edge::extrude_with(mutation, cache, f))
             .map(|(storage, arc)| Bind::bind(storage, arc).expect_consistent())
-            .expect_consistent())
     }
 
     /// Removes the arc and its composite edge.
@@ -906,14 +1235,135 @@ where
     /// becomes disjoint and is also removed. If an arc $\overrightarrow{AB}$ is
     /// removed and its source vertex is not disjoint, then $A$ is returned.
     pub fn remove(self) -> Option<VertexView<&'a mut M>> {
+        self.try_remove().expect_consistent()
+    }
+
+    /// Removes the arc and its composite edge.
+    ///
+    /// Unlike [`remove`], this function does not panic if the mutation fails.
+    ///
+    /// Returns the source vertex of the initiating arc or `None` if that
+    /// vertex becomes disjoint and is also removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the topology of the arc is malformed. The topology
+    /// is validated before the graph is modified, so the graph is not
+    /// modified in this case.
+    ///
+    /// [`remove`]: crate::graph::ArcView::remove
+    pub fn try_remove(self) -> Result<Option<VertexView<&'a mut M>>, GraphError> {
         let a = self.source_vertex().key();
-        // This should never fail here.
-        let cache = EdgeRemoveCache::from_arc(self.to_ref()).expect_consistent();
+        let cache = EdgeRemoveCache::from_arc(self.to_ref())?;
         let (storage, _) = self.unbind();
-        Mutation::replace(storage, Default::default())
+        Mutation::from_graph(storage)
             .commit_with(|mutation| edge::remove(mutation, cache))
             .map(|(storage, _)| Bind::bind(storage, a))
-            .expect_consistent()
+    }
+
+    /// Collapses the composite edge of the arc into its source vertex.
+    ///
+    /// Collapsing an arc $\overrightarrow{AB}$ removes the vertex $B$ and
+    /// connects its adjacent vertices to $A$. Triangles that are connected to
+    /// the composite edge are removed and other faces that are connected to
+    /// $B$ are connected to $A$ instead. The data of $A$ is not modified; see
+    /// [`collapse_with`] to combine the data of $A$ and $B$.
+    ///
+    /// Returns the source vertex $A$.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either vertex of the edge is on a boundary or if
+    /// the collapse would produce non-manifold topology or a vertex with a
+    /// valence less than three. These conditions are checked before the graph
+    /// is modified, so the graph is not modified in these cases.
+    ///
+    /// # Examples
+    ///
+    /// Collapsing an edge of an icosahedron:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::platonic::Icosahedron;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Icosahedron::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.arcs().nth(0).unwrap().key();
+    /// let vertex = graph.arc_mut(key).unwrap().collapse().unwrap();
+    ///
+    /// assert_eq!(6, vertex.valence());
+    /// assert_eq!(11, graph.vertex_count());
+    /// assert_eq!(18, graph.face_count());
+    /// ```
+    ///
+    /// [`collapse_with`]: crate::graph::ArcView::collapse_with
+    pub fn collapse(self) -> Result<VertexView<&'a mut M>, GraphError> {
+        self.collapse_with(|a, _| *a)
+    }
+
+    /// Collapses the composite edge of the arc into its source vertex and
+    /// combines the data of its vertices.
+    ///
+    /// Behaves like [`collapse`], but the data of the source vertex $A$ is
+    /// replaced by the result of the given function, which receives the data
+    /// of $A$ and the removed vertex $B$, respectively.
+    ///
+    /// Returns the source vertex $A$.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`collapse`].
+    ///
+    /// # Examples
+    ///
+    /// Collapsing an edge of an icosahedron into its midpoint:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::platonic::Icosahedron;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Icosahedron::new().polygons::<Position<E3>>().collect();
+    /// let arc = graph.arcs().nth(0).unwrap();
+    /// let (key, midpoint) = (arc.key(), arc.midpoint());
+    /// let vertex = graph
+    ///     .arc_mut(key)
+    ///     .unwrap()
+    ///     .collapse_with(|_, _| midpoint)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(6, vertex.valence());
+    /// assert_eq!(11, graph.vertex_count());
+    /// ```
+    ///
+    /// [`collapse`]: crate::graph::ArcView::collapse
+    pub fn collapse_with<F>(self, f: F) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        F: FnMut(&G::Vertex, &G::Vertex) -> G::Vertex,
+    {
+        let cache = EdgeCollapseCache::from_arc(self.to_ref())?;
+        let (storage, _) = self.unbind();
+        Mutation::from_graph(storage)
+            .commit_with(|mutation| edge::collapse_with(mutation, cache, f))
+            .map(|(storage, a)| Bind::bind(storage, a).expect_consistent())
     }
 }
 
@@ -1289,6 +1739,31 @@ where
         self.to_ref().into_arc()
     }
 
+    /// Converts the edge into its leading arc.
+    ///
+    /// Unlike [`into_arc`], this function does not panic if the arc cannot be
+    /// found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc cannot be found, which indicates that the
+    /// graph is malformed.
+    ///
+    /// [`into_arc`]: crate::graph::EdgeView::into_arc
+    pub fn try_into_arc(self) -> Result<ArcView<B>, GraphError> {
+        self.into_reachable_arc()
+            .ok_or_else(|| GraphError::TopologyMalformed)
+    }
+
+    /// Gets the leading arc of the edge.
+    ///
+    /// See [`try_into_arc`].
+    ///
+    /// [`try_into_arc`]: crate::graph::EdgeView::try_into_arc
+    pub fn try_arc(&self) -> Result<ArcView<&M>, GraphError> {
+        self.to_ref().try_into_arc()
+    }
+
     pub fn is_boundary_edge(&self) -> bool {
         let arc = self.arc();
         arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc()
@@ -1314,6 +1789,46 @@ where
     }
 }
 
+impl<B, M, G> EdgeView<B>
+where
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    /// Gets the face on the left side of the edge.
+    ///
+    /// The left face is the face of the leading arc of the edge (see
+    /// [`arc`]). Because faces are wound counter-clockwise, this face lies to
+    /// the left of the leading arc. Returns `None` if the leading arc is a
+    /// boundary arc.
+    ///
+    /// [`arc`]: crate::graph::EdgeView::arc
+    pub fn left_face(&self) -> Option<FaceView<&M>> {
+        self.arc().into_face()
+    }
+
+    /// Gets the face on the right side of the edge.
+    ///
+    /// The right face is the face of the arc opposite the leading arc of the
+    /// edge. Returns `None` if that arc is a boundary arc.
+    pub fn right_face(&self) -> Option<FaceView<&M>> {
+        self.arc().into_neighbor_face()
+    }
+
+    /// Gets an iterator of views over the faces on either side of the edge.
+    ///
+    /// The left face, if any, is yielded before the right face.
+    pub fn faces(&self) -> impl Clone + Iterator<Item = FaceView<&M>> {
+        ArrayVec::from([self.left_face(), self.right_face()])
+            .into_iter()
+            .flatten()
+    }
+}
+
 impl<B> Borrow<EdgeKey> for EdgeView<B>
 where
     B: Reborrow,
@@ -1635,26 +2150,6 @@ where
     }
 }
 
-impl<'a, M, G> Iterator for VertexCirculator<&'a mut M>
-where
-    M: AsStorageMut<Vertex<G>> + Parametric<Data = G>,
-    G: 'a + GraphData,
-{
-    type Item = VertexOrphan<'a, G>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        VertexCirculator::next(self).map(|key| {
-            let vertex = self.storage.as_storage_mut().get_mut(&key).unwrap();
-            let vertex = unsafe { mem::transmute::<&'_ mut Vertex<G>, &'a mut Vertex<G>>(vertex) };
-            Orphan::bind_unchecked(vertex, key).into()
-        })
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.inner.len()))
-    }
-}
-
 pub struct FaceCirculator<B>
 where
     B: Reborrow,
@@ -1737,37 +2232,20 @@ where
     }
 }
 
-impl<'a, M, G> Iterator for FaceCirculator<&'a mut M>
-where
-    M: AsStorageMut<Face<G>> + Parametric<Data = G>,
-    G: 'a + GraphData,
-{
-    type Item = FaceOrphan<'a, G>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        FaceCirculator::next(self).map(|key| {
-            let face = self.storage.as_storage_mut().get_mut(&key).unwrap();
-            let face = unsafe { mem::transmute::<&'_ mut Face<G>, &'a mut Face<G>>(face) };
-            Orphan::bind_unchecked(face, key).into()
-        })
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.inner.len()))
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
 
+    use crate::entity::storage::AsStorageOf;
     use crate::geometry::FromGeometry;
-    use crate::graph::{ArcKey, GraphData, MeshGraph};
+    use crate::graph::edge::Arc;
+    use crate::graph::{ArcKey, GraphData, GraphError, MeshGraph};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
+    use crate::primitive::platonic::{Icosahedron, Tetrahedron};
     use crate::primitive::Tetragon;
 
     type E2 = Point2<R64>;
@@ -1794,6 +2272,124 @@ mod tests {
             .next()
     }
 
+    #[test]
+    fn circulate_around_source() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        for arc in graph.arcs() {
+            let next = arc.next_around_source();
+            assert_eq!(arc.source_vertex().key(), next.source_vertex().key());
+            assert_eq!(arc.key(), next.previous_around_source().key());
+            // Each vertex of a cube has three outgoing arcs.
+            assert_eq!(
+                arc.key(),
+                next.next_around_source().next_around_source().key()
+            );
+        }
+    }
+
+    #[test]
+    fn neighbor_face() {
+        let graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 2, 1, 3],
+            vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0)],
+            3,
+        )
+        .unwrap();
+
+        for arc in graph.arcs() {
+            assert_eq!(
+                arc.opposite_arc().face().map(|face| face.key()),
+                arc.neighbor_face().map(|face| face.key()),
+            );
+        }
+        // Only the arcs of the shared edge have neighboring faces.
+        assert_eq!(
+            2,
+            graph
+                .arcs()
+                .filter(|arc| arc.neighbor_face().is_some())
+                .count()
+        );
+    }
+
+    #[test]
+    fn edge_faces() {
+        let graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+            4,
+        )
+        .unwrap();
+        let face = graph.faces().next().unwrap().key();
+
+        for edge in graph.edges() {
+            // Each edge of a single face is a boundary edge.
+            assert!(edge.left_face().is_some() != edge.right_face().is_some());
+            assert_eq!(
+                vec![face],
+                edge.faces().map(|face| face.key()).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                edge.arc().face().map(|face| face.key()),
+                edge.left_face().map(|face| face.key())
+            );
+        }
+    }
+
+    #[test]
+    fn collapse_arc() {
+        let mut graph: MeshGraph<E3> = Icosahedron::new().polygons::<Position<E3>>().collect();
+        let arc = graph.arcs().nth(0).unwrap();
+        let (key, a, b) = (
+            arc.key(),
+            arc.source_vertex().key(),
+            arc.destination_vertex().key(),
+        );
+
+        let vertex = graph.arc_mut(key).unwrap().collapse().unwrap();
+        assert_eq!(a, vertex.key());
+        assert_eq!(6, vertex.valence());
+        assert!(graph.vertex(b).is_none());
+        assert_eq!(11, graph.vertex_count());
+        assert_eq!(27, graph.edge_count());
+        assert_eq!(18, graph.face_count());
+        assert!(graph.is_closed());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+
+        // Collapsing an edge of a tetrahedron would produce vertices with a
+        // valence of two.
+        let mut graph: MeshGraph<E3> = Tetrahedron::new().polygons::<Position<E3>>().collect();
+        let key = graph.arcs().nth(0).unwrap().key();
+        assert_eq!(
+            Some(&GraphError::TopologyConflict),
+            graph
+                .arc_mut(key)
+                .unwrap()
+                .collapse()
+                .err()
+                .as_ref()
+                .map(GraphError::root)
+        );
+        assert_eq!(4, graph.vertex_count());
+    }
+
+    #[test]
+    fn collapse_arc_with() {
+        let mut graph: MeshGraph<E3> = Icosahedron::new().polygons::<Position<E3>>().collect();
+        let arc = graph.arcs().nth(0).unwrap();
+        let (key, b, midpoint) = (arc.key(), arc.destination_vertex().key(), arc.midpoint());
+
+        let vertex = graph
+            .arc_mut(key)
+            .unwrap()
+            .collapse_with(|_, _| midpoint)
+            .unwrap();
+        assert_eq!(midpoint, *vertex.position());
+        assert!(graph.vertex(b).is_none());
+        assert_eq!(11, graph.vertex_count());
+    }
+
     #[test]
     fn extrude_arc() {
         let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
@@ -1896,4 +2492,33 @@ mod tests {
         // After the removal, the graph should have no faces.
         assert_eq!(0, graph.face_count());
     }
+
+    #[test]
+    fn try_traverse_malformed_arcs() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.arcs().nth(0).unwrap().key();
+        {
+            let arc = graph.arc(key).unwrap();
+            assert_eq!(arc.next_arc().key(), arc.try_next_arc().unwrap().key());
+            assert_eq!(
+                arc.opposite_arc().key(),
+                arc.try_opposite_arc().unwrap().key()
+            );
+            assert_eq!(arc.edge().key(), arc.try_edge().unwrap().key());
+            assert!(arc.try_source_vertex().is_ok());
+        }
+
+        // Break the rings of all faces. Traversal fails rather than panics.
+        for (_, arc) in graph.as_storage_mut_of::<Arc<_>>().iter_mut() {
+            arc.next = None;
+            arc.edge = None;
+        }
+        let arc = graph.arc(key).unwrap();
+        assert_eq!(
+            Some(GraphError::TopologyMalformed),
+            arc.try_next_arc().err()
+        );
+        assert_eq!(Some(GraphError::TopologyMalformed), arc.try_edge().err());
+        assert!(arc.try_previous_arc().is_ok());
+    }
 }
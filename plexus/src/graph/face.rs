@@ -1,35 +1,37 @@
 use derivative::Derivative;
 use fool::BoolExt;
+use num::{One, Zero};
 use slotmap::DefaultKey;
 use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::cmp;
 use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
-use std::mem;
 use std::ops::{Deref, DerefMut};
+use theon::ops::Cross;
 use theon::query::{Intersection, Line, Plane};
-use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector, VectorSpace};
 use theon::{AsPosition, AsPositionMut};
-use typenum::U3;
+use typenum::{U2, U3};
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
 use crate::entity::storage::{AsStorage, AsStorageMut, OpaqueKey, SlotStorage};
 use crate::entity::traverse::{Adjacency, Breadth, Depth, Trace, TraceFirst, Traversal};
 use crate::entity::view::{Bind, ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::Entity;
+use crate::geometry::{AsUv, Uv};
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
-use crate::graph::geometry::{FaceCentroid, FaceNormal, FacePlane, VertexPosition};
+use crate::graph::geometry::{FaceCentroid, FaceNormal, FacePlane, Handedness, VertexPosition};
 use crate::graph::mutation::face::{
-    self, FaceBridgeCache, FaceExtrudeCache, FaceInsertCache, FacePokeCache, FaceRemoveCache,
-    FaceSplitCache,
+    self, FaceBridgeCache, FaceBridgeTriangulatedCache, FaceExtrudeCache, FaceInsertCache,
+    FacePokeCache, FaceRemoveCache, FaceSplitCache,
 };
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
 use crate::graph::{GraphError, MeshGraph, OptionExt as _, ResultExt as _, Selector};
-use crate::transact::{Mutate, Transact};
+use crate::transact::Transact;
 use crate::{DynamicArity, IteratorExt as _, StaticArity};
 
 use Selector::ByIndex;
@@ -56,6 +58,13 @@ where
     pub data: G::Face,
     /// Required key into the leading arc.
     pub(in crate::graph) arc: ArcKey,
+    /// Optional arity of the ring.
+    ///
+    /// This is set when a face is inserted by the mutation API and is updated
+    /// by mutations that extend the ring of an existing face. Faces that are
+    /// not inserted by the mutation API may not cache their arity.
+    #[derivative(Hash = "ignore")]
+    pub(in crate::graph) arity: Option<usize>,
 }
 
 impl<G> Face<G>
@@ -66,6 +75,7 @@ where
         Face {
             data: geometry,
             arc,
+            arity: None,
         }
     }
 }
@@ -189,6 +199,73 @@ where
     }
 }
 
+/// Reachable API.
+impl<B, M, G> FaceView<B>
+where
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + AsStorage<Vertex<G>> + Parametric<Data = G>,
+    G: GraphData,
+    G::Vertex: AsPosition,
+{
+    /// Gets the centroid of the face if its ring is reachable.
+    ///
+    /// Unlike [`centroid`], this does not require a consistent graph and
+    /// returns `None` if the ring of the face cannot be traversed. This can be
+    /// used to compute geometry on partially constructed graphs.
+    ///
+    /// [`centroid`]: crate::graph::FaceView::centroid
+    pub fn reachable_centroid(&self) -> Option<VertexPosition<G>>
+    where
+        VertexPosition<G>: EuclideanSpace,
+    {
+        VertexPosition::<G>::centroid(self.reachable_positions()?)
+    }
+
+    /// Gets the normal of the face if its ring is reachable.
+    ///
+    /// Unlike [`normal`], this does not require a consistent graph and returns
+    /// `None` if the ring of the face cannot be traversed or if the normal
+    /// cannot be computed.
+    ///
+    /// [`normal`]: crate::graph::FaceView::normal
+    pub fn reachable_normal(&self) -> Option<Vector<VertexPosition<G>>>
+    where
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let positions = self.reachable_positions()?;
+        let (a, b) = (*positions.get(0)?, *positions.get(1)?);
+        let c = VertexPosition::<G>::centroid(positions)?;
+        let ab = a - b;
+        let bc = b - c;
+        let normal = match G::HANDEDNESS {
+            Handedness::Right => ab.cross(bc),
+            Handedness::Left => bc.cross(ab),
+        };
+        normal
+            .normalize()
+            .and_then(|normal| G::GEOMETRY_POLICY.check_vector(normal).ok())
+    }
+
+    fn reachable_positions(&self) -> Option<SmallVec<[VertexPosition<G>; 4]>> {
+        let (storage, _) = self.to_ref().unbind();
+        // Bound the traversal by the number of arcs so that malformed rings
+        // that never return to the leading arc terminate.
+        let n = AsStorage::<Arc<G>>::as_storage(storage).len();
+        let first = self.to_ref().into_reachable_arc()?;
+        let mut arc = first;
+        let mut positions = SmallVec::new();
+        for _ in 0..n {
+            positions.push(*arc.into_reachable_source_vertex()?.data.as_position());
+            arc = arc.into_reachable_next_arc()?;
+            if arc.key() == first.key() {
+                return Some(positions);
+            }
+        }
+        None
+    }
+}
+
 impl<B, M, G> FaceView<B>
 where
     B: Reborrow<Target = M>,
@@ -214,6 +291,31 @@ where
     pub fn arc(&self) -> ArcView<&M> {
         self.to_ref().into_arc()
     }
+
+    /// Converts the face into its leading arc.
+    ///
+    /// Unlike [`into_arc`], this function does not panic if the arc cannot be
+    /// found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc cannot be found, which indicates that the
+    /// graph is malformed.
+    ///
+    /// [`into_arc`]: crate::graph::FaceView::into_arc
+    pub fn try_into_arc(self) -> Result<ArcView<B>, GraphError> {
+        self.into_reachable_arc()
+            .ok_or_else(|| GraphError::TopologyMalformed)
+    }
+
+    /// Gets the leading arc of the face.
+    ///
+    /// See [`try_into_arc`].
+    ///
+    /// [`try_into_arc`]: crate::graph::FaceView::try_into_arc
+    pub fn try_arc(&self) -> Result<ArcView<&M>, GraphError> {
+        self.to_ref().try_into_arc()
+    }
 }
 
 impl<B, M, G> FaceView<B>
@@ -258,6 +360,145 @@ where
     {
         G::plane(self.to_ref())
     }
+
+    /// Computes a best-fit plane for the face or, if that fails, a plane
+    /// through its centroid with its area-weighted normal.
+    ///
+    /// Returns the plane and `true` if the fallback was used. See
+    /// [`FacePlane::plane_or_fallback`].
+    ///
+    /// [`FacePlane::plane_or_fallback`]: crate::graph::FacePlane::plane_or_fallback
+    pub fn plane_or_fallback(&self) -> Result<(Plane<VertexPosition<G>>, bool), GraphError>
+    where
+        G: FacePlane,
+        G::Vertex: AsPosition,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        G::plane_or_fallback(self.to_ref())
+    }
+
+    /// Computes the signed area of the face in two dimensions.
+    ///
+    /// The area is computed using the shoelace formula. The area is positive
+    /// if the face is wound counter-clockwise and negative if the face is
+    /// wound clockwise.
+    pub fn signed_area_2d(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U2>,
+    {
+        let two = Scalar::<VertexPosition<G>>::one() + One::one();
+        let positions = self
+            .adjacent_vertices()
+            .map(|vertex| vertex.position().into_xy())
+            .collect::<SmallVec<[_; 8]>>();
+        let n = positions.len();
+        (0..n).fold(Zero::zero(), |area: Scalar<VertexPosition<G>>, index| {
+            let (ax, ay) = positions[index];
+            let (bx, by) = positions[(index + 1) % n];
+            area + ((ax * by) - (bx * ay))
+        }) / two
+    }
+
+    /// Returns `true` if the face is wound counter-clockwise in two
+    /// dimensions.
+    ///
+    /// Degenerate faces with zero area are wound neither counter-clockwise nor
+    /// clockwise.
+    pub fn is_ccw(&self) -> bool
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U2>,
+    {
+        self.signed_area_2d() > Zero::zero()
+    }
+
+    /// Computes the area of the face in three dimensions.
+    ///
+    /// The area is half of the magnitude of the sum of the cross products of a
+    /// fan of triangles about the leading vertex. This is exact for planar
+    /// faces, including those that are not convex.
+    pub fn area(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let two = Scalar::<VertexPosition<G>>::one() + One::one();
+        let positions = self
+            .adjacent_vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<SmallVec<[_; 8]>>();
+        let origin = positions[0];
+        positions
+            .iter()
+            .skip(1)
+            .zip(positions.iter().skip(2))
+            .map(|(b, c)| (*b - origin).cross(*c - origin))
+            .fold(None, |sum: Option<Vector<VertexPosition<G>>>, normal| {
+                Some(sum.map_or(normal, |sum| sum + normal))
+            })
+            .map_or_else(Zero::zero, |sum| sum.magnitude() / two)
+    }
+
+    /// Computes the area of the face in texture space.
+    ///
+    /// The area is computed from the texture coordinates of the vertices of
+    /// the face using the shoelace formula and is always non-negative. See
+    /// [`AsUv`].
+    ///
+    /// [`AsUv`]: crate::geometry::AsUv
+    pub fn uv_area(&self) -> Scalar<Uv<G::Vertex>>
+    where
+        G::Vertex: AsUv,
+    {
+        let two = Scalar::<Uv<G::Vertex>>::one() + One::one();
+        let uvs = self
+            .adjacent_vertices()
+            .map(|vertex| vertex.data.as_uv().into_xy())
+            .collect::<SmallVec<[_; 8]>>();
+        let n = uvs.len();
+        let area = (0..n).fold(Zero::zero(), |area: Scalar<Uv<G::Vertex>>, index| {
+            let (au, av) = uvs[index];
+            let (bu, bv) = uvs[(index + 1) % n];
+            area + ((au * bv) - (bu * av))
+        }) / two;
+        if area < Zero::zero() {
+            -area
+        }
+        else {
+            area
+        }
+    }
+
+    /// Computes the texel density of the face.
+    ///
+    /// The texel density is the ratio of the area of the face in texture space
+    /// to its area in three dimensions (see [`uv_area`] and [`area`]). Faces
+    /// with very different densities are stretched relative to one another
+    /// when textured. Returns `None` if the face has no area.
+    ///
+    /// See [`MeshGraph::uv_stretch_report`].
+    ///
+    /// [`area`]: crate::graph::FaceView::area
+    /// [`uv_area`]: crate::graph::FaceView::uv_area
+    /// [`MeshGraph::uv_stretch_report`]: crate::graph::MeshGraph::uv_stretch_report
+    pub fn texel_density(&self) -> Option<Scalar<VertexPosition<G>>>
+    where
+        G::Vertex: AsPosition + AsUv,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        Vector<Uv<G::Vertex>>: VectorSpace<Scalar = Scalar<VertexPosition<G>>>,
+    {
+        let area = self.area();
+        if area > Zero::zero() {
+            Some(self.uv_area() / area)
+        }
+        else {
+            None
+        }
+    }
 }
 
 impl<B, M, G> FaceView<B>
@@ -273,35 +514,81 @@ where
     /// Flattens the face by translating the positions of all vertices into a
     /// best-fit plane.
     ///
+    /// If a best-fit plane cannot be computed, then the plane through the
+    /// centroid of the face with its area-weighted normal is used instead (see
+    /// [`FaceView::plane_or_fallback`]).
+    ///
     /// # Errors
     ///
-    /// Returns an error if a best-fit plane could not be computed or positions
-    /// could not be translated into the plane.
+    /// Returns an error if no plane could be computed or positions could not
+    /// be translated into the plane.
     pub fn flatten(&mut self) -> Result<(), GraphError>
     where
         G: FacePlane,
         G::Vertex: AsPositionMut,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        self.flatten_with_tolerance(Zero::zero()).map(|_| ())
+    }
+
+    /// Flattens the face by translating the positions of all vertices into a
+    /// best-fit plane if any vertex lies farther than the given tolerance from
+    /// that plane.
+    ///
+    /// Target positions are computed for all vertices before any are written,
+    /// so the face is left unmodified if an error occurs.
+    ///
+    /// Returns `true` if vertex positions were translated and `false` if all
+    /// vertices were already within the tolerance of the plane.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no plane could be computed or positions could not
+    /// be translated into the plane.
+    pub fn flatten_with_tolerance<T>(&mut self, tolerance: T) -> Result<bool, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FacePlane,
+        G::Vertex: AsPositionMut,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
         VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
     {
         if self.arity() == 3 {
-            return Ok(());
+            return Ok(false);
+        }
+        let tolerance = tolerance.into();
+        let (plane, _) = self.plane_or_fallback()?;
+        let mut is_flat = true;
+        // Compute all target positions before writing any of them.
+        let positions = self
+            .adjacent_vertices()
+            .map(|vertex| {
+                let position = *vertex.position();
+                let line = Line::<VertexPosition<G>> {
+                    origin: position,
+                    direction: plane.normal,
+                };
+                let distance = line
+                    .intersection(&plane)
+                    .and_then(|intersection| intersection.into_time_of_impact())
+                    .ok_or(GraphError::Geometry)?;
+                if distance * distance > tolerance * tolerance {
+                    is_flat = false;
+                }
+                let translation = *line.direction.get() * distance;
+                Ok((vertex.key(), position + translation))
+            })
+            .collect::<Result<SmallVec<[_; 8]>, GraphError>>()?;
+        if is_flat {
+            return Ok(false);
         }
-        let plane = self.plane()?;
         for mut vertex in self.adjacent_vertex_orphans() {
-            let position = *vertex.position();
-            let line = Line::<VertexPosition<G>> {
-                origin: position,
-                direction: plane.normal,
-            };
-            let distance = line
-                .intersection(&plane)
-                .expect("no line-plane intersection along normal")
-                .into_time_of_impact()
-                .expect("normal is parallel to plane");
-            let translation = *line.direction.get() * distance;
-            *vertex.data.as_position_mut() = position + translation;
+            if let Some((_, position)) = positions.iter().find(|(key, _)| *key == vertex.key()) {
+                *vertex.data.as_position_mut() = *position;
+            }
         }
-        Ok(())
+        Ok(true)
     }
 }
 
@@ -335,6 +622,21 @@ where
     pub fn adjacent_faces(&self) -> impl Clone + Iterator<Item = FaceView<&B::Target>> {
         self.to_ref().into_adjacent_faces()
     }
+
+    /// Gets an iterator over the keys of the vertices that form the face.
+    ///
+    /// Keys are yielded in the same order as [`adjacent_vertices`]. Unlike
+    /// [`adjacent_vertices`], this reads only the arcs of the face's ring, and
+    /// it does not allocate. This can be used to collect the indices of faces
+    /// when exporting large graphs.
+    ///
+    /// [`adjacent_vertices`]: crate::graph::FaceView::adjacent_vertices
+    pub fn vertex_keys(&self) -> impl '_ + Clone + Iterator<Item = VertexKey> {
+        self.adjacent_arcs().map(|arc| {
+            let (_, b): (VertexKey, VertexKey) = arc.key().into();
+            b
+        })
+    }
 }
 
 impl<'a, B, M, G> FaceView<B>
@@ -396,7 +698,15 @@ where
     G: 'a + GraphData,
 {
     pub fn into_adjacent_face_orphans(self) -> impl Iterator<Item = FaceOrphan<'a, G>> {
-        FaceCirculator::from(ArcCirculator::from(self.into_ring()))
+        let keys = self
+            .to_ref()
+            .adjacent_faces()
+            .keys()
+            .collect::<SmallVec<[_; 8]>>();
+        let (storage, _) = self.unbind();
+        Orphan::bind_disjoint_into(storage, &keys)
+            .expect_consistent()
+            .into_iter()
     }
 }
 
@@ -420,7 +730,15 @@ where
         + Parametric,
 {
     pub fn into_adjacent_vertex_orphans(self) -> impl Iterator<Item = VertexOrphan<'a, Data<M>>> {
-        VertexCirculator::from(ArcCirculator::from(self.into_ring()))
+        let keys = self
+            .to_ref()
+            .adjacent_vertices()
+            .keys()
+            .collect::<SmallVec<[_; 8]>>();
+        let (storage, _) = self.unbind();
+        Orphan::bind_disjoint_into(storage, &keys)
+            .expect_consistent()
+            .into_iter()
     }
 }
 
@@ -470,7 +788,6 @@ where
         + AsStorage<Edge<G>>
         + AsStorage<Face<G>>
         + AsStorage<Vertex<G>>
-        + Default
         + Mutable<Data = G>,
     G: GraphData,
 {
@@ -533,10 +850,9 @@ where
         let destination = destination.key_or_else(key_at_index)?;
         let cache = FaceSplitCache::from_face(self.to_ref(), source, destination)?;
         let (storage, _) = self.unbind();
-        Ok(Mutation::replace(storage, Default::default())
+        Mutation::from_graph(storage)
             .commit_with(|mutation| face::split(mutation, cache))
             .map(|(storage, arc)| Bind::bind(storage, arc).expect_consistent())
-            .expect_consistent())
     }
 
     /// Merges the face into an adjacent face over a shared edge.
@@ -594,7 +910,7 @@ where
         })?;
         let ab = self
             .adjacent_arcs()
-            .find(|arc| match arc.opposite_arc().face() {
+            .find(|arc| match arc.neighbor_face() {
                 Some(face) => face.key() == destination,
                 _ => false,
             })
@@ -604,7 +920,7 @@ where
         // TODO: Batch this operation by using the mutation API instead.
         let arc: ArcView<_> = self.rebind(ab).expect_consistent();
         Ok(arc
-            .remove()
+            .try_remove()?
             // Removing an edge between faces must yield a vertex.
             .expect_consistent()
             .into_outgoing_arc()
@@ -625,28 +941,77 @@ where
     pub fn bridge(self, destination: FaceKey) -> Result<(), GraphError> {
         let cache = FaceBridgeCache::from_face(self.to_ref(), destination)?;
         let (storage, _) = self.unbind();
-        Mutation::replace(storage, Default::default())
+        Mutation::from_graph(storage)
             .commit_with(|mutation| face::bridge(mutation, cache))
-            .expect_consistent();
-        Ok(())
+            .map(|_| ())
+    }
+
+    /// Connects faces with triangles inserted along their perimeters.
+    ///
+    /// Unlike [`bridge`], the faces need not have the same arity. The
+    /// perimeters are matched by proximity and stitched together by choosing
+    /// the shorter diagonal at each step. Both the initiating face and
+    /// destination face are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the destination face cannot be found, if it is the
+    /// initiating face, or if the faces share any vertices.
+    ///
+    /// [`bridge`]: crate::graph::FaceView::bridge
+    pub fn bridge_triangulated(self, destination: FaceKey) -> Result<(), GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        let cache = FaceBridgeTriangulatedCache::from_face(self.to_ref(), destination)?;
+        let (storage, _) = self.unbind();
+        Mutation::from_graph(storage)
+            .commit_with(|mutation| face::bridge_triangulated(mutation, cache))
+            .map(|_| ())
     }
 
     /// Decomposes the face into triangles. Does nothing if the face is
     /// triangular.
     ///
+    /// Triangles are formed as a fan about the first vertex of the face. See
+    /// [`triangulate_with`] and [`triangulate_shortest_diagonal`].
+    ///
     /// Returns the terminating face of the decomposition.
+    ///
+    /// [`triangulate_shortest_diagonal`]: crate::graph::FaceView::triangulate_shortest_diagonal
+    /// [`triangulate_with`]: crate::graph::FaceView::triangulate_with
     pub fn triangulate(self) -> Self {
+        self.triangulate_with(|_| 0)
+    }
+
+    /// Decomposes the face into triangles using a function to choose each
+    /// diagonal. Does nothing if the face is triangular.
+    ///
+    /// Triangles are split from the face one at a time. At each step, the
+    /// function receives the remaining face and returns the index of a vertex
+    /// $i$ within its perimeter. The face is then split along the diagonal
+    /// between vertices $i$ and $i + 2$, which separates the triangle formed
+    /// by vertices $i$, $i + 1$, and $i + 2$. Indices wrap about the
+    /// perimeter.
+    ///
+    /// Returns the terminating face of the decomposition.
+    pub fn triangulate_with<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(FaceView<&M>) -> usize,
+    {
         // TODO: This naive approach exhibits bad behaviors when faces are
         //       concave, linear, collapsed, or are otherwise degenerate.
         //       Additionally, splitting may fail under certain conditions!
-        //       Triangulation that ignores geometry is likely much less useful
-        //       than a triangulation algorithm that considers position data.
         // TODO: This implementation differs from `MeshGraph::triangulate`,
         //       because it is not possible to retry `split` in this context.
         let mut face = self;
         while face.arity() > 3 {
+            let arity = face.arity();
+            let index = f(face.to_ref()) % arity;
             face = face
-                .split(ByIndex(0), ByIndex(2))
+                .split(ByIndex(index), ByIndex((index + 2) % arity))
                 .expect_consistent() // TODO: This may panic!
                 .into_face()
                 .expect_consistent();
@@ -654,6 +1019,43 @@ where
         face
     }
 
+    /// Decomposes the face into triangles by splitting along the shortest
+    /// diagonal at each step. Does nothing if the face is triangular.
+    ///
+    /// For quadrilaterals, this compares the two diagonals and splits along
+    /// the shorter, which avoids thin triangles in elongated faces. See
+    /// [`triangulate_with`].
+    ///
+    /// Returns the terminating face of the decomposition.
+    ///
+    /// [`triangulate_with`]: crate::graph::FaceView::triangulate_with
+    pub fn triangulate_shortest_diagonal(self) -> Self
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        self.triangulate_with(|face| {
+            let positions = face
+                .adjacent_vertices()
+                .map(|vertex| *vertex.position())
+                .collect::<SmallVec<[_; 8]>>();
+            let n = positions.len();
+            let length = |index: usize| {
+                let diagonal = positions[(index + 2) % n] - positions[index];
+                diagonal.dot(diagonal)
+            };
+            (1..n).fold(0, |min, index| {
+                if length(index) < length(min) {
+                    index
+                }
+                else {
+                    min
+                }
+            })
+        })
+    }
+
     /// Subdivides the face about a vertex. A triangle fan is formed from each
     /// arc in the face's perimeter and the vertex.
     ///
@@ -695,30 +1097,102 @@ where
     where
         F: FnOnce() -> G::Vertex,
     {
-        // This should never fail here.
-        let cache = FacePokeCache::from_face(self.to_ref()).expect_consistent();
+        self.try_poke_with(f).expect_consistent()
+    }
+
+    /// Subdivides the face about a vertex. A triangle fan is formed from each
+    /// arc in the face's perimeter and the vertex.
+    ///
+    /// Unlike [`poke_with`], this function does not panic if the mutation
+    /// fails.
+    ///
+    /// Returns the inserted vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the topology of the face is malformed. The topology
+    /// is validated before the graph is modified, so the graph is not
+    /// modified in this case.
+    ///
+    /// [`poke_with`]: crate::graph::FaceView::poke_with
+    pub fn try_poke_with<F>(self, f: F) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        F: FnOnce() -> G::Vertex,
+    {
+        let cache = FacePokeCache::from_face(self.to_ref())?;
         let (storage, _) = self.unbind();
-        Mutation::replace(storage, Default::default())
+        Mutation::from_graph(storage)
             .commit_with(|mutation| face::poke_with(mutation, cache, f))
             .map(|(storage, vertex)| Bind::bind(storage, vertex).expect_consistent())
-            .expect_consistent()
     }
 
     /// Subdivides the face about its centroid. A triangle fan is formed from
     /// each arc in the face's perimeter and a vertex inserted at the centroid.
     ///
+    /// The data of the inserted vertex is blended from the data of the
+    /// vertices of the face by [`GraphData::blend_vertices`].
+    ///
     /// Returns the inserted vertex.
+    ///
+    /// [`GraphData::blend_vertices`]: crate::graph::GraphData::blend_vertices
     pub fn poke_at_centroid(self) -> VertexView<&'a mut M>
     where
         G: FaceCentroid,
         G::Vertex: AsPositionMut,
     {
-        let mut geometry = self.arc().source_vertex().data;
-        let centroid = self.centroid();
-        self.poke_with(move || {
-            *geometry.as_position_mut() = centroid;
-            geometry
-        })
+        self.poke_toward(|face| face.centroid())
+    }
+
+    /// Subdivides the face about a computed position. A triangle fan is formed
+    /// from each arc in the face's perimeter and a vertex inserted at the
+    /// position returned by the given function.
+    ///
+    /// The data of the inserted vertex is blended from the data of the
+    /// vertices of the face by [`GraphData::blend_vertices`], but its position
+    /// is the position computed from the face by the given function.
+    ///
+    /// Returns the inserted vertex.
+    ///
+    /// # Examples
+    ///
+    /// Poking a face toward a fixed point:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// let vertex = graph
+    ///     .face_mut(key)
+    ///     .unwrap()
+    ///     .poke_toward(|_| Point3::origin());
+    /// ```
+    ///
+    /// [`GraphData::blend_vertices`]: crate::graph::GraphData::blend_vertices
+    pub fn poke_toward<F>(self, f: F) -> VertexView<&'a mut M>
+    where
+        F: FnOnce(FaceView<&M>) -> VertexPosition<G>,
+        G::Vertex: AsPositionMut,
+    {
+        let mut geometry = G::blend_vertices(
+            &self
+                .adjacent_vertices()
+                .map(|vertex| vertex.data)
+                .collect::<SmallVec<[_; 8]>>(),
+        );
+        *geometry.as_position_mut() = f(self.to_ref());
+        self.poke_with(move || geometry)
     }
 
     /// Subdivides the face about its centroid. A triangle fan is formed from
@@ -763,12 +1237,8 @@ where
         G::Vertex: AsPositionMut,
         VertexPosition<G>: EuclideanSpace,
     {
-        let mut geometry = self.arc().source_vertex().data;
         let position = self.centroid() + (self.normal()? * offset.into());
-        Ok(self.poke_with(move || {
-            *geometry.as_position_mut() = position;
-            geometry
-        }))
+        Ok(self.poke_toward(move |_| position))
     }
 
     /// Extrudes the face along its normal.
@@ -810,27 +1280,89 @@ where
     where
         F: Fn(G::Vertex) -> G::Vertex,
     {
-        // This should never fail here.
-        let cache = FaceExtrudeCache::from_face(self.to_ref()).expect_consistent();
+        self.try_extrude_with(f).expect_consistent()
+    }
+
+    /// Extrudes a face using the given vertex data.
+    ///
+    /// Unlike [`extrude_with`], this function does not panic if the mutation
+    /// fails.
+    ///
+    /// Returns the extruded face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the topology of the face is malformed. The topology
+    /// is validated before the graph is modified, so the graph is not
+    /// modified in this case.
+    ///
+    /// [`extrude_with`]: crate::graph::FaceView::extrude_with
+    pub fn try_extrude_with<F>(self, f: F) -> Result<FaceView<&'a mut M>, GraphError>
+    where
+        F: Fn(G::Vertex) -> G::Vertex,
+    {
+        let cache = FaceExtrudeCache::from_face(self.to_ref())?;
         let (storage, _) = self.unbind();
-        Mutation::replace(storage, Default::default())
+        Mutation::from_graph(storage)
             .commit_with(|mutation| face::extrude_with(mutation, cache, f))
             .map(|(storage, face)| Bind::bind(storage, face).expect_consistent())
-            .expect_consistent()
     }
 
     /// Removes the face.
     ///
     /// Returns the remaining ring of the face if it is not entirely disjoint, otherwise `None`.
     pub fn remove(self) -> Option<Ring<&'a mut M>> {
-        // This should never fail here.
-        let cache = FaceRemoveCache::from_face(self.to_ref()).expect_consistent();
+        self.try_remove().expect_consistent()
+    }
+
+    /// Removes the face.
+    ///
+    /// Unlike [`remove`], this function does not panic if the mutation fails.
+    ///
+    /// Returns the remaining ring of the face if it is not entirely disjoint, otherwise `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the topology of the face is malformed. The topology
+    /// is validated before the graph is modified, so the graph is not
+    /// modified in this case.
+    ///
+    /// [`remove`]: crate::graph::FaceView::remove
+    pub fn try_remove(self) -> Result<Option<Ring<&'a mut M>>, GraphError> {
+        let cache = FaceRemoveCache::from_face(self.to_ref())?;
         let (storage, _) = self.unbind();
-        Mutation::replace(storage, Default::default())
+        Mutation::from_graph(storage)
             .commit_with(|mutation| face::remove(mutation, cache))
-            .map(|(storage, face)| ArcView::bind(storage, face.arc))
-            .expect_consistent()
-            .map(|arc| arc.into_ring())
+            .map(|(storage, face)| ArcView::bind(storage, face.arc).map(|arc| arc.into_ring()))
+    }
+
+    /// Removes the face and reports the vertices that were affected.
+    ///
+    /// Returns the remaining ring of the face if it is not entirely disjoint,
+    /// otherwise `None`, along with the keys of the vertices of the face. The
+    /// neighborhoods of these vertices are changed by the removal, so this can
+    /// be used to recompute data locally rather than for the entire graph.
+    pub fn remove_reporting(self) -> (Option<Ring<&'a mut M>>, Vec<VertexKey>) {
+        self.try_remove_reporting().expect_consistent()
+    }
+
+    /// Removes the face and reports the vertices that were affected.
+    ///
+    /// Unlike [`remove_reporting`], this function does not panic if the
+    /// mutation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the topology of the face is malformed. The topology
+    /// is validated before the graph is modified, so the graph is not
+    /// modified in this case.
+    ///
+    /// [`remove_reporting`]: crate::graph::FaceView::remove_reporting
+    pub fn try_remove_reporting(
+        self,
+    ) -> Result<(Option<Ring<&'a mut M>>, Vec<VertexKey>), GraphError> {
+        let keys = self.vertex_keys().collect::<Vec<_>>();
+        self.try_remove().map(|ring| (ring, keys))
     }
 }
 
@@ -929,8 +1461,11 @@ where
 
     /// Gets the arity of the face. This is the number of arcs that form the
     /// face's ring.
+    ///
+    /// The arity is cached when a face is inserted by the mutation API, so
+    /// this is usually constant time. Otherwise, the ring is traversed.
     fn arity(&self) -> Self::Dynamic {
-        self.adjacent_arcs().count()
+        self.arity.unwrap_or_else(|| self.adjacent_arcs().count())
     }
 }
 
@@ -1293,7 +1828,11 @@ where
     G: 'a + GraphData,
 {
     pub fn into_arc_orphans(self) -> impl Iterator<Item = ArcOrphan<'a, G>> {
-        ArcCirculator::from(self)
+        let keys = self.to_ref().arcs().keys().collect::<SmallVec<[_; 8]>>();
+        let (storage, _) = self.into_arc().unbind();
+        Orphan::bind_disjoint_into(storage, &keys)
+            .expect_consistent()
+            .into_iter()
     }
 }
 
@@ -1314,7 +1853,15 @@ where
     G: 'a + GraphData,
 {
     pub fn into_vertex_orphans(self) -> impl Iterator<Item = VertexOrphan<'a, G>> {
-        VertexCirculator::from(ArcCirculator::from(self))
+        let keys = self
+            .to_ref()
+            .vertices()
+            .keys()
+            .collect::<SmallVec<[_; 8]>>();
+        let (storage, _) = self.into_arc().unbind();
+        Orphan::bind_disjoint_into(storage, &keys)
+            .expect_consistent()
+            .into_iter()
     }
 }
 
@@ -1331,7 +1878,7 @@ where
 
 impl<'a, M, G> Ring<&'a mut M>
 where
-    M: AsStorage<Vertex<G>> + AsStorage<Arc<G>> + AsStorage<Face<G>> + Default + Mutable<Data = G>,
+    M: AsStorage<Vertex<G>> + AsStorage<Arc<G>> + AsStorage<Face<G>> + Mutable<Data = G>,
     G: GraphData,
 {
     /// Gets the face of the ring or inserts a face if one does not already
@@ -1361,7 +1908,7 @@ where
             // This should never fail here.
             let cache = FaceInsertCache::from_ring(self.to_ref()).expect_consistent();
             let (storage, _) = self.arc.unbind();
-            Mutation::replace(storage, Default::default())
+            Mutation::from_graph(storage)
                 .commit_with(|mutation| {
                     face::insert_with(mutation, cache, || (Default::default(), f()))
                 })
@@ -1500,28 +2047,6 @@ where
     }
 }
 
-impl<'a, M, G> Iterator for VertexCirculator<&'a mut M>
-where
-    M: AsStorage<Arc<G>> + AsStorageMut<Vertex<G>> + Consistent + Parametric<Data = G>,
-    G: 'a + GraphData,
-{
-    type Item = VertexOrphan<'a, G>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        VertexCirculator::next(self).map(|key| {
-            let vertex = self.inner.storage.as_storage_mut().get_mut(&key).unwrap();
-            let vertex = unsafe { mem::transmute::<&'_ mut Vertex<G>, &'a mut Vertex<G>>(vertex) };
-            Orphan::bind_unchecked(vertex, key).into()
-        })
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        // This requires consistency, because an inconsistent graph may not
-        // produce the expected minimum of three vertices.
-        (3, None)
-    }
-}
-
 pub struct ArcCirculator<B>
 where
     B: Reborrow,
@@ -1602,28 +2127,6 @@ where
     }
 }
 
-impl<'a, M, G> Iterator for ArcCirculator<&'a mut M>
-where
-    M: AsStorageMut<Arc<G>> + Consistent + Parametric<Data = G>,
-    G: 'a + GraphData,
-{
-    type Item = ArcOrphan<'a, G>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        ArcCirculator::next(self).map(|key| {
-            let arc = self.storage.as_storage_mut().get_mut(&key).unwrap();
-            let arc = unsafe { mem::transmute::<&'_ mut Arc<G>, &'a mut Arc<G>>(arc) };
-            Orphan::bind_unchecked(arc, key).into()
-        })
-    }
-
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        // This requires consistency, because an inconsistent graph may not
-        // produce the expected minimum of three arcs.
-        (3, None)
-    }
-}
-
 pub struct FaceCirculator<B>
 where
     B: Reborrow,
@@ -1696,38 +2199,53 @@ where
     }
 }
 
-impl<'a, M, G> Iterator for FaceCirculator<&'a mut M>
-where
-    M: AsStorage<Arc<G>> + AsStorageMut<Face<G>> + Consistent + Parametric<Data = G>,
-    G: 'a + GraphData,
-{
-    type Item = FaceOrphan<'a, G>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        FaceCirculator::next(self).map(|key| {
-            let face = self.inner.storage.as_storage_mut().get_mut(&key).unwrap();
-            let face = unsafe { mem::transmute::<&'_ mut Face<G>, &'a mut Face<G>>(face) };
-            Orphan::bind_unchecked(face, key).into()
-        })
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
 
-    use crate::graph::MeshGraph;
+    use crate::entity::storage::AsStorageOf;
+    use crate::entity::view::Bind;
+    use crate::geometry::{AsPosition, AsPositionMut};
+    use crate::graph::core::OwnedCore;
+    use crate::graph::edge::Arc;
+    use crate::graph::{FaceView, GeometryPolicy, GraphData, GraphError, Handedness, MeshGraph};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
-    use crate::primitive::Tetragon;
+    use crate::primitive::{BoundedPolygon, Tetragon, Trigon};
 
     type E2 = Point2<R64>;
     type E3 = Point3<R64>;
 
+    #[test]
+    fn signed_area_and_winding_2d() {
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        let face = graph.face(key).unwrap();
+
+        assert_eq!(R64::from_inner(2.0), face.signed_area_2d());
+        assert!(face.is_ccw());
+
+        // Reverse the winding of the face.
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![Tetragon::new(3usize, 2, 1, 0)],
+            vec![(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        let face = graph.face(key).unwrap();
+
+        assert_eq!(R64::from_inner(-2.0), face.signed_area_2d());
+        assert!(!face.is_ccw());
+    }
+
     #[test]
     fn circulate_over_arcs() {
         let graph: MeshGraph<E3> = UvSphere::new(3, 2)
@@ -1797,6 +2315,51 @@ mod tests {
         assert_eq!(2, graph.face_count());
     }
 
+    #[test]
+    fn cached_arity() {
+        fn assert_arity(graph: &MeshGraph<E3>) {
+            for face in graph.faces() {
+                assert_eq!(Some(face.adjacent_arcs().count()), face.arity);
+            }
+        }
+
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_arity(&graph);
+
+        // Splitting an edge increases the arity of its faces.
+        let key = graph.arcs().nth(0).unwrap().key();
+        let vertex = graph.arc_mut(key).unwrap().split_at_midpoint().key();
+        assert_arity(&graph);
+        assert_eq!(
+            2,
+            graph
+                .vertex(vertex)
+                .unwrap()
+                .adjacent_faces()
+                .filter(|face| face.arity() == 5)
+                .count()
+        );
+
+        let abc = graph.faces().find(|face| face.arity() == 5).unwrap().key();
+        let arc = graph
+            .face_mut(abc)
+            .unwrap()
+            .split(ByIndex(0), ByIndex(2))
+            .unwrap()
+            .key();
+        assert_arity(&graph);
+
+        let abc = graph.arc(arc).unwrap().face().unwrap().key();
+        let cba = graph.arc(arc).unwrap().opposite_arc().face().unwrap().key();
+        graph.face_mut(abc).unwrap().merge(ByKey(cba)).unwrap();
+        assert_arity(&graph);
+
+        let abc = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(abc).unwrap().poke_at_centroid();
+        assert_arity(&graph);
+        assert_eq!(Ok(()), graph.validate());
+    }
+
     #[test]
     fn extrude_face() {
         let mut graph: MeshGraph<E3> = UvSphere::new(3, 2)
@@ -1826,6 +2389,68 @@ mod tests {
         assert_eq!(9, graph.face_count());
     }
 
+    #[test]
+    fn bridge_faces_triangulated() {
+        // Construct a graph with a disjoint triangle and quadrilateral.
+        let mut graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![
+                BoundedPolygon::N3(Trigon::new(0usize, 1, 2)),
+                BoundedPolygon::N4(Tetragon::new(3, 4, 5, 6)),
+            ],
+            vec![
+                (0.0, 0.0, 0.0), // 0
+                (1.0, 0.0, 0.0), // 1
+                (0.5, 1.0, 0.0), // 2
+                (0.0, 1.0, 1.0), // 3
+                (1.0, 1.0, 1.0), // 4
+                (1.0, 0.0, 1.0), // 5
+                (0.0, 0.0, 1.0), // 6
+            ],
+        )
+        .unwrap();
+        let source = graph.faces().find(|face| face.arity() == 3).unwrap().key();
+        let destination = graph.faces().find(|face| face.arity() == 4).unwrap().key();
+        graph
+            .face_mut(source)
+            .unwrap()
+            .bridge_triangulated(destination)
+            .unwrap();
+
+        // One triangle is inserted for each arc of the source and destination
+        // faces, each of which is removed.
+        assert_eq!(7, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        // The perimeters contribute seven edges and the stitching inserts
+        // another diagonal per triangle.
+        assert_eq!(14, graph.edge_count());
+    }
+
+    #[test]
+    fn bridge_faces_triangulated_conflict() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let source = graph.faces().nth(0).unwrap().key();
+        let adjacent = graph
+            .face(source)
+            .unwrap()
+            .adjacent_faces()
+            .nth(0)
+            .unwrap()
+            .key();
+
+        // A face cannot be bridged to itself or to a face with which it shares
+        // vertices.
+        for destination in [source, adjacent].iter() {
+            let error = graph
+                .face_mut(source)
+                .unwrap()
+                .bridge_triangulated(*destination)
+                .unwrap_err();
+            assert_eq!(GraphError::TopologyConflict, *error.root());
+        }
+        assert_eq!(6, graph.face_count());
+        assert!(graph.is_closed());
+    }
+
     #[test]
     fn merge_faces() {
         // Construct a graph with two connected quadrilaterals.
@@ -1878,13 +2503,157 @@ mod tests {
         assert_eq!(3, vertex.adjacent_faces().count());
     }
 
+    #[test]
+    fn poke_face_blends_vertex_data() {
+        #[derive(Clone, Copy)]
+        struct Vertex {
+            position: E2,
+            weight: R64,
+        }
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+
+            fn blend_vertices(vertices: &[Self::Vertex]) -> Self::Vertex {
+                let n = R64::from_inner(vertices.len() as f64);
+                let weight = vertices
+                    .iter()
+                    .fold(R64::from_inner(0.0), |sum, vertex| sum + vertex.weight);
+                Vertex {
+                    weight: weight / n,
+                    ..vertices[0]
+                }
+            }
+        }
+
+        impl AsPosition for Vertex {
+            type Position = E2;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        impl AsPositionMut for Vertex {
+            fn as_position_mut(&mut self) -> &mut Self::Position {
+                &mut self.position
+            }
+        }
+
+        let vertex = |x: f64, y: f64, weight: f64| Vertex {
+            position: E2::new(R64::from_inner(x), R64::from_inner(y)),
+            weight: R64::from_inner(weight),
+        };
+        let mut graph = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![
+                vertex(0.0, 0.0, 0.0),
+                vertex(1.0, 0.0, 1.0),
+                vertex(1.0, 1.0, 2.0),
+                vertex(0.0, 1.0, 3.0),
+            ],
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        let vertex = graph.face_mut(key).unwrap().poke_at_centroid();
+
+        assert_eq!(R64::from_inner(1.5), vertex.data.weight);
+        assert_eq!(
+            E2::new(R64::from_inner(0.5), R64::from_inner(0.5)),
+            vertex.data.position
+        );
+    }
+
+    #[test]
+    fn geometry_of_non_point_vertex() {
+        // Positions are accessed through `AsPosition`, so vertex data need not
+        // be a point.
+        #[derive(Clone, Copy)]
+        struct Vertex {
+            position: E3,
+            index: usize,
+        }
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+        }
+
+        impl AsPosition for Vertex {
+            type Position = E3;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        impl AsPositionMut for Vertex {
+            fn as_position_mut(&mut self) -> &mut Self::Position {
+                &mut self.position
+            }
+        }
+
+        let vertex = |index: usize, x: f64, y: f64| Vertex {
+            position: E3::new(R64::from_inner(x), R64::from_inner(y), R64::from_inner(0.0)),
+            index,
+        };
+        let mut graph = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![
+                vertex(0, 0.0, 0.0),
+                vertex(1, 2.0, 0.0),
+                vertex(2, 2.0, 2.0),
+                vertex(3, 0.0, 2.0),
+            ],
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        let face = graph.face(key).unwrap();
+        let centroid = face.centroid();
+
+        assert_eq!(
+            E3::new(
+                R64::from_inner(1.0),
+                R64::from_inner(1.0),
+                R64::from_inner(0.0)
+            ),
+            centroid
+        );
+        assert!(face.normal().is_ok());
+
+        let vertex = graph.face_mut(key).unwrap().poke_at_centroid();
+
+        assert_eq!(centroid, vertex.data.position);
+        assert_eq!(0, vertex.data.index);
+        assert_eq!(4, vertex.adjacent_faces().count());
+    }
+
+    #[test]
+    fn poke_toward() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        let target = graph.face(key).unwrap().centroid() * R64::from_inner(2.0);
+        let vertex = graph.face_mut(key).unwrap().poke_toward(|face| {
+            assert_eq!(key, face.key());
+            target
+        });
+
+        assert_eq!(target, *vertex.position());
+        assert_eq!(4, vertex.adjacent_faces().count());
+    }
+
     #[test]
     fn triangulate_mesh() {
         let (indices, vertices) = Cube::new()
             .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
             .index_vertices::<Tetragon<usize>, _>(HashIndexer::default());
         let mut graph = MeshGraph::<E3>::from_raw_buffers(indices, vertices).unwrap();
-        graph.triangulate();
+        graph.triangulate().unwrap();
 
         assert_eq!(8, graph.vertex_count());
         assert_eq!(36, graph.arc_count());
@@ -1894,6 +2663,29 @@ mod tests {
         assert_eq!(12, graph.face_count());
     }
 
+    #[test]
+    fn triangulate_shortest_diagonal() {
+        // A parallelogram with diagonals of very different lengths.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![(0.0, 0.0), (3.0, 0.0), (4.0, 1.0), (1.0, 1.0)],
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        let arity = graph
+            .face_mut(key)
+            .unwrap()
+            .triangulate_shortest_diagonal()
+            .arity();
+
+        assert_eq!(3, arity);
+        assert_eq!(2, graph.face_count());
+        let edge = graph.edges().find(|edge| !edge.is_boundary_edge()).unwrap();
+        let arc = edge.arc();
+        let diagonal = *arc.destination_vertex().position() - *arc.source_vertex().position();
+        assert_eq!(R64::from_inner(5.0), diagonal.dot(&diagonal));
+    }
+
     #[test]
     fn logical_metrics() {
         let graph = MeshGraph::<Point2<f32>>::from_raw_buffers_with_arity(
@@ -1924,4 +2716,211 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn strict_geometry_policy() {
+        #[derive(Clone, Copy)]
+        struct Vertex(Point3<f64>);
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+
+            const GEOMETRY_POLICY: GeometryPolicy = GeometryPolicy::Strict;
+        }
+
+        impl AsPosition for Vertex {
+            type Position = Point3<f64>;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.0
+            }
+        }
+
+        let mut graph = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![
+                Vertex(Point3::new(0.0, 0.0, 0.0)),
+                Vertex(Point3::new(1.0, 0.0, 0.0)),
+                Vertex(Point3::new(0.0, 1.0, 0.0)),
+            ],
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+
+        assert!(graph.face(key).unwrap().normal().is_ok());
+
+        // Introduce a non-finite position.
+        for mut vertex in graph.vertex_orphans() {
+            vertex.data.0.z = f64::NAN;
+        }
+
+        assert_eq!(
+            Err(GraphError::Geometry),
+            graph.face(key).unwrap().normal().map(|_| ())
+        );
+    }
+
+    #[test]
+    fn left_handed_normal() {
+        #[derive(Clone, Copy)]
+        struct Vertex(Point3<f64>);
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+
+            const HANDEDNESS: Handedness = Handedness::Left;
+        }
+
+        impl AsPosition for Vertex {
+            type Position = Point3<f64>;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.0
+            }
+        }
+
+        let positions = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ];
+        let right = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            positions.clone(),
+        )
+        .unwrap();
+        let left = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            positions.into_iter().map(Vertex),
+        )
+        .unwrap();
+
+        let right = right.faces().nth(0).unwrap().normal().unwrap();
+        let face = left.faces().nth(0).unwrap();
+
+        // The counter-clockwise triangle faces away from left-handed normals.
+        assert_eq!(-right, face.normal().unwrap());
+        assert_eq!(Some(-right), face.reachable_normal());
+    }
+
+    #[test]
+    fn flatten_with_fallback_plane() {
+        use crate::entity::borrow::Reborrow;
+        use crate::entity::storage::AsStorage;
+        use crate::graph::data::Parametric;
+        use crate::graph::face::ToRing;
+        use crate::graph::mutation::Consistent;
+        use crate::graph::vertex;
+        use crate::graph::FacePlane;
+        use theon::query::Plane;
+
+        #[derive(Clone, Copy)]
+        struct Vertex(Point3<f64>);
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+        }
+
+        impl AsPosition for Vertex {
+            type Position = Point3<f64>;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.0
+            }
+        }
+
+        impl AsPositionMut for Vertex {
+            fn as_position_mut(&mut self) -> &mut Self::Position {
+                &mut self.0
+            }
+        }
+
+        // A best-fit plane that is always ill-conditioned.
+        impl FacePlane for Vertex {
+            fn plane<B, T>(_: T) -> Result<Plane<Point3<f64>>, GraphError>
+            where
+                B: Reborrow,
+                B::Target: AsStorage<Arc<Self>>
+                    + AsStorage<vertex::Vertex<Self>>
+                    + Consistent
+                    + Parametric<Data = Self>,
+                T: ToRing<B>,
+            {
+                Err(GraphError::Geometry)
+            }
+        }
+
+        let mut graph = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![
+                Vertex(Point3::new(0.0, 0.0, 0.0)),
+                Vertex(Point3::new(2.0, 0.0, 0.0)),
+                Vertex(Point3::new(2.0, 2.0, 1.0)),
+                Vertex(Point3::new(0.0, 2.0, 0.0)),
+            ],
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+
+        let (plane, is_fallback) = graph.face(key).unwrap().plane_or_fallback().unwrap();
+        assert!(is_fallback);
+        assert!(plane.normal.get().z > 0.0);
+
+        graph.face_mut(key).unwrap().flatten().unwrap();
+        let face = graph.face(key).unwrap();
+        let (plane, _) = face.plane_or_fallback().unwrap();
+        for vertex in face.adjacent_vertices() {
+            let distance = (vertex.position() - plane.origin).dot(plane.normal.get());
+            assert!(distance.abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn reachable_centroid_and_normal() {
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![
+                (0.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (2.0, 2.0, 0.0),
+                (0.0, 2.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        let face = graph.face(key).unwrap();
+
+        assert_eq!(Some(face.centroid()), face.reachable_centroid());
+        assert_eq!(face.normal().ok(), face.reachable_normal());
+
+        // Break the ring of the face. Its geometry is no longer reachable.
+        let mut core: OwnedCore<_> = graph.into();
+        for (_, arc) in core.as_storage_mut_of::<Arc<_>>().iter_mut() {
+            arc.next = None;
+        }
+        let face: FaceView<_> = Bind::bind(&core, key).unwrap();
+
+        assert_eq!(None, face.reachable_centroid());
+        assert_eq!(None, face.reachable_normal());
+    }
+
+    #[test]
+    fn vertex_keys() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        for face in graph.faces() {
+            assert!(face
+                .vertex_keys()
+                .eq(face.adjacent_vertices().map(|vertex| vertex.key())));
+        }
+    }
 }
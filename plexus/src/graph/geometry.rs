@@ -7,9 +7,11 @@
 // necessary, constraints are specified there so that they do not pollute user
 // code.
 
+use num::Zero;
+use smallvec::SmallVec;
 use theon::adjunct::FromItems;
 use theon::ops::{Cross, Interpolate, Project};
-use theon::query::Plane;
+use theon::query::{Plane, Unit};
 use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Vector, VectorSpace};
 use theon::{AsPosition, Position};
 use typenum::U3;
@@ -25,6 +27,95 @@ use crate::graph::{GraphError, OptionExt as _};
 
 pub type VertexPosition<G> = Position<<G as GraphData>::Vertex>;
 
+/// Policy for non-finite results of geometric operations.
+///
+/// Geometric operations may produce non-finite results (`NaN` or infinity)
+/// when given degenerate or non-finite geometry, for example when normalizing
+/// a vector computed from non-finite positions. The policy of a graph is
+/// determined by its [`GraphData`] (see [`GraphData::GEOMETRY_POLICY`]) and is
+/// consulted by fallible geometric operations like [`FaceNormal`] and
+/// [`VertexNormal`].
+///
+/// Note that some scalar types, like those provided by [`decorum`], may
+/// already reject non-finite values.
+///
+/// [`decorum`]: https://crates.io/crates/decorum
+///
+/// [`FaceNormal`]: crate::graph::FaceNormal
+/// [`GraphData`]: crate::graph::GraphData
+/// [`GraphData::GEOMETRY_POLICY`]: crate::graph::GraphData::GEOMETRY_POLICY
+/// [`VertexNormal`]: crate::graph::VertexNormal
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GeometryPolicy {
+    /// Non-finite results are rejected with [`GraphError::Geometry`].
+    ///
+    /// [`GraphError::Geometry`]: crate::graph::GraphError::Geometry
+    Strict,
+    /// Non-finite results are returned as is.
+    Lenient,
+}
+
+impl GeometryPolicy {
+    /// Applies the policy to a vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the policy is strict and the vector has a
+    /// non-finite component.
+    pub fn check_vector<T>(self, vector: T) -> Result<T, GraphError>
+    where
+        T: InnerSpace,
+    {
+        match self {
+            GeometryPolicy::Strict => {
+                // The squared magnitude is finite if and only if all of the
+                // components are finite (barring overflow). The difference of
+                // a non-finite value with itself is `NaN` and so is not zero.
+                let squared = vector.dot(vector);
+                if squared - squared == Zero::zero() {
+                    Ok(vector)
+                }
+                else {
+                    Err(GraphError::Geometry)
+                }
+            }
+            GeometryPolicy::Lenient => Ok(vector),
+        }
+    }
+}
+
+impl Default for GeometryPolicy {
+    fn default() -> Self {
+        GeometryPolicy::Lenient
+    }
+}
+
+/// Winding convention of faces.
+///
+/// Handedness determines which winding of a face's vertices is considered
+/// front-facing and so the direction of face normals computed by
+/// [`FaceNormal`]. The handedness of a graph is determined by its
+/// [`GraphData`] (see [`GraphData::HANDEDNESS`]). Operations that depend on
+/// normals, like extrusion and poking with an offset, follow the handedness of
+/// the graph.
+///
+/// [`FaceNormal`]: crate::graph::FaceNormal
+/// [`GraphData`]: crate::graph::GraphData
+/// [`GraphData::HANDEDNESS`]: crate::graph::GraphData::HANDEDNESS
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Handedness {
+    /// Counter-clockwise winding is front-facing.
+    Right,
+    /// Clockwise winding is front-facing.
+    Left,
+}
+
+impl Default for Handedness {
+    fn default() -> Self {
+        Handedness::Right
+    }
+}
+
 pub trait VertexCentroid: GraphData
 where
     Self::Vertex: AsPosition,
@@ -93,6 +184,7 @@ where
         .expect_consistent()
         .normalize()
         .ok_or_else(|| GraphError::Geometry)
+        .and_then(|normal| Self::GEOMETRY_POLICY.check_vector(normal))
     }
 }
 
@@ -235,7 +327,14 @@ where
         let c = G::centroid(ring)?;
         let ab = a - b;
         let bc = b - c;
-        ab.cross(bc).normalize().ok_or_else(|| GraphError::Geometry)
+        let normal = match G::HANDEDNESS {
+            Handedness::Right => ab.cross(bc),
+            Handedness::Left => bc.cross(ab),
+        };
+        normal
+            .normalize()
+            .ok_or_else(|| GraphError::Geometry)
+            .and_then(|normal| G::GEOMETRY_POLICY.check_vector(normal))
     }
 }
 
@@ -250,6 +349,50 @@ where
         B::Target:
             AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
         T: ToRing<B>;
+
+    /// Computes a best-fit plane or, if that fails, a plane through the
+    /// centroid of the ring with its area-weighted normal.
+    ///
+    /// Best-fit planes may not be computable for degenerate rings, such as
+    /// slivers with nearly collinear vertices. The area-weighted normal is the
+    /// sum of the cross products of a fan of triangles about the centroid and
+    /// is well-defined for any ring with area. Returns the plane and `true` if
+    /// the fallback was used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if neither plane can be computed, which occurs if the
+    /// ring has no area.
+    fn plane_or_fallback<B, T>(ring: T) -> Result<(Plane<VertexPosition<Self>>, bool), GraphError>
+    where
+        Self: FaceCentroid,
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        T: ToRing<B>,
+        VertexPosition<Self>: EuclideanSpace,
+        Vector<VertexPosition<Self>>: Cross<Output = Vector<VertexPosition<Self>>>,
+    {
+        let ring = ring.into_ring();
+        if let Ok(plane) = Self::plane(ring.to_ref()) {
+            return Ok((plane, false));
+        }
+        let origin = Self::centroid(ring.to_ref())?;
+        let positions = ring
+            .vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<SmallVec<[_; 8]>>();
+        let normal = positions
+            .iter()
+            .zip(positions.iter().cycle().skip(1))
+            .map(|(a, b)| (*a - origin).cross(*b - origin))
+            .fold(None, |sum: Option<Vector<VertexPosition<Self>>>, normal| {
+                Some(sum.map_or(normal, |sum| sum + normal))
+            })
+            .and_then(Unit::try_from_inner)
+            .ok_or_else(|| GraphError::Geometry)?;
+        Ok((Plane { origin, normal }, true))
+    }
 }
 
 // TODO: The `lapack` feature depends on `ndarray-linalg` and Intel MKL. MKL is
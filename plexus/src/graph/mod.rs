@@ -64,6 +64,18 @@
 //! graph. Note that paths and rings are **not** entities and are not explicitly
 //! stored in graphs.
 //!
+//! Keys are assigned when entities are inserted and are never rekeyed by
+//! mutations, so the keys returned by topological operations (for example, the
+//! vertex returned by [`FaceView::poke_at_centroid`] or the face returned by
+//! [`MeshGraph::insert_face_with_arc_data`]) remain valid until those entities
+//! are removed. The only operation that rekeys entities is
+//! [`MeshGraph::compact`], which returns a [`GraphRekeying`] that maps keys
+//! before compaction to keys after compaction.
+//!
+//! Data that is not part of [`GraphData`] can be associated with entities by
+//! their keys using an [`AttributeMap`], which can be rekeyed along with a
+//! compacted graph.
+//!
 //! # Views
 //!
 //! [`MeshGraph`]s expose _views_ over their entities (vertices, arcs, edges,
@@ -215,7 +227,7 @@
 //!     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
 //! )
 //! .unwrap();
-//! graph.triangulate();
+//! graph.triangulate().unwrap();
 //!
 //! // Traverse an arc and use a circulator to get the faces of a nearby vertex.
 //! let key = graph.arcs().nth(0).unwrap().key();
@@ -239,12 +251,19 @@
 //! [`EuclideanSpace`]: theon::space::EuclideanSpace
 //! [`AsPosition`]: crate::geometry::AsPosition
 //! [`ArcView::split_at_midpoint`]: crate::graph::ArcView::split_at_midpoint
+//! [`AttributeMap`]: crate::graph::AttributeMap
 //! [`EdgeMidpoint`]: crate::graph::EdgeMidpoint
+//! [`FaceView::poke_at_centroid`]: crate::graph::FaceView::poke_at_centroid
 //! [`FaceView::poke_with_offset`]: crate::graph::FaceView::poke_with_offset
 //! [`GraphData`]: crate::graph::GraphData
+//! [`GraphRekeying`]: crate::graph::GraphRekeying
 //! [`MeshGraph`]: crate::graph::MeshGraph
+//! [`MeshGraph::compact`]: crate::graph::MeshGraph::compact
+//! [`MeshGraph::insert_face_with_arc_data`]: crate::graph::MeshGraph::insert_face_with_arc_data
 //! [`UvSphere`]: crate::primitive::sphere::UvSphere
 
+mod attribute;
+mod boolean;
 mod builder;
 mod core;
 mod data;
@@ -253,57 +272,73 @@ mod face;
 mod geometry;
 mod mutation;
 mod path;
+mod sdf;
+mod sparse;
 mod vertex;
+mod voxel;
 
 use decorum::cmp::IntrinsicOrd;
-use decorum::R64;
+use decorum::{Real, R64};
 use itertools::Itertools;
-use num::{Integer, NumCast, ToPrimitive, Unsigned};
+use num::{Integer, NumCast, One, ToPrimitive, Unsigned, Zero};
 use smallvec::SmallVec;
 use std::borrow::Borrow;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::convert::TryFrom;
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Formatter};
 use std::hash::Hash;
 use std::iter::FromIterator;
+use std::ops::{ControlFlow, Div};
 use std::vec;
 use theon::adjunct::{FromItems, Map};
-use theon::query::Aabb;
-use theon::space::{EuclideanSpace, Scalar};
+use theon::ops::Cross;
+use theon::query::{Aabb, Plane};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector, VectorSpace};
 use theon::{AsPosition, AsPositionMut};
 use thiserror::Error;
-use typenum::{self, NonZero};
+use typenum::{self, NonZero, U3};
 
-use crate::buffer::{BufferError, FromRawBuffers, FromRawBuffersWithArity, MeshBuffer};
+use crate::buffer::{
+    BufferError, FromMeshBuffer, FromRawBuffers, FromRawBuffersWithArity, MeshBuffer,
+};
 use crate::builder::{Buildable, FacetBuilder, MeshBuilder, SurfaceBuilder};
-use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
+use crate::encoding::{AsColorMut, ColorRamp, FaceDecoder, FromEncoding, VertexDecoder};
 use crate::entity::storage::{AsStorage, AsStorageMut, AsStorageOf, Fuse, OpaqueKey, Storage};
 use crate::entity::view::{Bind, Orphan, View};
-use crate::entity::EntityError;
-use crate::geometry::{FromGeometry, IntoGeometry};
+use crate::entity::{Entity, EntityError};
+use crate::geometry::{arrangement, bound, delaunay, hull};
+use crate::geometry::{AsSeam, AsUv, AsUvMut, FromGeometry, IntoGeometry, ProjectXy, Uv};
 use crate::graph::builder::GraphBuilder;
 use crate::graph::core::{Core, OwnedCore};
 use crate::graph::data::Parametric;
-use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::face::{FaceExtrudeCache, FaceInsertCache, FacePokeCache};
 use crate::graph::mutation::{Consistent, Mutation};
-use crate::index::{Flat, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexVertices, Indexer};
+use crate::graph::sparse::conjugate_gradient;
+use crate::index::{
+    Flat, Flat2, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexVertices, Indexer,
+};
 use crate::primitive::decompose::IntoVertices;
 use crate::primitive::{IntoPolygons, Polygonal, UnboundedPolygon};
 use crate::transact::Transact;
 use crate::{DynamicArity, MeshArity, StaticArity};
 
 pub use crate::entity::view::{ClosedView, Rebind};
+pub use crate::graph::attribute::{AttributeKey, AttributeMap};
+pub use crate::graph::boolean::BooleanOp;
+pub use crate::graph::builder::CoreBuilder;
 pub use crate::graph::data::GraphData;
 pub use crate::graph::edge::{
     Arc, ArcKey, ArcOrphan, ArcView, Edge, EdgeKey, EdgeOrphan, EdgeView, ToArc,
 };
 pub use crate::graph::face::{Face, FaceKey, FaceOrphan, FaceView, Ring, ToRing};
 pub use crate::graph::geometry::{
-    ArcNormal, EdgeMidpoint, FaceCentroid, FaceNormal, FacePlane, VertexCentroid, VertexNormal,
-    VertexPosition,
+    ArcNormal, EdgeMidpoint, FaceCentroid, FaceNormal, FacePlane, GeometryPolicy, Handedness,
+    VertexCentroid, VertexNormal, VertexPosition,
 };
 pub use crate::graph::path::Path;
+pub use crate::graph::sparse::{LaplacianKind, SparseMatrix, VertexAdjacency};
 pub use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
+pub use crate::graph::voxel::{VoxelGrid, VoxelKind};
 
 pub use Selector::ByIndex;
 pub use Selector::ByKey;
@@ -345,6 +380,90 @@ pub enum GraphError {
     /// A graph or other data structure is not compatible with an encoding.
     #[error("encoding operation failed")]
     Encoding,
+    /// An operation failed for a particular entity.
+    ///
+    /// This error provides context for an underlying error, such as the key
+    /// of a vertex that lacks a leading arc when a mutation is committed. See
+    /// [`GraphError::root`].
+    #[error("{operation} failed for {key:?}")]
+    Entity {
+        /// The key of the offending entity.
+        key: GraphKey,
+        /// A short description of the failed operation.
+        operation: &'static str,
+        /// The underlying error.
+        source: Box<GraphError>,
+    },
+}
+
+impl GraphError {
+    /// Gets the underlying error, discarding any context.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use plexus::graph::GraphError;
+    ///
+    /// fn is_conflict(error: &GraphError) -> bool {
+    ///     matches!(error.root(), GraphError::TopologyConflict)
+    /// }
+    /// ```
+    pub fn root(&self) -> &GraphError {
+        match self {
+            GraphError::Entity { source, .. } => source.root(),
+            _ => self,
+        }
+    }
+
+    pub(in crate::graph) fn with_entity<K>(self, key: K, operation: &'static str) -> Self
+    where
+        K: Into<GraphKey>,
+    {
+        GraphError::Entity {
+            key: key.into(),
+            operation,
+            source: Box::new(self),
+        }
+    }
+}
+
+/// Key of an entity in a [`MeshGraph`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GraphKey {
+    /// Key of a vertex.
+    Vertex(VertexKey),
+    /// Key of an arc.
+    Arc(ArcKey),
+    /// Key of an edge.
+    Edge(EdgeKey),
+    /// Key of a face.
+    Face(FaceKey),
+}
+
+impl From<VertexKey> for GraphKey {
+    fn from(key: VertexKey) -> Self {
+        GraphKey::Vertex(key)
+    }
+}
+
+impl From<ArcKey> for GraphKey {
+    fn from(key: ArcKey) -> Self {
+        GraphKey::Arc(key)
+    }
+}
+
+impl From<EdgeKey> for GraphKey {
+    fn from(key: EdgeKey) -> Self {
+        GraphKey::Edge(key)
+    }
+}
+
+impl From<FaceKey> for GraphKey {
+    fn from(key: FaceKey) -> Self {
+        GraphKey::Face(key)
+    }
 }
 
 // TODO: How should buffer errors be handled? Is this sufficient?
@@ -354,6 +473,7 @@ impl From<BufferError> for GraphError {
             BufferError::ArityConflict { expected, actual } => {
                 GraphError::ArityConflict { expected, actual }
             }
+            BufferError::Geometry => GraphError::Geometry,
             _ => GraphError::Encoding,
         }
     }
@@ -481,6 +601,200 @@ impl<K> From<usize> for Selector<K> {
     }
 }
 
+/// Length and capacity of the storage of an entity.
+///
+/// See [`GraphCapacity`].
+///
+/// [`GraphCapacity`]: crate::graph::GraphCapacity
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StorageCapacity {
+    /// The number of entities in storage.
+    pub len: usize,
+    /// The number of entities that storage can hold without reallocating.
+    pub capacity: usize,
+}
+
+/// Per-entity storage capacity of a [`MeshGraph`].
+///
+/// See [`MeshGraph::capacity`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::capacity`]: crate::graph::MeshGraph::capacity
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GraphCapacity {
+    pub vertices: StorageCapacity,
+    pub arcs: StorageCapacity,
+    pub edges: StorageCapacity,
+    pub faces: StorageCapacity,
+}
+
+/// Mapping from the keys of entities in a [`MeshGraph`] to their keys after
+/// the graph has been compacted.
+///
+/// See [`MeshGraph::compact`] and [`AttributeMap::rekey`].
+///
+/// [`AttributeMap::rekey`]: crate::graph::AttributeMap::rekey
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::compact`]: crate::graph::MeshGraph::compact
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GraphRekeying {
+    pub vertices: HashMap<VertexKey, VertexKey>,
+    pub edges: HashMap<EdgeKey, EdgeKey>,
+    pub faces: HashMap<FaceKey, FaceKey>,
+}
+
+impl GraphRekeying {
+    /// Gets the key of a vertex after compaction.
+    pub fn vertex(&self, key: VertexKey) -> Option<VertexKey> {
+        self.vertices.get(&key).cloned()
+    }
+
+    /// Gets the key of an arc after compaction.
+    ///
+    /// Arcs are keyed by their vertices, so this is derived from the rekeying
+    /// of vertices.
+    pub fn arc(&self, key: ArcKey) -> Option<ArcKey> {
+        let (a, b) = key.into();
+        Some((self.vertex(a)?, self.vertex(b)?).into())
+    }
+
+    /// Gets the key of an edge after compaction.
+    pub fn edge(&self, key: EdgeKey) -> Option<EdgeKey> {
+        self.edges.get(&key).cloned()
+    }
+
+    /// Gets the key of a face after compaction.
+    pub fn face(&self, key: FaceKey) -> Option<FaceKey> {
+        self.faces.get(&key).cloned()
+    }
+}
+
+/// Differences between two [`MeshGraph`]s.
+///
+/// Vertices are put into correspondence by their positions and faces are put
+/// into correspondence by their vertices. Entities are _removed_ if they are
+/// in the first graph but have no corresponding entity in the second graph and
+/// are _inserted_ if they are in the second graph but have no corresponding
+/// entity in the first graph. Keys of removed entities refer to the first
+/// graph and keys of inserted entities refer to the second graph.
+///
+/// The `Debug` implementation prints a human-readable summary.
+///
+/// See [`MeshGraph::diff`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::diff`]: crate::graph::MeshGraph::diff
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct MeshDiff {
+    /// The number of vertices in the second graph less the number of
+    /// vertices in the first graph.
+    pub vertex_count_delta: isize,
+    /// The number of arcs in the second graph less the number of arcs in the
+    /// first graph.
+    pub arc_count_delta: isize,
+    /// The number of edges in the second graph less the number of edges in
+    /// the first graph.
+    pub edge_count_delta: isize,
+    /// The number of faces in the second graph less the number of faces in
+    /// the first graph.
+    pub face_count_delta: isize,
+    pub removed_vertices: Vec<VertexKey>,
+    pub inserted_vertices: Vec<VertexKey>,
+    pub removed_faces: Vec<FaceKey>,
+    pub inserted_faces: Vec<FaceKey>,
+}
+
+impl MeshDiff {
+    /// Returns `true` if there are no differences.
+    ///
+    /// Note that arcs and edges are only compared by count, so graphs with
+    /// corresponding vertices and faces may still differ in their boundary
+    /// edges. Such differences are reflected by the count deltas.
+    pub fn is_empty(&self) -> bool {
+        self.vertex_count_delta == 0
+            && self.arc_count_delta == 0
+            && self.edge_count_delta == 0
+            && self.face_count_delta == 0
+            && self.removed_vertices.is_empty()
+            && self.inserted_vertices.is_empty()
+            && self.removed_faces.is_empty()
+            && self.inserted_faces.is_empty()
+    }
+}
+
+impl Debug for MeshDiff {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(formatter, "MeshDiff (no differences)");
+        }
+        writeln!(formatter, "MeshDiff")?;
+        writeln!(
+            formatter,
+            "  vertices: {:+} ({} inserted, {} removed)",
+            self.vertex_count_delta,
+            self.inserted_vertices.len(),
+            self.removed_vertices.len(),
+        )?;
+        writeln!(formatter, "  arcs: {:+}", self.arc_count_delta)?;
+        writeln!(formatter, "  edges: {:+}", self.edge_count_delta)?;
+        write!(
+            formatter,
+            "  faces: {:+} ({} inserted, {} removed)",
+            self.face_count_delta,
+            self.inserted_faces.len(),
+            self.removed_faces.len(),
+        )?;
+        for key in &self.removed_vertices {
+            write!(formatter, "\n  - vertex {:?}", key)?;
+        }
+        for key in &self.inserted_vertices {
+            write!(formatter, "\n  + vertex {:?}", key)?;
+        }
+        for key in &self.removed_faces {
+            write!(formatter, "\n  - face {:?}", key)?;
+        }
+        for key in &self.inserted_faces {
+            write!(formatter, "\n  + face {:?}", key)?;
+        }
+        Ok(())
+    }
+}
+
+/// Distribution of texel densities over the faces of a [`MeshGraph`].
+///
+/// See [`MeshGraph::uv_stretch_report`] and [`FaceView::texel_density`].
+///
+/// [`FaceView::texel_density`]: crate::graph::FaceView::texel_density
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::uv_stretch_report`]: crate::graph::MeshGraph::uv_stretch_report
+#[derive(Clone, Debug, PartialEq)]
+pub struct UvStretchReport<T> {
+    pub min: T,
+    pub max: T,
+    pub mean: T,
+    /// The texel density of each face with area in the order of
+    /// [`MeshGraph::faces`].
+    ///
+    /// [`MeshGraph::faces`]: crate::graph::MeshGraph::faces
+    pub densities: Vec<(FaceKey, T)>,
+    /// Faces with no area, for which the texel density is undefined.
+    pub degenerate: Vec<FaceKey>,
+}
+
+impl<T> UvStretchReport<T>
+where
+    T: Copy + Div<Output = T>,
+{
+    /// Gets the ratio of the maximum texel density to the minimum texel
+    /// density.
+    ///
+    /// This is one if all faces have the same texel density. Larger ratios
+    /// indicate more stretch between faces.
+    pub fn ratio(&self) -> T {
+        self.max / self.min
+    }
+}
+
 /// [Half-edge graph][dcel] representation of a polygonal mesh.
 ///
 /// `MeshGraph`s form a polygonal mesh from four interconnected entities:
@@ -530,7 +844,215 @@ where
         )
     }
 
+    /// Creates a graph from a fully linked core, verifying its consistency.
+    ///
+    /// Unlike graphs built by the mutation API, the topology of a
+    /// [`CoreBuilder`] is linked directly. This allows importers that already
+    /// encode half-edge connectivity to preserve their topology rather than
+    /// rebuilding it from index buffers. See [`MeshGraph::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns all defects found in the core if it is inconsistent.
+    ///
+    /// [`CoreBuilder`]: crate::graph::CoreBuilder
+    /// [`MeshGraph::validate`]: crate::graph::MeshGraph::validate
+    pub fn from_core_checked(core: CoreBuilder<G>) -> Result<Self, Vec<GraphError>> {
+        MeshGraph::try_from_core(core.into_core())
+    }
+
+    // Unlike the `From` conversion, this does not assume that the core has been
+    // built by the mutation API.
+    pub(in crate::graph) fn try_from_core(core: OwnedCore<G>) -> Result<Self, Vec<GraphError>> {
+        let errors = validate(&core);
+        if errors.is_empty() {
+            Ok(MeshGraph::from(core.recount_boundary_arcs()))
+        }
+        else {
+            Err(errors)
+        }
+    }
+
+    /// Creates a graph from raw buffers without verifying its topology.
+    ///
+    /// Unlike [`FromRawBuffers::from_raw_buffers`], this does not insert faces
+    /// via the mutation API and so skips the consistency and manifold checks
+    /// that are performed for each face. Instead, connectivity is linked
+    /// directly from the index buffer, which is considerably faster for large
+    /// meshes. This is intended for trusted input, such as buffers exported
+    /// from a [`MeshGraph`].
+    ///
+    /// The input must describe a consistent and manifold mesh: every face must
+    /// have an arity of at least three, no two faces may share an arc, and
+    /// every vertex may be the source of at most one boundary arc. If the input
+    /// violates these requirements, then the graph is inconsistent and
+    /// subsequent operations may panic or produce incorrect results. Use
+    /// [`MeshGraph::validate`] to verify the graph when in doubt.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let graph = MeshGraph::<Point2<f64>>::from_raw_buffers_unchecked(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// );
+    /// assert!(graph.validate().is_ok());
+    /// ```
+    ///
+    /// [`FromRawBuffers::from_raw_buffers`]: crate::buffer::FromRawBuffers::from_raw_buffers
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    /// [`MeshGraph::validate`]: crate::graph::MeshGraph::validate
+    pub fn from_raw_buffers_unchecked<P, H, I, J>(indices: I, vertices: J) -> Self
+    where
+        P: IntoVertices + Polygonal,
+        P::Vertex: Integer + ToPrimitive + Unsigned,
+        G::Vertex: FromGeometry<H>,
+        I: IntoIterator<Item = P>,
+        J: IntoIterator<Item = H>,
+    {
+        let mut vertex_storage = Storage::<Vertex<G>>::new();
+        let mut arc_storage = Storage::<Arc<G>>::new();
+        let mut edge_storage = Storage::<Edge<G>>::new();
+        let mut face_storage = Storage::<Face<G>>::new();
+        let keys = vertices
+            .into_iter()
+            .map(|vertex| vertex_storage.insert(Vertex::new(vertex.into_geometry())))
+            .collect::<Vec<_>>();
+        // Insert and link the rings of faces.
+        for face in indices {
+            let perimeter = face
+                .into_vertices()
+                .into_iter()
+                .map(|index| keys[<usize as NumCast>::from(index).unwrap()])
+                .collect::<SmallVec<[_; 4]>>();
+            let n = perimeter.len();
+            let key =
+                |index: usize| ArcKey::from((perimeter[index % n], perimeter[(index + 1) % n]));
+            let mut abc = Face::new(key(0), Default::default());
+            abc.arity = Some(n);
+            let abc = face_storage.insert(abc);
+            for index in 0..n {
+                let mut arc = Arc::new(Default::default());
+                arc.next = Some(key(index + 1));
+                arc.previous = Some(key(index + n - 1));
+                arc.face = Some(abc);
+                arc_storage.insert_with_key(key(index), arc);
+                vertex_storage.get_mut(&perimeter[index]).unwrap().arc = Some(key(index));
+            }
+        }
+        // Insert opposite arcs on the boundary and edges.
+        let mut boundaries = HashMap::new();
+        let mut boundary_arc_count = 0;
+        for ab in arc_storage.keys().collect::<Vec<_>>() {
+            let (a, b): (VertexKey, VertexKey) = ab.into();
+            let ba = ArcKey::from((b, a));
+            if !arc_storage.contains_key(&ba) {
+                arc_storage.insert_with_key(ba, Arc::new(Default::default()));
+                boundaries.insert(b, ba);
+                boundary_arc_count += 1;
+            }
+            if arc_storage.get(&ab).unwrap().edge.is_none() {
+                let ab_ba = edge_storage.insert(Edge::new(ab, Default::default()));
+                for arc in &[ab, ba] {
+                    arc_storage.get_mut(arc).unwrap().edge = Some(ab_ba);
+                }
+            }
+        }
+        // Link boundary arcs into rings. The next arc of a boundary arc is the
+        // boundary arc that leaves its destination vertex.
+        for (_, ba) in boundaries.iter() {
+            let (_, a): (VertexKey, VertexKey) = (*ba).into();
+            if let Some(ax) = boundaries.get(&a) {
+                arc_storage.get_mut(ba).unwrap().next = Some(*ax);
+                arc_storage.get_mut(ax).unwrap().previous = Some(*ba);
+            }
+        }
+        MeshGraph::from(
+            Core::empty()
+                .fuse(vertex_storage)
+                .fuse(arc_storage)
+                .fuse(edge_storage)
+                .fuse(face_storage)
+                .with_boundary_arc_count(boundary_arc_count),
+        )
+    }
+
+    /// Verifies the consistency of the graph.
+    ///
+    /// Every vertex must have an outgoing leading arc; every arc must have an
+    /// opposite arc, an edge, and next and previous arcs that are joined to it
+    /// at its destination and source vertices, respectively; and every face
+    /// must have a closed ring of arcs that refer to it. Graphs modified only
+    /// via the mutation API are always consistent, so this is primarily a
+    /// debugging aid.
+    ///
+    /// The number of boundary arcs maintained by the graph (see
+    /// [`boundary_arc_count`]) must also agree with its arcs.
+    ///
+    /// # Errors
+    ///
+    /// Returns all defects found in the graph. Each error refers to the key of
+    /// the offending entity (see [`GraphError::Entity`]), except for
+    /// [`GraphError::TopologyMalformed`] if the number of boundary arcs is
+    /// incorrect.
+    ///
+    /// [`boundary_arc_count`]: crate::graph::MeshGraph::boundary_arc_count
+    /// [`GraphError::Entity`]: crate::graph::GraphError::Entity
+    /// [`GraphError::TopologyMalformed`]: crate::graph::GraphError::TopologyMalformed
+    pub fn validate(&self) -> Result<(), Vec<GraphError>> {
+        let mut errors = validate(self);
+        let boundary_arc_count = self
+            .as_storage_of::<Arc<_>>()
+            .iter()
+            .filter(|(_, arc)| arc.face.is_none())
+            .count();
+        if boundary_arc_count != self.boundary_arc_count() {
+            errors.push(GraphError::TopologyMalformed);
+        }
+        if errors.is_empty() {
+            Ok(())
+        }
+        else {
+            Err(errors)
+        }
+    }
+
+    /// Gets the keys of arcs that are not properly joined to an edge.
+    ///
+    /// An arc is orphaned if it has no edge, its edge cannot be found, or its
+    /// opposite arc cannot be found. Graphs modified only via the mutation API
+    /// never have orphaned arcs, so this is primarily a debugging aid. See
+    /// [`validate`].
+    ///
+    /// This scans the arcs of the graph and is $O(n)$ in the number of arcs.
+    ///
+    /// [`validate`]: crate::graph::MeshGraph::validate
+    pub fn orphaned_arcs(&self) -> Vec<ArcKey> {
+        let arcs = self.as_storage_of::<Arc<_>>();
+        let edges = self.as_storage_of::<Edge<_>>();
+        arcs.iter()
+            .filter(|(ab, arc)| {
+                !arc.edge.map_or(false, |edge| edges.contains_key(&edge))
+                    || !arcs.contains_key(&ab.into_opposite())
+            })
+            .map(|(ab, _)| ab)
+            .collect()
+    }
+
     /// Gets the number of vertices in the graph.
+    ///
+    /// This is $O(1)$.
     pub fn vertex_count(&self) -> usize {
         self.as_storage_of::<Vertex<_>>().len()
     }
@@ -563,6 +1085,11 @@ where
     }
 
     /// Gets the number of arcs in the graph.
+    ///
+    /// Every edge is composed of a pair of opposing arcs, including edges along
+    /// boundaries, so there are always twice as many arcs as edges.
+    ///
+    /// This is $O(1)$.
     pub fn arc_count(&self) -> usize {
         self.as_storage_of::<Arc<_>>().len()
     }
@@ -579,6 +1106,11 @@ where
 
     // TODO: Return `Clone + Iterator`.
     /// Gets an iterator of immutable views over the arcs in the graph.
+    ///
+    /// Arcs are directed and both arcs of each edge are visited. To visit each
+    /// undirected edge once, see [`edges`].
+    ///
+    /// [`edges`]: crate::graph::MeshGraph::edges
     pub fn arcs(&self) -> impl ExactSizeIterator<Item = ArcView<&Self>> {
         self.as_storage_of::<Arc<_>>()
             .keys()
@@ -595,6 +1127,14 @@ where
     }
 
     /// Gets the number of edges in the graph.
+    ///
+    /// Every edge is either an interior edge or an open edge, so this is the
+    /// sum of [`interior_edge_count`] and [`open_edge_count`].
+    ///
+    /// This is $O(1)$.
+    ///
+    /// [`interior_edge_count`]: crate::graph::MeshGraph::interior_edge_count
+    /// [`open_edge_count`]: crate::graph::MeshGraph::open_edge_count
     pub fn edge_count(&self) -> usize {
         self.as_storage_of::<Edge<_>>().len()
     }
@@ -611,6 +1151,11 @@ where
 
     // TODO: Return `Clone + Iterator`.
     /// Gets an iterator of immutable views over the edges in the graph.
+    ///
+    /// Edges are undirected. The arcs of an edge can be queried via
+    /// [`EdgeView::arc`] and its opposite.
+    ///
+    /// [`EdgeView::arc`]: crate::graph::EdgeView::arc
     pub fn edges(&self) -> impl ExactSizeIterator<Item = EdgeView<&Self>> {
         self.as_storage_of::<Edge<_>>()
             .keys()
@@ -626,7 +1171,40 @@ where
             .map(From::from)
     }
 
+    /// Gets an iterator over the edges in the graph with the positions of
+    /// their vertices and their data.
+    ///
+    /// Each item is the key of an edge, the positions of the source and
+    /// destination vertices of its leading arc (see [`EdgeView::arc`]), and
+    /// the data of the edge. This is useful for exporting or visualizing
+    /// edges, because no views are constructed.
+    ///
+    /// [`EdgeView::arc`]: crate::graph::EdgeView::arc
+    pub fn edge_segments_with_data(
+        &self,
+    ) -> impl '_
+           + Clone
+           + ExactSizeIterator<Item = (EdgeKey, &VertexPosition<G>, &VertexPosition<G>, &G::Edge)>
+    where
+        G::Vertex: AsPosition,
+    {
+        let keys = self.as_storage_of::<Edge<_>>().keys().collect::<Vec<_>>();
+        keys.into_iter().map(move |key| {
+            let edge = self
+                .as_storage_of::<Edge<G>>()
+                .get(&key)
+                .expect_consistent();
+            let (a, b): (VertexKey, VertexKey) = edge.arc.into();
+            let vertices = self.as_storage_of::<Vertex<G>>();
+            let position =
+                |key: VertexKey| vertices.get(&key).expect_consistent().data.as_position();
+            (key, position(a), position(b), &edge.data)
+        })
+    }
+
     /// Gets the number of faces in the graph.
+    ///
+    /// This is $O(1)$.
     pub fn face_count(&self) -> usize {
         self.as_storage_of::<Face<_>>().len()
     }
@@ -658,342 +1236,232 @@ where
             .map(From::from)
     }
 
-    /// Gets an immutable path over the given sequence of vertex keys.
+    /// Gets an iterator of immutable views over the faces in the graph with
+    /// the given arity.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns an error if a vertex is not found or the path is malformed.
-    pub fn path<I>(&self, keys: I) -> Result<Path<&Self>, GraphError>
-    where
-        I: IntoIterator,
-        I::Item: Borrow<VertexKey>,
-    {
-        Path::bind(self, keys)
+    /// Counting the triangles in a mixed graph:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::{Tetragon, Trigon, UnboundedPolygon};
+    ///
+    /// let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![
+    ///         UnboundedPolygon::from(Tetragon::new(0usize, 1, 2, 3)),
+    ///         UnboundedPolygon::from(Trigon::new(1, 4, 2)),
+    ///     ],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (2.0, 0.5)],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(1, graph.faces_with_arity(3).count());
+    /// ```
+    pub fn faces_with_arity(
+        &self,
+        arity: usize,
+    ) -> impl Clone + ExactSizeIterator<Item = FaceView<&Self>> {
+        let keys = self
+            .faces()
+            .filter(|face| face.arity() == arity)
+            .map(|face| face.key())
+            .collect::<Vec<_>>();
+        keys.into_iter()
+            .map(move |key| View::bind_unchecked(self, key))
+            .map(From::from)
     }
 
-    /// Gets a mutable path over the given sequence of vertex keys.
-    ///
-    /// # Errors
+    /// Gets the number of faces in the graph with each arity.
     ///
-    /// Returns an error if a vertex is not found or the path is malformed.
-    pub fn path_mut<I>(&mut self, keys: I) -> Result<Path<&mut Self>, GraphError>
-    where
-        I: IntoIterator,
-        I::Item: Borrow<VertexKey>,
-    {
-        Path::bind(self, keys)
+    /// The returned map is keyed by arity and only contains arities of faces
+    /// in the graph.
+    pub fn arity_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for face in self.faces() {
+            *histogram.entry(face.arity()).or_insert(0) += 1;
+        }
+        histogram
     }
 
-    /// Gets an axis-aligned bounding box that encloses the graph.
-    pub fn aabb(&self) -> Aabb<VertexPosition<G>>
+    /// Inserts a face into the graph, computing the data of its arcs from
+    /// their endpoints.
+    ///
+    /// The face is formed from the given vertex keys in order. The given
+    /// function is called with the source and destination vertices of each arc
+    /// that is inserted, including the opposite arcs of any new edges, and
+    /// returns the data for that arc. Arcs that already exist are not
+    /// modified.
+    ///
+    /// Faces with new vertices can be inserted with arc data while building
+    /// a graph via [`MeshGraph::builder`].
+    ///
+    /// Returns the key of the inserted face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertices cannot form a face, such as when a
+    /// vertex cannot be found or the face would conflict with existing
+    /// topology.
+    ///
+    /// [`MeshGraph::builder`]: crate::builder::Buildable::builder
+    pub fn insert_face_with_arc_data<K, F>(
+        &mut self,
+        keys: K,
+        data: G::Face,
+        f: F,
+    ) -> Result<FaceKey, GraphError>
     where
-        G::Vertex: AsPosition,
-        VertexPosition<G>: EuclideanSpace,
-        Scalar<VertexPosition<G>>: IntrinsicOrd,
+        K: AsRef<[VertexKey]>,
+        F: FnMut(VertexKey, VertexKey) -> G::Arc,
     {
-        Aabb::from_points(self.vertices().map(|vertex| *vertex.data.as_position()))
+        let cache = FaceInsertCache::from_storage(&*self, keys.as_ref())?;
+        Mutation::from_graph(self)
+            .commit_with(|mutation| {
+                mutation::face::insert_with_arc_data(mutation, cache, move || data, f)
+            })
+            .map(|(_, face)| face)
     }
 
-    // TODO: This triangulation does not consider geometry and exhibits some
-    //       bad behavior in certain situations. Triangulation needs to be
-    //       reworked and may need to expose a bit more complexity. A geometric
-    //       triangulation algorithm would be a useful addition and could
-    //       detect concave faces and provide more optimal splits. See comments
-    //       on `FaceView::triangulate`.
-    /// Triangulates the graph, tessellating all faces into triangles.
-    pub fn triangulate(&mut self) {
-        // TODO: This implementation is a bit fragile and depends on the
-        //       semantics of `TopologyConflict` in this context. It also panics
-        //       if no valid split is found given all offsets or if some other
-        //       error is encountered while splitting. Can this code assume that
-        //       any of these conditions aren't possible? This should work a bit
-        //       better than using `FaceView::triangulate` until triangulation
-        //       is reworked.
-        let keys = self.as_storage_of::<Face<_>>().keys().collect::<Vec<_>>();
-        for key in keys {
-            let mut face = self.face_mut(key).unwrap();
-            let mut offset = 0;
-            while face.arity() > 3 {
-                match face.split(ByIndex(offset), ByIndex(offset + 2)) {
-                    Ok(next) => {
-                        face = next.into_face().expect_consistent();
-                        offset = 0;
-                    }
-                    Err(GraphError::TopologyConflict) => {
-                        // Retry if the split intersected another face. See
-                        // `FaceSplitCache::from_face`.
-                        face = self.face_mut(key).unwrap();
-                        offset += 1;
-                        if offset >= face.arity() {
-                            panic!()
-                        }
-                    }
-                    _ => panic!(),
-                }
-            }
-        }
-    }
-
-    /// Smooths the positions of vertices in the graph.
-    ///
-    /// Each position is translated by its offset from its centroid scaled by
-    /// the given factor. The centroid of a vertex position is the mean of the
-    /// positions of its adjacent vertices. That is, given a factor $k$ and a
-    /// vertex with position $P$ and centroid $Q$, its position becomes
-    /// $P+k(Q-P)$.
-    pub fn smooth<T>(&mut self, factor: T)
-    where
-        T: Into<Scalar<VertexPosition<G>>>,
-        G: VertexCentroid,
-        G::Vertex: AsPositionMut,
-        VertexPosition<G>: EuclideanSpace,
-    {
-        let factor = factor.into();
-        let mut positions = HashMap::with_capacity(self.vertex_count());
-        for vertex in self.vertices() {
-            let position = *vertex.position();
-            positions.insert(
-                vertex.key(),
-                position + ((vertex.centroid() - position) * factor),
-            );
-        }
-        for mut vertex in self.vertex_orphans() {
-            *vertex.data.as_position_mut() = positions.remove(&vertex.key()).unwrap();
-        }
-    }
-
-    /// Splits the graph along a path.
+    /// Returns `true` if the graph is closed.
     ///
-    /// Splitting a graph creates boundaries along the given path and copies any
-    /// necessary vertex, arc, and edge geometry.
+    /// A _closed_ (or _watertight_) graph has no boundary arcs. That is, every
+    /// arc is part of a face. Closedness is typically required for computing
+    /// volumes, boolean operations, and fabrication. Empty graphs are closed.
     ///
-    /// If the path bisects the graph, then splitting will result in disjointed
-    /// sub-graphs.
+    /// This is $O(1)$. See [`boundary_arc_count`].
     ///
     /// # Examples
     ///
-    /// ```rust,no_run
+    /// ```rust
+    /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
     /// #
-    /// use nalgebra::Point2;
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
     /// use plexus::graph::MeshGraph;
     /// use plexus::prelude::*;
-    /// use plexus::primitive::Trigon;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
     ///
-    /// type E2 = Point2<f64>;
+    /// type E3 = Point3<R64>;
     ///
-    /// // Create a graph from two triangles.
-    /// let mut graph = MeshGraph::<E2>::from_raw_buffers(
-    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 1, 3)],
-    ///     vec![
-    ///         (-1.0, 0.0),
-    ///         (0.0, -1.0),
-    ///         (0.0, 1.0),
-    ///         (1.0, 0.0),
-    ///     ],
-    /// )
-    /// .unwrap();
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// assert!(graph.is_closed());
     ///
-    /// // Find the shared edge that bisects the triangles and then construct a path
-    /// // along the edge and split the graph.
-    /// let key = graph
-    ///     .edges()
-    ///     .find(|edge| !edge.is_boundary_edge())
-    ///     .map(|edge| edge.into_arc().key())
-    ///     .unwrap();
-    /// let mut path = graph.arc_mut(key).unwrap().into_path();
-    /// MeshGraph::split_at_path(path).unwrap();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph.face_mut(key).unwrap().remove();
+    /// assert!(!graph.is_closed());
+    /// assert_eq!(4, graph.open_edge_count());
     /// ```
-    pub fn split_at_path(path: Path<&mut Self>) -> Result<(), GraphError> {
-        let _ = path;
-        unimplemented!()
+    ///
+    /// [`boundary_arc_count`]: crate::graph::MeshGraph::boundary_arc_count
+    pub fn is_closed(&self) -> bool {
+        self.boundary_arc_count() == 0
     }
 
-    /// Gets an iterator over a vertex within each disjoint sub-graph.
-    ///
-    /// Traverses the graph and returns an arbitrary vertex within each
-    /// _disjoint sub-graph_. A sub-graph is _disjoint_ if it cannot be reached
-    /// from all other topology in the graph.
+    /// Gets the number of boundary arcs in the graph.
     ///
-    /// # Examples
+    /// A _boundary arc_ is an arc that is not part of a face (see
+    /// [`is_boundary_arc`]). This count is maintained as the graph is mutated,
+    /// so this is $O(1)$.
     ///
-    /// ```rust
-    /// # extern crate nalgebra;
-    /// # extern crate plexus;
-    /// #
-    /// use nalgebra::Point2;
-    /// use plexus::graph::MeshGraph;
-    /// use plexus::prelude::*;
-    /// use plexus::primitive::Trigon;
+    /// [`is_boundary_arc`]: crate::graph::ArcView::is_boundary_arc
+    pub fn boundary_arc_count(&self) -> usize {
+        self.core.boundary_arc_count()
+    }
+
+    /// Gets the number of open edges in the graph.
     ///
-    /// type E2 = Point2<f64>;
+    /// An _open edge_ is an edge with a boundary arc (see
+    /// [`is_boundary_edge`]). Closed graphs have no open edges. See
+    /// [`is_closed`].
     ///
-    /// // Create a graph from two disjoint triangles.
-    /// let graph = MeshGraph::<E2>::from_raw_buffers(
-    ///     vec![Trigon::new(0u32, 1, 2), Trigon::new(3, 4, 5)],
-    ///     vec![
-    ///         (-2.0, 0.0),
-    ///         (-1.0, 0.0),
-    ///         (-1.0, 1.0),
-    ///         (1.0, 0.0),
-    ///         (2.0, 0.0),
-    ///         (1.0, 1.0),
-    ///     ],
-    /// )
-    /// .unwrap();
+    /// Open edges still have two arcs. An open edge along the boundary of a
+    /// surface has one boundary arc, while a disjoint edge has two, so
+    /// [`boundary_arc_count`] is at least the number of open edges.
     ///
-    /// // A vertex from each disjoint triangle is returned.
-    /// for vertex in graph.disjoint_subgraph_vertices() {
-    ///     // ...
-    /// }
-    /// ```
-    pub fn disjoint_subgraph_vertices(&self) -> impl ExactSizeIterator<Item = VertexView<&Self>> {
-        let keys = self
-            .as_storage_of::<Vertex<_>>()
-            .keys()
-            .collect::<HashSet<_>>();
-        let mut subkeys = HashSet::with_capacity(self.vertex_count());
-        let mut vertices = SmallVec::<[VertexView<_>; 4]>::new();
-        while let Some(key) = keys.difference(&subkeys).nth(0) {
-            let vertex = VertexView::from(View::bind_unchecked(self, *key));
-            vertices.push(vertex);
-            subkeys.extend(vertex.traverse_by_depth().map(|vertex| vertex.key()));
-        }
-        vertices.into_iter()
+    /// [`boundary_arc_count`]: crate::graph::MeshGraph::boundary_arc_count
+    /// [`is_boundary_edge`]: crate::graph::EdgeView::is_boundary_edge
+    /// [`is_closed`]: crate::graph::MeshGraph::is_closed
+    pub fn open_edge_count(&self) -> usize {
+        self.edges().filter(|edge| edge.is_boundary_edge()).count()
     }
 
-    /// Moves disjoint sub-graphs into separate graphs.
-    pub fn into_disjoint_subgraphs(self) -> Vec<Self> {
-        unimplemented!()
+    /// Gets the number of interior edges in the graph.
+    ///
+    /// An _interior edge_ is an edge with no boundary arcs, such that both of
+    /// its arcs are part of a face. This is the number of edges less the
+    /// number of open edges (see [`open_edge_count`]).
+    ///
+    /// [`open_edge_count`]: crate::graph::MeshGraph::open_edge_count
+    pub fn interior_edge_count(&self) -> usize {
+        self.edge_count() - self.open_edge_count()
     }
 
-    /// Creates a [`Buildable`] mesh data structure from the graph.
+    /// Gets the number of holes in the graph.
     ///
-    /// The output is created from each unique vertex in the graph. No face data
-    /// is used, and the `Facet` type is always the unit type `()`.
+    /// Holes are the boundaries of the graph. Each hole is a ring of boundary
+    /// arcs, so this is the number of such rings. Closed graphs have no holes.
     ///
-    /// # Examples
+    /// For a connected orientable surface with boundary, the number of holes
+    /// $b$ relates the Euler characteristic $\chi$ to the genus $g$ of the
+    /// surface: $\chi = 2 - 2g - b$.
     ///
-    /// Creating a [`MeshBuffer`] from a [`MeshGraph`] used to modify a cube:
+    /// # Examples
     ///
     /// ```rust
     /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
     /// #
-    /// use decorum::N64;
+    /// use decorum::R64;
     /// use nalgebra::Point3;
-    /// use plexus::buffer::MeshBufferN;
     /// use plexus::graph::MeshGraph;
     /// use plexus::prelude::*;
     /// use plexus::primitive::cube::Cube;
     /// use plexus::primitive::generate::Position;
     ///
-    /// type E3 = Point3<N64>;
+    /// type E3 = Point3<R64>;
     ///
     /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
-    /// let key = graph.faces().nth(0).unwrap().key();
-    /// graph
-    ///     .face_mut(key)
-    ///     .unwrap()
-    ///     .extrude_with_offset(1.0)
-    ///     .unwrap();
+    /// assert_eq!(0, graph.hole_count());
     ///
-    /// let buffer: MeshBufferN<usize, E3> = graph.to_mesh_by_vertex().unwrap();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph.face_mut(key).unwrap().remove();
+    /// assert_eq!(1, graph.hole_count());
     /// ```
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the graph does not have constant arity that is
-    /// compatible with the index buffer. Typically, a graph is triangulated
-    /// before being converted to a buffer.
-    ///
-    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
-    /// [`Buildable`]: crate::builder::Buildable
-    /// [`MeshGraph`]: crate::graph::MeshGraph
-    pub fn to_mesh_by_vertex<B>(&self) -> Result<B, B::Error>
-    where
-        B: Buildable<Facet = ()>,
-        B::Vertex: FromGeometry<G::Vertex>,
-    {
-        self.to_mesh_by_vertex_with(|vertex| vertex.data.into_geometry())
-    }
-
-    /// Creates a [`Buildable`] mesh data structure from the graph.
-    ///
-    /// The output is created from each unique vertex in the graph, which is
-    /// converted by the given function. No face data is used, and the `Facet`
-    /// type is always the unit type `()`.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the vertex geometry cannot be inserted into the
-    /// output, there are arity conflicts, or the output does not support
-    /// topology found in the graph.
-    ///
-    /// [`Buildable`]: crate::builder::Buildable
-    pub fn to_mesh_by_vertex_with<B, F>(&self, mut f: F) -> Result<B, B::Error>
-    where
-        B: Buildable<Facet = ()>,
-        F: FnMut(VertexView<&Self>) -> B::Vertex,
-    {
-        let mut builder = B::builder();
-        builder.surface_with(|builder| {
-            let mut keys = HashMap::with_capacity(self.vertex_count());
-            for vertex in self.vertices() {
-                keys.insert(vertex.key(), builder.insert_vertex(f(vertex))?);
+    pub fn hole_count(&self) -> usize {
+        let mut visited = HashSet::new();
+        let mut count = 0;
+        for arc in self.arcs().filter(|arc| arc.is_boundary_arc()) {
+            if visited.contains(&arc.key()) {
+                continue;
             }
-            builder.facets_with(|builder| {
-                for face in self.faces() {
-                    let indices = face
-                        .adjacent_vertices()
-                        .map(|vertex| keys[&vertex.key()])
-                        .collect::<SmallVec<[_; 8]>>();
-                    builder.insert_facet(indices.as_slice(), ())?;
-                }
-                Ok(())
-            })
-        })?;
-        builder.build()
+            visited.extend(arc.ring().arcs().map(|arc| arc.key()));
+            count += 1;
+        }
+        count
     }
 
-    /// Creates a [`Buildable`] mesh data structure from the graph.
-    ///
-    /// The output is created from each face in the graph. For each face, the
-    /// face data and data for each of its vertices is inserted into the mesh
-    /// via [`FromGeometry`]. This means that a vertex is inserted for each of
-    /// its adjacent faces.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the vertex geometry cannot be inserted into the
-    /// output, there are arity conflicts, or the output does not support
-    /// topology found in the graph.
+    /// Returns `true` if the graph has the same topology and vertex positions
+    /// as another graph, regardless of the keys of their entities.
     ///
-    /// [`Buildable`]: crate::builder::Buildable
-    /// [`FromGeometry`]: crate::geometry::FromGeometry
-    pub fn to_mesh_by_face<B>(&self) -> Result<B, B::Error>
-    where
-        B: Buildable,
-        B::Vertex: FromGeometry<G::Vertex>,
-        B::Facet: FromGeometry<G::Face>,
-    {
-        self.to_mesh_by_face_with(|_, vertex| vertex.data.into_geometry())
-    }
-
-    /// Creates a [`Buildable`] mesh data structure from the graph.
+    /// Vertices are put into correspondence by their positions, so this is
+    /// intended for graphs with distinct vertex positions, such as graphs
+    /// built by the same construction. This is useful for comparing the
+    /// results of mesh operations against expected graphs in tests.
     ///
-    /// The output is created from each face in the graph. For each face, the
-    /// face data and data for each of its vertices is converted into the output
-    /// vertex data by the given function. This means that a vertex is inserted
-    /// for each of its adjacent faces. The data of each face is is inserted
-    /// into the output via [`FromGeometry`].
+    /// See [`structurally_eq`].
     ///
     /// # Examples
     ///
-    /// Creating a [`MeshBuffer`] from a [`MeshGraph`] used to compute normals:
-    ///
     /// ```rust
     /// # extern crate decorum;
     /// # extern crate nalgebra;
@@ -1001,619 +1469,5617 @@ where
     /// #
     /// use decorum::R64;
     /// use nalgebra::Point3;
-    /// use plexus::buffer::MeshBuffer;
-    /// use plexus::geometry::Vector;
     /// use plexus::graph::MeshGraph;
     /// use plexus::prelude::*;
     /// use plexus::primitive::cube::Cube;
     /// use plexus::primitive::generate::Position;
-    /// use plexus::primitive::BoundedPolygon;
     ///
     /// type E3 = Point3<R64>;
     ///
-    /// pub struct Vertex {
-    ///     pub position: E3,
-    ///     pub normal: Vector<E3>,
-    /// }
-    ///
     /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let mut other: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// assert!(graph.is_isomorphic_to(&other));
     ///
-    /// let buffer: MeshBuffer<BoundedPolygon<usize>, _> = graph
-    ///     .to_mesh_by_face_with(|face, vertex| Vertex {
-    ///         position: *vertex.position(),
-    ///         normal: face.normal().unwrap(),
-    ///     })
-    ///     .unwrap();
+    /// let key = other.faces().nth(0).unwrap().key();
+    /// other.face_mut(key).unwrap().triangulate();
+    /// assert!(!graph.is_isomorphic_to(&other));
     /// ```
     ///
-    /// # Errors
+    /// [`structurally_eq`]: crate::graph::MeshGraph::structurally_eq
+    pub fn is_isomorphic_to(&self, other: &Self) -> bool
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: PartialEq,
+    {
+        self.is_isomorphic_with(other, |a, b| a == b)
+    }
+
+    /// Returns `true` if the graph has the same topology as another graph and
+    /// vertex positions that are within a distance of each other, regardless
+    /// of the keys of their entities.
     ///
-    /// Returns an error if the vertex geometry cannot be inserted into the
-    /// output, there are arity conflicts, or the output does not support
-    /// topology found in the graph.
+    /// Vertices are put into correspondence by their positions, so the
+    /// tolerance should be less than half of the distance between any two
+    /// vertices in the graphs.
     ///
-    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
-    /// [`Buildable`]: crate::builder::Buildable
-    /// [`FromGeometry`]: crate::geometry::FromGeometry
-    /// [`MeshGraph`]: crate::graph::MeshGraph
-    pub fn to_mesh_by_face_with<B, F>(&self, mut f: F) -> Result<B, B::Error>
+    /// See [`is_isomorphic_to`].
+    ///
+    /// [`is_isomorphic_to`]: crate::graph::MeshGraph::is_isomorphic_to
+    pub fn structurally_eq<T>(&self, other: &Self, tolerance: T) -> bool
     where
-        B: Buildable,
-        B::Facet: FromGeometry<G::Face>,
-        F: FnMut(FaceView<&Self>, VertexView<&Self>) -> B::Vertex,
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
     {
-        let mut builder = B::builder();
-        builder.surface_with(|builder| {
-            for face in self.faces() {
-                let indices = face
+        let tolerance = tolerance.into();
+        self.is_isomorphic_with(other, |a, b| {
+            let difference = *a - *b;
+            difference.dot(difference) <= tolerance * tolerance
+        })
+    }
+
+    fn is_isomorphic_with<F>(&self, other: &Self, f: F) -> bool
+    where
+        F: Fn(&VertexPosition<G>, &VertexPosition<G>) -> bool,
+        G::Vertex: AsPosition,
+    {
+        if self.vertex_count() != other.vertex_count()
+            || self.arc_count() != other.arc_count()
+            || self.face_count() != other.face_count()
+        {
+            return false;
+        }
+        let (correspondence, _) = self.correspond_vertices_with(other, f);
+        if correspondence.len() != self.vertex_count() {
+            return false;
+        }
+        // Because the arc counts are equal, the arcs are in correspondence if
+        // every arc has a corresponding arc in the other graph.
+        let is_corresponding_arc = self.arcs().all(|arc| {
+            let (a, b) = arc.key().into();
+            other
+                .arc((correspondence[&a], correspondence[&b]).into())
+                .is_some()
+        });
+        if !is_corresponding_arc {
+            return false;
+        }
+        self.faces()
+            .all(|face| Self::corresponding_face(face, other, &correspondence).is_some())
+    }
+
+    // Puts the vertices of the graph into correspondence with the vertices of
+    // another graph by their positions. Returns the correspondence and the
+    // vertices of the other graph that have no corresponding vertex.
+    fn correspond_vertices_with<F>(
+        &self,
+        other: &Self,
+        f: F,
+    ) -> (HashMap<VertexKey, VertexKey>, Vec<VertexKey>)
+    where
+        F: Fn(&VertexPosition<G>, &VertexPosition<G>) -> bool,
+        G::Vertex: AsPosition,
+    {
+        let mut unmatched = other
+            .vertices()
+            .map(|vertex| (vertex.key(), *vertex.position()))
+            .collect::<Vec<_>>();
+        let mut correspondence = HashMap::with_capacity(unmatched.len());
+        for vertex in self.vertices() {
+            if let Some(index) = unmatched
+                .iter()
+                .position(|(_, position)| f(vertex.position(), position))
+            {
+                correspondence.insert(vertex.key(), unmatched.swap_remove(index).0);
+            }
+        }
+        (
+            correspondence,
+            unmatched.into_iter().map(|(key, _)| key).collect(),
+        )
+    }
+
+    // Gets the face of another graph that corresponds to the given face. Faces
+    // correspond if their vertices correspond in the same cyclic order.
+    fn corresponding_face(
+        face: FaceView<&Self>,
+        other: &Self,
+        correspondence: &HashMap<VertexKey, VertexKey>,
+    ) -> Option<FaceKey> {
+        let keys = face
+            .adjacent_vertices()
+            .map(|vertex| correspondence.get(&vertex.key()).cloned())
+            .collect::<Option<Vec<_>>>()?;
+        other
+            .arc((keys[0], keys[1]).into())
+            .and_then(|arc| arc.face())
+            .and_then(|face| {
+                let mut others = face
                     .adjacent_vertices()
-                    .map(|vertex| builder.insert_vertex(f(face, vertex)))
-                    .collect::<Result<SmallVec<[_; 8]>, _>>()?;
-                builder
-                    .facets_with(|builder| builder.insert_facet(indices.as_slice(), face.data))?;
+                    .map(|vertex| vertex.key())
+                    .collect::<Vec<_>>();
+                let index = others.iter().position(|key| *key == keys[0])?;
+                others.rotate_left(index);
+                if others == keys {
+                    Some(face.key())
+                }
+                else {
+                    None
+                }
+            })
+    }
+
+    /// Gets the differences between the graph and another graph.
+    ///
+    /// Vertices are put into correspondence by their positions and faces are
+    /// put into correspondence by their vertices, so like
+    /// [`is_isomorphic_to`], this is intended for graphs with distinct vertex
+    /// positions. Unlike [`is_isomorphic_to`], this reports _what_ differs,
+    /// which is useful for diagnosing regressions in the results of mesh
+    /// operations. See [`MeshDiff`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let mut other = graph.clone();
+    /// let key = other.faces().nth(0).unwrap().key();
+    /// other.face_mut(key).unwrap().poke_at_centroid();
+    ///
+    /// let diff = graph.diff(&other);
+    /// assert_eq!(1, diff.vertex_count_delta);
+    /// assert_eq!(1, diff.removed_faces.len());
+    /// assert_eq!(4, diff.inserted_faces.len());
+    /// println!("{:?}", diff);
+    /// ```
+    ///
+    /// [`MeshDiff`]: crate::graph::MeshDiff
+    /// [`is_isomorphic_to`]: crate::graph::MeshGraph::is_isomorphic_to
+    pub fn diff(&self, other: &Self) -> MeshDiff
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: PartialEq,
+    {
+        self.diff_with(other, |a, b| a == b)
+    }
+
+    /// Gets the differences between the graph and another graph with vertex
+    /// positions that are within a distance of each other.
+    ///
+    /// The tolerance should be less than half of the distance between any two
+    /// vertices in the graphs. See [`diff`] and [`structurally_eq`].
+    ///
+    /// [`diff`]: crate::graph::MeshGraph::diff
+    /// [`structurally_eq`]: crate::graph::MeshGraph::structurally_eq
+    pub fn diff_with_tolerance<T>(&self, other: &Self, tolerance: T) -> MeshDiff
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let tolerance = tolerance.into();
+        self.diff_with(other, |a, b| {
+            let difference = *a - *b;
+            difference.dot(difference) <= tolerance * tolerance
+        })
+    }
+
+    fn diff_with<F>(&self, other: &Self, f: F) -> MeshDiff
+    where
+        F: Fn(&VertexPosition<G>, &VertexPosition<G>) -> bool,
+        G::Vertex: AsPosition,
+    {
+        let delta = |a: usize, b: usize| b as isize - a as isize;
+        let (correspondence, inserted_vertices) = self.correspond_vertices_with(other, f);
+        let removed_vertices = self
+            .vertices()
+            .map(|vertex| vertex.key())
+            .filter(|key| !correspondence.contains_key(key))
+            .collect();
+        let mut matched = HashSet::with_capacity(self.face_count());
+        let mut removed_faces = Vec::new();
+        for face in self.faces() {
+            let key = face.key();
+            match Self::corresponding_face(face, other, &correspondence) {
+                Some(other) => {
+                    matched.insert(other);
+                }
+                _ => {
+                    removed_faces.push(key);
+                }
             }
-            Ok(())
-        })?;
-        builder.build()
+        }
+        let inserted_faces = other
+            .faces()
+            .map(|face| face.key())
+            .filter(|key| !matched.contains(key))
+            .collect();
+        MeshDiff {
+            vertex_count_delta: delta(self.vertex_count(), other.vertex_count()),
+            arc_count_delta: delta(self.arc_count(), other.arc_count()),
+            edge_count_delta: delta(self.edge_count(), other.edge_count()),
+            face_count_delta: delta(self.face_count(), other.face_count()),
+            removed_vertices,
+            inserted_vertices,
+            removed_faces,
+            inserted_faces,
+        }
+    }
+
+    /// Gets the vertex adjacency of the graph in compressed sparse row form.
+    ///
+    /// Rows are assigned to vertices in the order in which they are yielded by
+    /// [`vertices`], so rows are stable so long as the graph is not mutated.
+    /// This can be used to provide connectivity to linear algebra crates, such
+    /// as when computing the spectrum of a graph Laplacian.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let adjacency = graph.vertex_adjacency_csr();
+    /// for row in 0..adjacency.len() {
+    ///     assert_eq!(3, adjacency.columns(row).len());
+    /// }
+    /// ```
+    ///
+    /// [`vertices`]: crate::graph::MeshGraph::vertices
+    pub fn vertex_adjacency_csr(&self) -> VertexAdjacency {
+        sparse::vertex_adjacency(self)
+    }
+
+    /// Assembles the Laplacian matrix of the graph.
+    ///
+    /// Rows and columns are assigned to vertices in the order in which they are
+    /// yielded by [`vertices`]. See [`compact`] for a stable dense index. The
+    /// matrix is symmetric and positive semi-definite: each off-diagonal
+    /// element is the negated weight between adjacent vertices and each
+    /// diagonal element is the sum of the weights of the adjacent vertices, so
+    /// the elements of each row sum to zero. See [`LaplacianKind`] for the
+    /// weights.
+    ///
+    /// This operator is used for smoothing, parameterization, and computing
+    /// geodesics, and can be provided to external linear algebra crates, such
+    /// as eigensolvers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cotangent Laplacian is requested and the graph
+    /// is not triangular or has a degenerate face.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{LaplacianKind, MeshGraph};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(8, 8)
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .collect();
+    /// let laplacian = graph.laplacian_matrix(LaplacianKind::Cotangent).unwrap();
+    /// for (row, column, value) in laplacian.triplets() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// [`compact`]: crate::graph::MeshGraph::compact
+    /// [`vertices`]: crate::graph::MeshGraph::vertices
+    /// [`LaplacianKind`]: crate::graph::LaplacianKind
+    pub fn laplacian_matrix(
+        &self,
+        kind: LaplacianKind,
+    ) -> Result<SparseMatrix<Scalar<VertexPosition<G>>>, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        sparse::laplacian_matrix(self, kind)
+    }
+
+    /// Computes texture coordinates for each vertex via harmonic
+    /// parameterization.
+    ///
+    /// The boundary of the graph is fixed to a circle inscribed in the unit
+    /// square, spaced by the lengths of its arcs, and the texture coordinates
+    /// of interior vertices are computed by solving the cotangent Laplacian
+    /// system (see [`laplacian_matrix`]). The parameterization is wound such
+    /// that faces that are wound counter-clockwise when viewed from above are
+    /// also wound counter-clockwise in texture space. Texture coordinates are
+    /// written via [`AsUvMut`].
+    ///
+    /// Harmonic parameterization is bijective if the cotangent weights are
+    /// non-negative, which may not be the case for faces with obtuse angles.
+    ///
+    /// Returns the graph so that operations can be chained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not triangular, is not topologically
+    /// equivalent to a disk (one boundary and an Euler characteristic of one),
+    /// or has a degenerate face.
+    ///
+    /// [`laplacian_matrix`]: crate::graph::MeshGraph::laplacian_matrix
+    /// [`AsUvMut`]: crate::geometry::AsUvMut
+    pub fn parameterize_harmonic(&mut self) -> Result<&mut Self, GraphError>
+    where
+        G::Vertex: AsPosition + AsUvMut,
+        VertexPosition<G>: EuclideanSpace,
+        Scalar<VertexPosition<G>>: ToPrimitive,
+    {
+        let characteristic =
+            (self.vertex_count() + self.face_count()) as isize - self.edge_count() as isize;
+        if characteristic != 1 || self.hole_count() != 1 {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let laplacian = self.laplacian_matrix(LaplacianKind::Cotangent)?;
+        let rows = laplacian
+            .keys
+            .iter()
+            .enumerate()
+            .map(|(row, key)| (*key, row))
+            .collect::<HashMap<_, _>>();
+        // Fix the boundary to a circle. The boundary is traversed in reverse
+        // (negative angles), because boundary arcs are wound opposite to the
+        // faces of the graph.
+        let boundary = self
+            .arcs()
+            .find(|arc| arc.is_boundary_arc())
+            .expect_consistent()
+            .ring()
+            .arcs()
+            .map(|arc| {
+                let vertex = arc.source_vertex();
+                (rows[&vertex.key()], *vertex.position())
+            })
+            .collect::<Vec<_>>();
+        let n = boundary.len();
+        let mut distances = Vec::with_capacity(n);
+        let mut perimeter = Zero::zero();
+        for index in 0..n {
+            distances.push(perimeter);
+            perimeter = perimeter + (boundary[(index + 1) % n].1 - boundary[index].1).magnitude();
+        }
+        if perimeter <= Zero::zero() {
+            return Err(GraphError::Geometry);
+        }
+        let one = Scalar::<VertexPosition<G>>::one();
+        let two = one + one;
+        let half = one / two;
+        let tau = <Scalar<VertexPosition<G>> as Real>::PI * two;
+        let mut uvs = vec![None; laplacian.len()];
+        for ((row, _), distance) in boundary.into_iter().zip(distances) {
+            let angle = -(tau * distance / perimeter);
+            uvs[row] = Some((
+                half + (half * Real::cos(angle)),
+                half + (half * Real::sin(angle)),
+            ));
+        }
+        // Solve for the texture coordinates of interior vertices.
+        let interior = (0..laplacian.len())
+            .filter(|row| uvs[*row].is_none())
+            .collect::<Vec<_>>();
+        let unknowns = interior
+            .iter()
+            .enumerate()
+            .map(|(index, row)| (*row, index))
+            .collect::<HashMap<_, _>>();
+        let mut bu = Vec::with_capacity(interior.len());
+        let mut bv = Vec::with_capacity(interior.len());
+        for row in interior.iter() {
+            let (mut u, mut v) = (Zero::zero(), Zero::zero());
+            for (column, value) in laplacian.columns(*row).iter().zip(laplacian.values(*row)) {
+                if let Some((cu, cv)) = uvs[*column] {
+                    u = u - (*value * cu);
+                    v = v - (*value * cv);
+                }
+            }
+            bu.push(u);
+            bv.push(v);
+        }
+        let us = conjugate_gradient(&laplacian, &interior, &unknowns, &bu);
+        let vs = conjugate_gradient(&laplacian, &interior, &unknowns, &bv);
+        for ((row, u), v) in interior.iter().zip(us).zip(vs) {
+            uvs[*row] = Some((u, v));
+        }
+        for mut vertex in self.vertex_orphans() {
+            let (u, v) = uvs[rows[&vertex.key()]].expect_consistent();
+            let u = NumCast::from(u).ok_or_else(|| GraphError::Geometry)?;
+            let v = NumCast::from(v).ok_or_else(|| GraphError::Geometry)?;
+            *vertex.data.as_uv_mut() = Uv::<G::Vertex>::from_xy(u, v);
+        }
+        Ok(self)
+    }
+
+    /// Computes the distribution of texel densities over the faces of the
+    /// graph.
+    ///
+    /// Texel density is the ratio of the area of a face in texture space to
+    /// its area in three dimensions (see [`FaceView::texel_density`]). A
+    /// texture layout without stretch has the same texel density everywhere.
+    /// Faces with no area are reported separately.
+    ///
+    /// Returns `None` if no face has area.
+    ///
+    /// [`FaceView::texel_density`]: crate::graph::FaceView::texel_density
+    pub fn uv_stretch_report(&self) -> Option<UvStretchReport<Scalar<VertexPosition<G>>>>
+    where
+        G::Vertex: AsPosition + AsUv,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        Vector<Uv<G::Vertex>>: VectorSpace<Scalar = Scalar<VertexPosition<G>>>,
+        Scalar<VertexPosition<G>>: NumCast,
+    {
+        let mut densities = Vec::with_capacity(self.face_count());
+        let mut degenerate = vec![];
+        for face in self.faces() {
+            match face.texel_density() {
+                Some(density) => densities.push((face.key(), density)),
+                None => degenerate.push(face.key()),
+            }
+        }
+        let (_, first) = *densities.first()?;
+        let (min, max, sum) = densities.iter().skip(1).fold(
+            (first, first, first),
+            |(min, max, sum), (_, density)| {
+                (
+                    if *density < min { *density } else { min },
+                    if *density > max { *density } else { max },
+                    sum + *density,
+                )
+            },
+        );
+        let n = <Scalar<VertexPosition<G>> as NumCast>::from(densities.len())?;
+        Some(UvStretchReport {
+            min,
+            max,
+            mean: sum / n,
+            densities,
+            degenerate,
+        })
+    }
+
+    /// Partitions the faces of the graph into islands bounded by seams.
+    ///
+    /// Faces are grouped by flooding across edges that are not marked as
+    /// seams (see [`AsSeam`]). An edge is a seam if either of its arcs is
+    /// marked as a seam, and boundary arcs always bound islands. Each island
+    /// is a chart of a texture layout, so this can be used to compute the
+    /// bounds of charts in texture space and pack them.
+    ///
+    /// Returns the keys of the faces in each island.
+    ///
+    /// [`AsSeam`]: crate::geometry::AsSeam
+    pub fn uv_islands(&self) -> Vec<Vec<FaceKey>>
+    where
+        G::Arc: AsSeam,
+    {
+        let mut visited = HashSet::with_capacity(self.face_count());
+        let mut islands = vec![];
+        for face in self.faces() {
+            if !visited.insert(face.key()) {
+                continue;
+            }
+            let mut island = vec![];
+            let mut keys = vec![face.key()];
+            while let Some(key) = keys.pop() {
+                island.push(key);
+                let face = self.face(key).expect_consistent();
+                for arc in face.adjacent_arcs() {
+                    let opposite = arc.opposite_arc();
+                    if arc.data.is_seam() || opposite.data.is_seam() {
+                        continue;
+                    }
+                    if let Some(neighbor) = opposite.face() {
+                        if visited.insert(neighbor.key()) {
+                            keys.push(neighbor.key());
+                        }
+                    }
+                }
+            }
+            islands.push(island);
+        }
+        islands
+    }
+
+    /// Colors the faces of the graph by a scalar field.
+    ///
+    /// The given function computes a scalar for each face, such as its area
+    /// or arity. Scalars are normalized to the interval $[0,1]$ by the minimum
+    /// and maximum of the field and then mapped to colors by the given ramp.
+    /// If all scalars are equal, then faces are given the color at the middle
+    /// of the ramp. Faces with non-finite scalars are not colored.
+    ///
+    /// Returns the graph so that operations can be chained.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::encoding::{Color, ColorRamp};
+    /// use plexus::graph::{GraphData, MeshGraph};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// pub enum Colored {}
+    ///
+    /// impl GraphData for Colored {
+    ///     type Vertex = E3;
+    ///     type Arc = ();
+    ///     type Edge = ();
+    ///     type Face = Color;
+    /// }
+    ///
+    /// let mut graph: MeshGraph<Colored> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph.face_mut(key).unwrap().poke_at_centroid();
+    /// graph.colorize_by(|face| face.arity() as f64, &ColorRamp::heat());
+    /// ```
+    pub fn colorize_by<F>(&mut self, mut f: F, ramp: &ColorRamp) -> &mut Self
+    where
+        F: FnMut(FaceView<&Self>) -> f64,
+        G::Face: AsColorMut,
+    {
+        let scalars = self
+            .faces()
+            .map(|face| (face.key(), f(face)))
+            .filter(|(_, scalar)| scalar.is_finite())
+            .collect::<HashMap<_, _>>();
+        let (min, max) = scalars
+            .values()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), scalar| {
+                (min.min(*scalar), max.max(*scalar))
+            });
+        let range = max - min;
+        for mut face in self.face_orphans() {
+            if let Some(scalar) = scalars.get(&face.key()) {
+                let t = if range > 0.0 {
+                    (scalar - min) / range
+                }
+                else {
+                    0.5
+                };
+                *face.data.as_color_mut() = ramp.sample(t);
+            }
+        }
+        self
+    }
+
+    /// Contracts the graph towards its curve skeleton via mean curvature
+    /// flow.
+    ///
+    /// Each iteration solves the implicit mean curvature flow system
+    /// $(I+hL)x'=x$ for the positions $x'$ of vertices, where $L$ is the
+    /// cotangent Laplacian of the graph with its current positions (see
+    /// [`laplacian_matrix`]) and $h$ is the given step. Positions in the graph
+    /// are replaced by their contracted positions and the contracted positions
+    /// are returned in the order of [`vertices`].
+    ///
+    /// Contraction degenerates faces as thin regions of the graph approach the
+    /// skeleton. Iteration stops early if a face degenerates. This performs
+    /// the contraction phase of skeletonization only: edges are not collapsed,
+    /// so the graph is not reduced to a one-dimensional skeleton.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not triangular or if a face is
+    /// degenerate before contraction.
+    ///
+    /// [`laplacian_matrix`]: crate::graph::MeshGraph::laplacian_matrix
+    /// [`vertices`]: crate::graph::MeshGraph::vertices
+    pub fn mean_curvature_skeleton<T>(
+        &mut self,
+        iterations: usize,
+        step: T,
+    ) -> Result<Vec<VertexPosition<G>>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let step = step.into();
+        for iteration in 0..iterations {
+            let mut system = match self.laplacian_matrix(LaplacianKind::Cotangent) {
+                Ok(laplacian) => laplacian,
+                // Contraction degenerates faces, at which point the cotangent
+                // Laplacian can no longer be computed.
+                Err(GraphError::Geometry) if iteration > 0 => break,
+                Err(error) => return Err(error),
+            };
+            // Form $I+hL$ from the Laplacian $L$. Diagonal elements are always
+            // present in the Laplacian.
+            for (row, offsets) in system.offsets.windows(2).enumerate() {
+                let (start, end) = (offsets[0], offsets[1]);
+                for (column, value) in system.columns[start..end]
+                    .iter()
+                    .zip(system.values[start..end].iter_mut())
+                {
+                    *value = *value * step;
+                    if *column == row {
+                        *value = *value + One::one();
+                    }
+                }
+            }
+            let rows = (0..system.len()).collect::<Vec<_>>();
+            let unknowns = rows
+                .iter()
+                .map(|row| (*row, *row))
+                .collect::<HashMap<_, _>>();
+            let mut bx = Vec::with_capacity(system.len());
+            let mut by = Vec::with_capacity(system.len());
+            let mut bz = Vec::with_capacity(system.len());
+            for key in system.keys.iter() {
+                let (x, y, z) = self.vertex(*key).expect_consistent().position().into_xyz();
+                bx.push(x);
+                by.push(y);
+                bz.push(z);
+            }
+            let xs = conjugate_gradient(&system, &rows, &unknowns, &bx);
+            let ys = conjugate_gradient(&system, &rows, &unknowns, &by);
+            let zs = conjugate_gradient(&system, &rows, &unknowns, &bz);
+            let mut positions = system
+                .keys
+                .iter()
+                .zip(xs.into_iter().zip(ys).zip(zs))
+                .map(|(key, ((x, y), z))| (*key, VertexPosition::<G>::from_xyz(x, y, z)))
+                .collect::<HashMap<_, _>>();
+            for mut vertex in self.vertex_orphans() {
+                if let Some(position) = positions.remove(&vertex.key()) {
+                    *vertex.data.as_position_mut() = position;
+                }
+            }
+        }
+        Ok(self.vertices().map(|vertex| *vertex.position()).collect())
+    }
+
+    /// Gets the length and capacity of the storage of each entity in the
+    /// graph.
+    ///
+    /// Capacity in excess of length is memory that has been allocated but is
+    /// not occupied, such as after removing many entities. The slot maps that
+    /// store vertices, edges, and faces cannot release vacant slots without
+    /// invalidating keys, so storage cannot be shrunk in place. Use
+    /// [`compact`] to rebuild the storage of the graph without vacant slots.
+    ///
+    /// [`compact`]: crate::graph::MeshGraph::compact
+    pub fn capacity(&self) -> GraphCapacity {
+        fn capacity_of<E>(storage: &Storage<E>) -> StorageCapacity
+        where
+            E: Entity,
+        {
+            StorageCapacity {
+                len: storage.len(),
+                capacity: storage.capacity(),
+            }
+        }
+
+        GraphCapacity {
+            vertices: capacity_of(self.as_storage_of::<Vertex<_>>()),
+            arcs: capacity_of(self.as_storage_of::<Arc<_>>()),
+            edges: capacity_of(self.as_storage_of::<Edge<_>>()),
+            faces: capacity_of(self.as_storage_of::<Face<_>>()),
+        }
+    }
+
+    /// Rebuilds the graph such that its entities are densely keyed.
+    ///
+    /// Removing entities from a graph leaves gaps in its storage. Compaction
+    /// rebuilds the storage of each entity without gaps and in iteration
+    /// order, such that entities are in correspondence with the dense index
+    /// space `0..n`. After compaction, the $i$th vertex yielded by
+    /// [`vertices`] has the dense index $i$, and likewise for arcs, edges, and
+    /// faces. This index is stable until the graph is mutated, which allows
+    /// external arrays of attributes (such as GPU buffers) to be indexed
+    /// densely.
+    ///
+    /// Keys are not preserved by compaction. Returns the mapping from the keys
+    /// of entities before compaction to their keys after compaction. All data
+    /// is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// let vertex = graph.face_mut(key).unwrap().poke_at_centroid();
+    /// let key = vertex.key();
+    ///
+    /// let rekeying = graph.compact();
+    /// let key = rekeying.vertex(key).unwrap();
+    /// let index = graph.vertices().position(|vertex| vertex.key() == key).unwrap();
+    /// ```
+    ///
+    /// [`vertices`]: crate::graph::MeshGraph::vertices
+    pub fn compact(&mut self) -> GraphRekeying {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut rekeying = GraphRekeying::default();
+        for vertex in self.vertices() {
+            rekeying.vertices.insert(
+                vertex.key(),
+                mutation::vertex::insert(&mut mutation, vertex.data),
+            );
+        }
+        for face in self.faces() {
+            let perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| rekeying.vertices[&vertex.key()])
+                .collect::<SmallVec<[_; 8]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter).expect_consistent();
+            let key = mutation::face::insert_with(&mut mutation, cache, || {
+                (Default::default(), face.data)
+            })
+            .expect_consistent();
+            rekeying.faces.insert(face.key(), key);
+        }
+        // Edges that are not part of any face are inserted here.
+        for edge in self.edges() {
+            let (a, b) = edge.arc().key().into();
+            let (key, _) = mutation::edge::get_or_insert_with(
+                &mut mutation,
+                (rekeying.vertices[&a], rekeying.vertices[&b]),
+                || (edge.data, Default::default()),
+            )
+            .expect_consistent();
+            rekeying.edges.insert(edge.key(), key);
+        }
+        let mut graph = mutation.commit().expect_consistent();
+        for arc in self.arcs() {
+            graph
+                .arc_mut(rekeying.arc(arc.key()).expect_consistent())
+                .expect_consistent()
+                .data = arc.data;
+        }
+        for edge in self.edges() {
+            graph
+                .edge_mut(rekeying.edges[&edge.key()])
+                .expect_consistent()
+                .data = edge.data;
+        }
+        *self = graph;
+        rekeying
+    }
+
+    /// Gets an immutable path over the given sequence of vertex keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a vertex is not found or the path is malformed.
+    pub fn path<I>(&self, keys: I) -> Result<Path<&Self>, GraphError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<VertexKey>,
+    {
+        Path::bind(self, keys)
+    }
+
+    /// Gets the shortest path between the given vertices.
+    ///
+    /// The path follows the fewest arcs from the source vertex to the
+    /// destination vertex and is typically open. Use [`Path::opposite_path`]
+    /// to traverse the path in reverse.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either vertex is not found, if the vertices are the
+    /// same, or if the destination is not reachable from the source.
+    ///
+    /// [`Path::opposite_path`]: crate::graph::Path::opposite_path
+    pub fn path_between(
+        &self,
+        source: VertexKey,
+        destination: VertexKey,
+    ) -> Result<Path<&Self>, GraphError> {
+        self.vertex(source)
+            .ok_or_else(|| GraphError::TopologyNotFound)?
+            .into_shortest_path(destination)
+    }
+
+    /// Gets a mutable path over the given sequence of vertex keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a vertex is not found or the path is malformed.
+    pub fn path_mut<I>(&mut self, keys: I) -> Result<Path<&mut Self>, GraphError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<VertexKey>,
+    {
+        Path::bind(self, keys)
+    }
+
+    /// Gets an axis-aligned bounding box that encloses the graph.
+    pub fn aabb(&self) -> Aabb<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Scalar<VertexPosition<G>>: IntrinsicOrd,
+    {
+        Aabb::from_points(self.vertices().map(|vertex| *vertex.data.as_position()))
+    }
+
+    /// Gets a bounding sphere that encloses the graph.
+    ///
+    /// Returns the center and radius of the sphere. The sphere is approximate
+    /// and may be somewhat larger than the minimal enclosing sphere. Returns
+    /// `None` if the graph has no vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+    /// let (center, radius) = graph.bounding_sphere().unwrap();
+    /// ```
+    pub fn bounding_sphere(&self) -> Option<(VertexPosition<G>, Scalar<VertexPosition<G>>)>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let positions = self
+            .vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+        bound::bounding_sphere(&positions)
+    }
+
+    // TODO: Use a spatial index if one is introduced.
+    /// Gets the keys of the vertices with positions within a distance of a
+    /// point.
+    ///
+    /// This is useful for selecting vertices in an area, such as the area
+    /// affected by a brush in sculpting and painting tools. The query scans
+    /// all vertices in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let half = R64::from_inner(0.5);
+    /// let keys = graph.vertices_within(Point3::new(half, half, half), half);
+    /// assert_eq!(1, keys.len());
+    /// ```
+    pub fn vertices_within<T>(&self, center: VertexPosition<G>, radius: T) -> Vec<VertexKey>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let radius = radius.into();
+        self.vertices()
+            .filter(|vertex| {
+                let difference = *vertex.position() - center;
+                difference.dot(difference) <= radius * radius
+            })
+            .map(|vertex| vertex.key())
+            .collect()
+    }
+
+    /// Gets the keys of the faces with centroids within a distance of a point.
+    ///
+    /// See [`vertices_within`].
+    ///
+    /// [`vertices_within`]: crate::graph::MeshGraph::vertices_within
+    pub fn faces_within<T>(&self, center: VertexPosition<G>, radius: T) -> Vec<FaceKey>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceCentroid,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let radius = radius.into();
+        self.faces()
+            .filter(|face| {
+                let difference = face.centroid() - center;
+                difference.dot(difference) <= radius * radius
+            })
+            .map(|face| face.key())
+            .collect()
+    }
+
+    // TODO: This triangulation does not consider geometry and exhibits some
+    //       bad behavior in certain situations. Triangulation needs to be
+    //       reworked and may need to expose a bit more complexity. A geometric
+    //       triangulation algorithm would be a useful addition and could
+    //       detect concave faces and provide more optimal splits. See comments
+    //       on `FaceView::triangulate`.
+    /// Triangulates the graph, tessellating all faces into triangles.
+    ///
+    /// Returns the graph so that operations can be chained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face cannot be split into triangles. Faces that
+    /// have already been triangulated are retained in this case.
+    pub fn triangulate(&mut self) -> Result<&mut Self, GraphError> {
+        self.triangulate_with_progress(|_| ControlFlow::Continue(()))
+    }
+
+    /// Triangulates the graph, tessellating all faces into triangles, and
+    /// reports progress.
+    ///
+    /// The given function is called with the fraction of faces that have been
+    /// triangulated in the interval $[0,1]$. If the function returns
+    /// `ControlFlow::Break`, then triangulation stops and the remaining faces
+    /// are not triangulated. The graph is consistent either way.
+    ///
+    /// Returns the graph so that operations can be chained. See
+    /// [`triangulate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face cannot be split into triangles. Faces that
+    /// have already been triangulated are retained in this case.
+    ///
+    /// [`triangulate`]: crate::graph::MeshGraph::triangulate
+    pub fn triangulate_with_progress<F>(&mut self, mut progress: F) -> Result<&mut Self, GraphError>
+    where
+        F: FnMut(f32) -> ControlFlow<()>,
+    {
+        // TODO: This implementation is a bit fragile and depends on the
+        //       semantics of `TopologyConflict` in this context. This should
+        //       work a bit better than using `FaceView::triangulate` until
+        //       triangulation is reworked.
+        let keys = self.as_storage_of::<Face<_>>().keys().collect::<Vec<_>>();
+        let n = keys.len();
+        for (index, key) in keys.into_iter().enumerate() {
+            if let ControlFlow::Break(_) = progress(index as f32 / n as f32) {
+                return Ok(self);
+            }
+            let mut face = self.face_mut(key).expect_consistent();
+            let mut offset = 0;
+            while face.arity() > 3 {
+                match face.split(ByIndex(offset), ByIndex(offset + 2)) {
+                    Ok(next) => {
+                        face = next.into_face().expect_consistent();
+                        offset = 0;
+                    }
+                    Err(error) if matches!(error.root(), GraphError::TopologyConflict) => {
+                        // Retry if the split intersected another face. See
+                        // `FaceSplitCache::from_face`. Fail if no split is
+                        // possible given any offset.
+                        face = self.face_mut(key).expect_consistent();
+                        offset += 1;
+                        if offset >= face.arity() {
+                            return Err(error);
+                        }
+                    }
+                    Err(error) => {
+                        return Err(error);
+                    }
+                }
+            }
+        }
+        let _ = progress(1.0);
+        Ok(self)
+    }
+
+    /// Subdivides all faces in the graph about their centroids. A triangle fan
+    /// is formed from each arc in a face's perimeter and a vertex inserted at
+    /// its centroid.
+    ///
+    /// Unlike poking each face via [`FaceView::poke_at_centroid`], all faces
+    /// are poked in a single mutation. The data of inserted vertices is
+    /// blended from the data of the vertices of their faces by
+    /// [`GraphData::blend_vertices`].
+    ///
+    /// Returns the graph so that operations can be chained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the topology of a face is malformed. The topology
+    /// of every face is validated before the graph is modified, so the graph
+    /// is not modified in this case.
+    ///
+    /// # Examples
+    ///
+    /// Poking and then smoothing a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// graph.poke_all().unwrap().smooth(0.5).unwrap();
+    ///
+    /// assert_eq!(24, graph.face_count());
+    /// assert_eq!(14, graph.vertex_count());
+    /// ```
+    ///
+    /// [`FaceView::poke_at_centroid`]: crate::graph::FaceView::poke_at_centroid
+    /// [`GraphData::blend_vertices`]: crate::graph::GraphData::blend_vertices
+    pub fn poke_all(&mut self) -> Result<&mut Self, GraphError>
+    where
+        G: FaceCentroid,
+        G::Vertex: AsPositionMut,
+    {
+        // Poking a face does not affect the topology of any other face, so
+        // caches can be computed for all faces before mutating the graph.
+        let pokes = self
+            .faces()
+            .map(|face| {
+                let mut data = G::blend_vertices(
+                    &face
+                        .adjacent_vertices()
+                        .map(|vertex| vertex.data)
+                        .collect::<SmallVec<[_; 8]>>(),
+                );
+                *data.as_position_mut() = face.centroid();
+                FacePokeCache::from_face(face).map(|cache| (cache, data))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Mutation::from_graph(self)
+            .commit_with(|mutation| {
+                pokes.into_iter().try_for_each(|(cache, data)| {
+                    mutation::face::poke_with(&mut *mutation, cache, move || data).map(|_| ())
+                })
+            })
+            .map(|(graph, _)| graph)
+    }
+
+    /// Extrudes each of the given faces along its normal.
+    ///
+    /// Each face is extruded independently as if by
+    /// [`FaceView::extrude_with_offset`], so faces that share edges are not
+    /// extruded as a region. Unlike extruding faces one at a time, all faces
+    /// are extruded in a single mutation: either all faces are extruded or
+    /// none are.
+    ///
+    /// Returns the extruded faces in the same order as the given keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face cannot be found, if a face is given more
+    /// than once, or if the geometry of a face could not be computed. Every
+    /// face is validated before the graph is modified, so the graph is not
+    /// modified in these cases.
+    ///
+    /// # Examples
+    ///
+    /// Extruding every other face of a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let keys = graph.faces().step_by(2).keys().collect::<Vec<_>>();
+    /// let faces = graph.extrude_each(&keys, R64::from_inner(0.5)).unwrap();
+    ///
+    /// assert_eq!(3, faces.len());
+    /// ```
+    ///
+    /// [`FaceView::extrude_with_offset`]: crate::graph::FaceView::extrude_with_offset
+    pub fn extrude_each<T>(
+        &mut self,
+        faces: &[FaceKey],
+        offset: T,
+    ) -> Result<Vec<FaceKey>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let offset = offset.into();
+        if faces.iter().collect::<HashSet<_>>().len() != faces.len() {
+            return Err(GraphError::TopologyConflict);
+        }
+        // Extruding a face does not affect the topology of any other face, so
+        // caches can be computed for all faces before mutating the graph.
+        let extrusions = faces
+            .iter()
+            .map(|key| {
+                let face = self
+                    .face(*key)
+                    .ok_or_else(|| GraphError::TopologyNotFound)?;
+                let translation = face.normal()? * offset;
+                FaceExtrudeCache::from_face(face).map(|cache| (cache, translation))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Mutation::from_graph(self)
+            .commit_with(|mutation| {
+                extrusions
+                    .into_iter()
+                    .map(|(cache, translation)| {
+                        mutation::face::extrude_with(&mut *mutation, cache, move |data| {
+                            data.map_position(|position| *position + translation)
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map(|(_, faces)| faces)
+    }
+
+    /// Smooths the positions of vertices in the graph.
+    ///
+    /// Each position is translated by its offset from its centroid scaled by
+    /// the given factor. The centroid of a vertex position is the mean of the
+    /// positions of its adjacent vertices. That is, given a factor $k$ and a
+    /// vertex with position $P$ and centroid $Q$, its position becomes
+    /// $P+k(Q-P)$.
+    ///
+    /// This is equivalent to [`smooth_weighted`] with uniform weights and a
+    /// single iteration.
+    ///
+    /// Returns the graph so that operations can be chained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`smooth_weighted`].
+    /// Uniform weights are always valid.
+    ///
+    /// [`smooth_weighted`]: crate::graph::MeshGraph::smooth_weighted
+    pub fn smooth<T>(&mut self, factor: T) -> Result<&mut Self, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: VertexCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.smooth_weighted(|_| One::one(), factor, 1)
+    }
+
+    /// Smooths the positions of vertices in the graph using the given weights.
+    ///
+    /// The given function is called with each outgoing arc of a vertex and
+    /// returns the weight of the arc's destination vertex. Each position is
+    /// translated by its offset from the weighted centroid of its adjacent
+    /// vertices scaled by the given factor $\lambda$. Weights are normalized
+    /// per vertex. That is, given a vertex with position $P$ and adjacent
+    /// vertices with positions $Q_i$ and weights $w_i$, its position becomes
+    /// $P+\lambda\left(\frac{\sum_i w_iQ_i}{\sum_i w_i}-P\right)$. Vertices
+    /// with a total weight of zero are not translated.
+    ///
+    /// Weights are recomputed from the current positions in each iteration
+    /// and positions are computed for all vertices before any are written.
+    /// Uniform weights yield [`smooth`] and cotangent weights yield
+    /// [`smooth_cotangent`]. Other weights, such as weights that decrease with
+    /// the dihedral angle of an arc's edge, can be used for feature-preserving
+    /// smoothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a weight is negative or non-finite. Positions
+    /// written by completed iterations are retained in this case.
+    ///
+    /// # Examples
+    ///
+    /// Smoothing an open graph without pulling vertices across its boundary:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph.face_mut(key).unwrap().remove();
+    /// graph
+    ///     .smooth_weighted(
+    ///         |arc| {
+    ///             if arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc() {
+    ///                 R64::from_inner(0.0)
+    ///             }
+    ///             else {
+    ///                 R64::from_inner(1.0)
+    ///             }
+    ///         },
+    ///         0.5,
+    ///         4,
+    ///     )
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [`smooth`]: crate::graph::MeshGraph::smooth
+    /// [`smooth_cotangent`]: crate::graph::MeshGraph::smooth_cotangent
+    pub fn smooth_weighted<T, F>(
+        &mut self,
+        mut weight: F,
+        factor: T,
+        iterations: usize,
+    ) -> Result<&mut Self, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        F: FnMut(ArcView<&Self>) -> Scalar<VertexPosition<G>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let factor = factor.into();
+        for _ in 0..iterations {
+            let mut positions = HashMap::with_capacity(self.vertex_count());
+            for vertex in self.vertices() {
+                let position = *vertex.position();
+                let mut total = Scalar::<VertexPosition<G>>::zero();
+                let mut offset = Vector::<VertexPosition<G>>::zero();
+                for arc in vertex.outgoing_arcs() {
+                    let neighbor = *arc.destination_vertex().position();
+                    let w = weight(arc);
+                    // Reject negative and non-finite (`NaN` or infinite) weights.
+                    if w < Zero::zero() || w - w != Zero::zero() {
+                        return Err(GraphError::Geometry);
+                    }
+                    total = total + w;
+                    offset = offset + ((neighbor - position) * w);
+                }
+                if total > Zero::zero() {
+                    positions.insert(vertex.key(), position + (offset * (factor / total)));
+                }
+            }
+            for mut vertex in self.vertex_orphans() {
+                if let Some(position) = positions.remove(&vertex.key()) {
+                    *vertex.data.as_position_mut() = position;
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Smooths the positions of vertices in the graph using cotangent weights.
+    ///
+    /// The weight of an arc is half of the sum of the cotangents of the angles
+    /// opposite its edge (see [`LaplacianKind::Cotangent`]). Negative
+    /// cotangents, which occur opposite obtuse angles, are clamped to zero.
+    /// Unlike uniform weights, cotangent weights approximate mean curvature
+    /// flow and largely preserve the distribution of vertices over the
+    /// surface.
+    ///
+    /// See [`smooth_weighted`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not triangular.
+    ///
+    /// [`smooth_weighted`]: crate::graph::MeshGraph::smooth_weighted
+    /// [`LaplacianKind::Cotangent`]: crate::graph::LaplacianKind::Cotangent
+    pub fn smooth_cotangent<T>(
+        &mut self,
+        factor: T,
+        iterations: usize,
+    ) -> Result<&mut Self, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        if let Some(face) = self.faces().find(|face| face.arity() != 3) {
+            return Err(GraphError::ArityConflict {
+                expected: 3,
+                actual: face.arity(),
+            });
+        }
+        let two = Scalar::<VertexPosition<G>>::one() + One::one();
+        self.smooth_weighted(
+            |arc| {
+                let source = *arc.source_vertex().position();
+                let destination = *arc.destination_vertex().position();
+                let cotangent = |corner: VertexPosition<G>| {
+                    let u = source - corner;
+                    let v = destination - corner;
+                    let dot = u.dot(v);
+                    let squared = (u.dot(u) * v.dot(v)) - (dot * dot);
+                    if squared <= Zero::zero() || dot <= Zero::zero() {
+                        Zero::zero()
+                    }
+                    else {
+                        dot / Real::sqrt(squared) / two
+                    }
+                };
+                // The vertex opposite the edge in each adjacent triangle is
+                // the destination of the next arc.
+                let mut weight = Zero::zero();
+                if !arc.is_boundary_arc() {
+                    weight = weight + cotangent(*arc.next_arc().destination_vertex().position());
+                }
+                let opposite = arc.opposite_arc();
+                if !opposite.is_boundary_arc() {
+                    weight =
+                        weight + cotangent(*opposite.next_arc().destination_vertex().position());
+                }
+                weight
+            },
+            factor,
+            iterations,
+        )
+    }
+
+    /// Translates the positions of vertices along their normals.
+    ///
+    /// The given function is called with each vertex and returns the distance
+    /// that the vertex is translated along its normal. Negative distances
+    /// translate vertices against their normals. This can be used with a noise
+    /// function to add procedural detail to a surface.
+    ///
+    /// Normals and displacements are computed for all vertices before any
+    /// positions are written, so the displacement of a vertex does not affect
+    /// the normals of its neighbors.
+    ///
+    /// Returns the graph so that operations can be chained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normal of a vertex cannot be computed. No
+    /// positions are written in this case.
+    ///
+    /// # Examples
+    ///
+    /// Displacing the vertices of a sphere by a function of their positions:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::{Real, R64};
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+    /// graph
+    ///     .displace_along_normals(|vertex| {
+    ///         let position = vertex.position();
+    ///         (position.x * R64::from_inner(8.0)).sin() * R64::from_inner(0.05)
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub fn displace_along_normals<F>(
+        &mut self,
+        mut displacement: F,
+    ) -> Result<&mut Self, GraphError>
+    where
+        F: FnMut(VertexView<&Self>) -> Scalar<VertexPosition<G>>,
+        G: VertexNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let mut positions = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let translation = vertex.normal()? * displacement(vertex);
+            positions.insert(vertex.key(), *vertex.position() + translation);
+        }
+        for mut vertex in self.vertex_orphans() {
+            if let Some(position) = positions.remove(&vertex.key()) {
+                *vertex.data.as_position_mut() = position;
+            }
+        }
+        Ok(self)
+    }
+
+    /// Smooths the positions of vertices along the boundaries of the graph.
+    ///
+    /// Each iteration translates every boundary vertex to the midpoint of its
+    /// two neighbors along its boundary loop. Interior vertices are not
+    /// modified. Positions are computed from the previous iteration before any
+    /// are written, so the result does not depend on the order in which loops
+    /// or vertices are visited. A vertex shared by more than one boundary loop
+    /// is translated to the centroid of all of its boundary neighbors.
+    ///
+    /// This is the complement of [`smooth`], which is typically constrained
+    /// at boundaries, and is useful for cleaning up noisy boundaries.
+    ///
+    /// [`smooth`]: crate::graph::MeshGraph::smooth
+    pub fn smooth_boundary(&mut self, iterations: usize) -> &mut Self
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        for _ in 0..iterations {
+            let mut neighbors = HashMap::<_, Vec<_>>::new();
+            for arc in self.arcs().filter(|arc| arc.is_boundary_arc()) {
+                // Boundary loops are formed by boundary arcs, so the
+                // neighbors of the destination vertex are the source vertex
+                // and the destination of the next arc.
+                neighbors
+                    .entry(arc.destination_vertex().key())
+                    .or_default()
+                    .extend(vec![
+                        *arc.source_vertex().position(),
+                        *arc.next_arc().destination_vertex().position(),
+                    ]);
+            }
+            if neighbors.is_empty() {
+                break;
+            }
+            let mut positions: HashMap<_, _> = neighbors
+                .into_iter()
+                .flat_map(|(key, positions)| {
+                    VertexPosition::<G>::centroid(positions).map(|position| (key, position))
+                })
+                .collect();
+            for mut vertex in self.vertex_orphans() {
+                if let Some(position) = positions.remove(&vertex.key()) {
+                    *vertex.data.as_position_mut() = position;
+                }
+            }
+        }
+        self
+    }
+
+    /// Gets the keys of vertices with an angle deficit that exceeds the given
+    /// threshold.
+    ///
+    /// The angle deficit of a vertex is $2\pi$ minus the sum of the interior
+    /// angles of its adjacent faces at the vertex. For vertices on a boundary,
+    /// $\pi$ is used instead of $2\pi$. The deficit is zero for vertices on
+    /// flat regions of a surface, so vertices with a large deficit mark
+    /// features like the corners of a box or the tip of a cone. Saddles have a
+    /// negative deficit, so the magnitude of the deficit is compared with the
+    /// threshold.
+    ///
+    /// Vertices that have no adjacent faces are never included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// assert_eq!(8, graph.feature_vertices(R64::from_inner(0.1)).len());
+    /// ```
+    pub fn feature_vertices<T>(&self, deficit_threshold: T) -> Vec<VertexKey>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let threshold = deficit_threshold.into();
+        let pi = <Scalar<VertexPosition<G>> as Real>::PI;
+        self.vertices()
+            .filter_map(|vertex| {
+                let a = *vertex.position();
+                let mut sum = Scalar::<VertexPosition<G>>::zero();
+                let mut is_boundary = false;
+                let mut is_isolated = true;
+                for arc in vertex.outgoing_arcs() {
+                    if arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc() {
+                        is_boundary = true;
+                    }
+                    if arc.is_boundary_arc() {
+                        continue;
+                    }
+                    is_isolated = false;
+                    // The interior angle at `A` is formed by the destination
+                    // of the outgoing arc and the source of the previous arc
+                    // in the same face.
+                    let u = *arc.destination_vertex().position() - a;
+                    let v = *arc.previous_arc().source_vertex().position() - a;
+                    let dot = u.dot(v);
+                    let squared = (u.dot(u) * v.dot(v)) - (dot * dot);
+                    let sine = if squared > Zero::zero() {
+                        Real::sqrt(squared)
+                    }
+                    else {
+                        Zero::zero()
+                    };
+                    sum = sum + Real::atan2(sine, dot);
+                }
+                if is_isolated {
+                    return None;
+                }
+                let deficit = if is_boundary { pi } else { pi + pi } - sum;
+                if Real::abs(deficit) > threshold {
+                    Some(vertex.key())
+                }
+                else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Computes per-corner normals with hard edges at sharp angles.
+    ///
+    /// The corners of each vertex are grouped into smoothing clusters. Two
+    /// corners that share an edge belong to the same cluster if the angle
+    /// between the normals of their faces is no greater than the given
+    /// threshold in radians. Each corner is assigned the mean of the face
+    /// normals in its cluster, so faces meeting at a sharper angle form a hard
+    /// edge. Corners are represented by arcs (see [`ArcView::corner_data`])
+    /// and their normals are written into arc data.
+    ///
+    /// Boundary arcs have no corner and are not modified.
+    ///
+    /// Returns the graph so that operations can be chained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normal of a face or cluster cannot be computed.
+    ///
+    /// [`ArcView::corner_data`]: crate::graph::ArcView::corner_data
+    pub fn compute_smooth_normals<T>(&mut self, angle_threshold: T) -> Result<&mut Self, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceNormal,
+        G::Arc: FromGeometry<Vector<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.compute_smooth_normals_with(angle_threshold, |data, normal| {
+            *data = normal.into_geometry();
+        })
+    }
+
+    /// Computes per-corner normals with hard edges at sharp angles and writes
+    /// them into arc data using the given function.
+    ///
+    /// See [`compute_smooth_normals`].
+    ///
+    /// [`compute_smooth_normals`]: crate::graph::MeshGraph::compute_smooth_normals
+    pub fn compute_smooth_normals_with<T, F>(
+        &mut self,
+        angle_threshold: T,
+        mut f: F,
+    ) -> Result<&mut Self, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        F: FnMut(&mut G::Arc, Vector<VertexPosition<G>>),
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let threshold = Real::cos(angle_threshold.into());
+        let normals = self
+            .faces()
+            .map(|face| face.normal().map(|normal| (face.key(), normal)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        let mut corners = HashMap::with_capacity(self.arc_count());
+        for vertex in self.vertices() {
+            let arcs = vertex
+                .outgoing_arcs()
+                .flat_map(|arc| arc.face().map(|face| (arc.key(), normals[&face.key()])))
+                .collect::<SmallVec<[_; 8]>>();
+            // Join the clusters of corners that share an edge and meet at an
+            // angle within the threshold. Each cluster is identified by the
+            // index of its root corner.
+            let mut clusters = (0..arcs.len()).collect::<SmallVec<[_; 8]>>();
+            let root = |clusters: &[usize], mut index: usize| {
+                while clusters[index] != index {
+                    index = clusters[index];
+                }
+                index
+            };
+            for (index, (key, normal)) in arcs.iter().enumerate() {
+                let adjacent = self
+                    .arc(*key)
+                    .expect_consistent()
+                    .opposite_arc()
+                    .next_arc()
+                    .key();
+                if let Some(adjacent) = arcs.iter().position(|(key, _)| *key == adjacent) {
+                    if normal.dot(arcs[adjacent].1) >= threshold {
+                        let (a, b) = (root(&clusters, index), root(&clusters, adjacent));
+                        clusters[a] = b;
+                    }
+                }
+            }
+            for (index, (key, _)) in arcs.iter().enumerate() {
+                let cluster = root(&clusters, index);
+                let normal = Vector::<VertexPosition<G>>::mean(
+                    arcs.iter()
+                        .enumerate()
+                        .filter(|(index, _)| root(&clusters, *index) == cluster)
+                        .map(|(_, (_, normal))| *normal),
+                )
+                .expect_consistent()
+                .normalize()
+                .ok_or_else(|| GraphError::Geometry)?;
+                corners.insert(*key, normal);
+            }
+        }
+        for mut arc in self.arc_orphans() {
+            if let Some(normal) = corners.remove(&arc.key()) {
+                f(&mut arc.data, normal);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Merges adjacent faces with normals that are within an angle of each
+    /// other.
+    ///
+    /// Faces are merged over their shared edge via [`FaceView::merge`] until no
+    /// more faces can be merged. This reduces the tessellation of planar
+    /// regions, such as a finely tessellated flat wall. Faces are only merged
+    /// if they share exactly one edge, so that the merged face is a simple
+    /// polygon. The merged face retains the data of one of the merged faces.
+    ///
+    /// Normals are compared with the normal of the merged face as faces are
+    /// merged, so a sequence of merges can accumulate deviation in a region
+    /// that is curved slightly.
+    ///
+    /// Returns the number of merges.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normal of a face cannot be computed or a merge
+    /// fails.
+    ///
+    /// [`FaceView::merge`]: crate::graph::FaceView::merge
+    pub fn merge_coplanar_faces<T>(&mut self, angle_tolerance: T) -> Result<usize, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.merge_coplanar_faces_with_progress(angle_tolerance, |_| ControlFlow::Continue(()))
+    }
+
+    /// Merges adjacent faces with normals that are within an angle of each
+    /// other and reports progress.
+    ///
+    /// The given function is called with the approximate fraction of faces
+    /// that have been visited in the interval $[0,1]$. If the function returns
+    /// `ControlFlow::Break`, then merging stops and the number of merges so
+    /// far is returned. The graph is consistent either way.
+    ///
+    /// See [`merge_coplanar_faces`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normal of a face cannot be computed or a merge
+    /// fails.
+    ///
+    /// [`merge_coplanar_faces`]: crate::graph::MeshGraph::merge_coplanar_faces
+    pub fn merge_coplanar_faces_with_progress<T, F>(
+        &mut self,
+        angle_tolerance: T,
+        mut progress: F,
+    ) -> Result<usize, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        F: FnMut(f32) -> ControlFlow<()>,
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let threshold = Real::cos(angle_tolerance.into());
+        let mut count = 0;
+        let mut keys = self.faces().map(|face| face.key()).collect::<Vec<_>>();
+        let n = keys.len();
+        while let Some(key) = keys.pop() {
+            // Merged faces are visited again, so the remaining faces are an
+            // approximation that never exceeds the number of faces.
+            if let ControlFlow::Break(_) = progress(1.0 - (keys.len() + 1) as f32 / n as f32) {
+                return Ok(count);
+            }
+            let face = match self.face(key) {
+                Some(face) => face,
+                // The face has been merged into another face.
+                _ => continue,
+            };
+            let normal = face.normal()?;
+            let vertices = face
+                .adjacent_vertices()
+                .map(|vertex| vertex.key())
+                .collect::<HashSet<_>>();
+            let mut destination = None;
+            for adjacent in face.adjacent_faces() {
+                let is_simple = face
+                    .adjacent_arcs()
+                    .filter(|arc| {
+                        arc.neighbor_face().map(|face| face.key()) == Some(adjacent.key())
+                    })
+                    .count()
+                    == 1
+                    && adjacent
+                        .adjacent_vertices()
+                        .filter(|vertex| vertices.contains(&vertex.key()))
+                        .count()
+                        == 2;
+                if is_simple && normal.dot(adjacent.normal()?) >= threshold {
+                    destination = Some(adjacent.key());
+                    break;
+                }
+            }
+            if let Some(destination) = destination {
+                let face = self
+                    .face_mut(key)
+                    .expect_consistent()
+                    .merge(ByKey(destination))?;
+                // The merged face may be merged again.
+                keys.push(face.key());
+                count += 1;
+            }
+        }
+        let _ = progress(1.0);
+        Ok(count)
+    }
+
+    /// Gets an iterator of immutable views over the edges in the graph that
+    /// are shorter than the given length.
+    ///
+    /// This is useful for finding edges to collapse, such as degenerate edges
+    /// with zero length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// assert_eq!(0, graph.edges_shorter_than(R64::from_inner(1.0)).count());
+    /// assert_eq!(12, graph.edges_shorter_than(R64::from_inner(2.0)).count());
+    /// ```
+    pub fn edges_shorter_than<T>(&self, length: T) -> impl Clone + Iterator<Item = EdgeView<&Self>>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let length = length.into();
+        self.edges_by_squared_length(|squared| squared < length * length)
+    }
+
+    /// Gets an iterator of immutable views over the edges in the graph that
+    /// are longer than the given length.
+    ///
+    /// This is useful for finding edges to split, such as when remeshing to a
+    /// target edge length.
+    pub fn edges_longer_than<T>(&self, length: T) -> impl Clone + Iterator<Item = EdgeView<&Self>>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let length = length.into();
+        self.edges_by_squared_length(|squared| squared > length * length)
+    }
+
+    fn edges_by_squared_length<F>(&self, mut f: F) -> impl Clone + Iterator<Item = EdgeView<&Self>>
+    where
+        F: FnMut(Scalar<VertexPosition<G>>) -> bool,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        // Edges are filtered eagerly so that the iterator can be cloned.
+        let keys = self
+            .edges()
+            .filter(|edge| {
+                let arc = edge.arc();
+                let vector = *arc.destination_vertex().position() - *arc.source_vertex().position();
+                f(vector.dot(vector))
+            })
+            .map(|edge| edge.key())
+            .collect::<Vec<_>>();
+        keys.into_iter()
+            .map(move |key| self.edge(key).expect_consistent())
+    }
+
+    /// Gets an iterator of immutable views over the faces in the graph with an
+    /// area that is less than or equal to the given tolerance.
+    ///
+    /// The area of a face is computed from a triangle fan about its leading
+    /// vertex, so the area of non-planar faces is approximate. This is useful
+    /// for finding degenerate faces, such as slivers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let graph = MeshGraph::<Point2<R64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 1, 3)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1e-3)],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(1, graph.degenerate_faces(R64::from_inner(1e-2)).count());
+    /// ```
+    pub fn degenerate_faces<T>(
+        &self,
+        area_tolerance: T,
+    ) -> impl Clone + Iterator<Item = FaceView<&Self>>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let tolerance = area_tolerance.into();
+        let two = Scalar::<VertexPosition<G>>::one() + One::one();
+        let keys = self
+            .faces()
+            .filter(|face| {
+                let positions = face
+                    .adjacent_vertices()
+                    .map(|vertex| *vertex.position())
+                    .collect::<SmallVec<[_; 8]>>();
+                let origin = positions[0];
+                let area = positions[1..]
+                    .windows(2)
+                    .map(|triangle| {
+                        let (a, b) = (triangle[0] - origin, triangle[1] - origin);
+                        let ab = a.dot(b);
+                        let squared = (a.dot(a) * b.dot(b)) - (ab * ab);
+                        if squared > Zero::zero() {
+                            Real::sqrt(squared) / two
+                        }
+                        else {
+                            Zero::zero()
+                        }
+                    })
+                    .fold(Zero::zero(), |sum: Scalar<VertexPosition<G>>, area| {
+                        sum + area
+                    });
+                area <= tolerance
+            })
+            .map(|face| face.key())
+            .collect::<Vec<_>>();
+        keys.into_iter()
+            .map(move |key| self.face(key).expect_consistent())
+    }
+
+    /// Collapses short edges and then removes faces with small areas.
+    ///
+    /// Edges that are shorter than the given length are collapsed as if by
+    /// [`ArcView::collapse`] until no such edges can be collapsed. Edges that
+    /// cannot be collapsed, such as edges on a boundary, are skipped. Faces
+    /// with an area that is less than or equal to the given area are then
+    /// removed (see [`degenerate_faces`]). Because collapsing edges changes
+    /// the areas of faces, areas are computed after all edges are collapsed.
+    ///
+    /// This is useful for cleaning up imported meshes, because degenerate
+    /// edges and faces typically yield non-finite normals and curvatures.
+    ///
+    /// Returns the number of collapsed edges and the number of removed faces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the topology of an edge or face is malformed. Edges
+    /// and faces that have already been collapsed or removed remain so.
+    ///
+    /// [`ArcView::collapse`]: crate::graph::ArcView::collapse
+    /// [`degenerate_faces`]: crate::graph::MeshGraph::degenerate_faces
+    pub fn remove_degenerate<T, U>(
+        &mut self,
+        length_tolerance: T,
+        area_tolerance: U,
+    ) -> Result<(usize, usize), GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        U: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let length = length_tolerance.into();
+        let mut skipped = HashSet::new();
+        let mut collapsed = 0;
+        while let Some(key) = self
+            .edges_shorter_than(length)
+            .map(|edge| edge.arc().key())
+            .find(|key| !skipped.contains(key))
+        {
+            match self.arc_mut(key).expect_consistent().collapse() {
+                Ok(_) => {
+                    collapsed += 1;
+                }
+                // Collapse is rejected before the graph is mutated.
+                Err(error) if matches!(error.root(), GraphError::TopologyConflict) => {
+                    skipped.insert(key);
+                }
+                Err(error) => {
+                    return Err(error);
+                }
+            }
+        }
+        let keys = self
+            .degenerate_faces(area_tolerance)
+            .map(|face| face.key())
+            .collect::<Vec<_>>();
+        for key in keys.iter() {
+            self.face_mut(*key).expect_consistent().try_remove()?;
+        }
+        Ok((collapsed, keys.len()))
+    }
+
+    /// Computes the convex hull of the vertex positions of the graph.
+    ///
+    /// Returns a closed triangular graph that encloses the positions of the
+    /// vertices of the graph. The faces of the hull are consistently wound
+    /// such that their normals point outward. Vertex data is copied from the
+    /// vertices that form the hull.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph has fewer than four non-coplanar vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(8, 8).polygons::<Position<E3>>().collect();
+    /// let hull = graph.convex_hull().unwrap();
+    /// ```
+    pub fn convex_hull(&self) -> Result<Self, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+    {
+        let vertices = self
+            .vertices()
+            .map(|vertex| vertex.data)
+            .collect::<Vec<_>>();
+        let positions = vertices
+            .iter()
+            .map(|data| *data.as_position())
+            .collect::<Vec<_>>();
+        let triangles = hull::convex_hull(&positions).ok_or_else(|| GraphError::Geometry)?;
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut keys = HashMap::new();
+        for triangle in triangles {
+            let perimeter = triangle
+                .iter()
+                .map(|index| {
+                    *keys.entry(*index).or_insert_with(|| {
+                        mutation::vertex::insert(&mut mutation, vertices[*index])
+                    })
+                })
+                .collect::<SmallVec<[_; 3]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit()
+    }
+
+    /// Constructs a graph from the Delaunay triangulation of points projected
+    /// onto the $xy$-plane.
+    ///
+    /// Points are triangulated by their $x$ and $y$ coordinates, so this is
+    /// useful for heightfields and terrain in which $z$ is a function of $x$
+    /// and $y$. Returns an open triangular graph that covers the convex hull
+    /// of the projected points. Faces are wound counter-clockwise in the
+    /// $xy$-plane. Points that coincide in the $xy$-plane with a prior point
+    /// are discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are fewer than three non-collinear points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// // Samples of a heightfield.
+    /// let points = vec![
+    ///     (0.0, 0.0, 1.0),
+    ///     (1.0, 0.0, 0.0),
+    ///     (1.0, 1.0, 2.0),
+    ///     (0.0, 1.0, 1.0),
+    ///     (0.4, 0.6, 3.0),
+    /// ]
+    /// .into_iter()
+    /// .map(|point| point.into_geometry())
+    /// .collect::<Vec<E3>>();
+    /// let graph = MeshGraph::<E3>::from_points_delaunay_2d(&points).unwrap();
+    /// ```
+    pub fn from_points_delaunay_2d(points: &[VertexPosition<G>]) -> Result<Self, GraphError>
+    where
+        G::Vertex: AsPosition + FromGeometry<VertexPosition<G>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional,
+        <VertexPosition<G> as FiniteDimensional>::N: ProjectXy<VertexPosition<G>>,
+    {
+        let projections = points
+            .iter()
+            .map(|point| <VertexPosition<G> as FiniteDimensional>::N::project_xy(*point))
+            .collect::<Vec<_>>();
+        let triangles = delaunay::delaunay::<VertexPosition<G>>(&projections)
+            .ok_or_else(|| GraphError::Geometry)?;
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut keys = HashMap::new();
+        for triangle in triangles {
+            let perimeter = triangle
+                .iter()
+                .map(|index| {
+                    *keys.entry(*index).or_insert_with(|| {
+                        mutation::vertex::insert(&mut mutation, points[*index].into_geometry())
+                    })
+                })
+                .collect::<SmallVec<[_; 3]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit()
+    }
+
+    /// Constructs a graph from the isosurface of a signed distance function.
+    ///
+    /// The function is sampled on a cubic lattice that covers the given bounds
+    /// with `resolution` cells along the longest axis of the bounds. The
+    /// surface is extracted where the function is zero and consists of
+    /// triangles with normals that point towards positive values (out of the
+    /// volume). Vertices are shared by adjacent triangles, so the surface is
+    /// closed wherever it does not cross the bounds.
+    ///
+    /// Cells of the lattice are decomposed into tetrahedra (_marching
+    /// tetrahedra_). Unlike the lookup table of marching cubes, this
+    /// decomposition has no ambiguous cases and always yields a manifold
+    /// surface.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the extracted triangles cannot be inserted into a
+    /// graph.
+    ///
+    /// # Examples
+    ///
+    /// Constructing a sphere:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// # extern crate theon;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use theon::query::Aabb;
+    /// use theon::space::{EuclideanSpace, InnerSpace};
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let bounds = Aabb::<E3>::from_points(
+    ///     vec![(-1.5, -1.5, -1.5), (1.5, 1.5, 1.5)]
+    ///         .into_iter()
+    ///         .map(|point| point.into_geometry()),
+    /// );
+    /// let graph = MeshGraph::<E3>::from_sdf(
+    ///     |point| (point - E3::origin()).magnitude() - R64::from_inner(1.0),
+    ///     bounds,
+    ///     16,
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn from_sdf<F>(
+        f: F,
+        bounds: Aabb<VertexPosition<G>>,
+        resolution: usize,
+    ) -> Result<Self, GraphError>
+    where
+        F: Fn(VertexPosition<G>) -> Scalar<VertexPosition<G>>,
+        G::Vertex: AsPosition + FromGeometry<VertexPosition<G>>,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        Scalar<VertexPosition<G>>: IntrinsicOrd + NumCast,
+    {
+        Self::from_sdf_with(f, bounds, resolution, |position| position.into_geometry())
+    }
+
+    /// Constructs a graph from the isosurface of a signed distance function
+    /// using a function to construct vertex data.
+    ///
+    /// This is the same as [`MeshGraph::from_sdf`], but vertex data need not
+    /// be convertible from positions. Instead, the given function is called
+    /// with the position of each vertex that is inserted into the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the extracted triangles cannot be inserted into a
+    /// graph.
+    ///
+    /// [`MeshGraph::from_sdf`]: crate::graph::MeshGraph::from_sdf
+    pub fn from_sdf_with<F, H>(
+        f: F,
+        bounds: Aabb<VertexPosition<G>>,
+        resolution: usize,
+        g: H,
+    ) -> Result<Self, GraphError>
+    where
+        F: Fn(VertexPosition<G>) -> Scalar<VertexPosition<G>>,
+        H: FnMut(VertexPosition<G>) -> G::Vertex,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        Scalar<VertexPosition<G>>: IntrinsicOrd + NumCast,
+    {
+        sdf::from_sdf_with(f, bounds, resolution, g)
+    }
+
+    /// Gets the polylines formed by the intersection of the graph with a plane.
+    ///
+    /// Each face that crosses the plane contributes a segment between the
+    /// intersections of its edges with the plane. These segments are chained
+    /// into polylines by their shared edges. Closed polylines repeat their
+    /// first position as their last position. Open polylines occur where the
+    /// plane crosses a boundary of the graph.
+    ///
+    /// Vertices that lie exactly on the plane are considered to be beneath it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// # extern crate theon;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::geometry::{Plane, Unit};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    /// use theon::space::{Basis, EuclideanSpace};
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+    /// let plane = Plane::<E3> {
+    ///     origin: EuclideanSpace::origin(),
+    ///     normal: Unit::x(),
+    /// };
+    /// let polylines = graph.intersect_plane(&plane);
+    /// ```
+    pub fn intersect_plane(&self, plane: &Plane<VertexPosition<G>>) -> Vec<Vec<VertexPosition<G>>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        let distance =
+            |position: VertexPosition<G>| plane.normal.get().dot(position - plane.origin);
+        let mut positions = HashMap::new();
+        let mut segments = vec![];
+        for face in self.faces() {
+            let crossings = face
+                .adjacent_arcs()
+                .filter_map(|arc| {
+                    let a = *arc.source_vertex().position();
+                    let b = *arc.destination_vertex().position();
+                    let (da, db) = (distance(a), distance(b));
+                    if (da > Zero::zero()) != (db > Zero::zero()) {
+                        let key = arc.edge().key();
+                        positions
+                            .entry(key)
+                            .or_insert_with(|| a + ((b - a) * (da / (da - db))));
+                        Some(key)
+                    }
+                    else {
+                        None
+                    }
+                })
+                .collect::<SmallVec<[_; 4]>>();
+            // Faces that are not convex may cross the plane more than twice.
+            // Pair crossings in order about the face.
+            for pair in crossings.chunks(2) {
+                if let [ab, cd] = *pair {
+                    segments.push((ab, cd));
+                }
+            }
+        }
+        let mut incidence = HashMap::<_, SmallVec<[usize; 2]>>::new();
+        for (index, (ab, cd)) in segments.iter().enumerate() {
+            incidence.entry(*ab).or_default().push(index);
+            incidence.entry(*cd).or_default().push(index);
+        }
+        // Begin with the endpoints of open polylines so that they are not
+        // split. Any remaining segments form closed polylines.
+        let starts = incidence
+            .iter()
+            .filter(|(_, indices)| indices.len() == 1)
+            .map(|(key, _)| *key)
+            .chain(segments.iter().map(|(ab, _)| *ab))
+            .collect::<Vec<_>>();
+        let mut is_visited = vec![false; segments.len()];
+        let mut polylines = vec![];
+        for start in starts {
+            let mut key = start;
+            let mut polyline = vec![positions[&key]];
+            while let Some(index) = incidence[&key]
+                .iter()
+                .cloned()
+                .find(|index| !is_visited[*index])
+            {
+                is_visited[index] = true;
+                let (ab, cd) = segments[index];
+                key = if ab == key { cd } else { ab };
+                polyline.push(positions[&key]);
+            }
+            if polyline.len() > 1 {
+                polylines.push(polyline);
+            }
+        }
+        polylines
+    }
+
+    /// Splits the graph along a plane.
+    ///
+    /// Faces that cross the plane are clipped and vertices are inserted where
+    /// edges intersect the plane. Returns the graphs beneath and above the
+    /// plane, respectively. Faces that lie within the plane are retained in the
+    /// graph beneath the plane. The section along the plane forms a boundary in
+    /// both graphs. See [`split_by_plane_with_caps`].
+    ///
+    /// Vertex data for inserted vertices is copied from a vertex of the split
+    /// edge and face data is copied from the clipped face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clipped faces cannot be inserted into either
+    /// graph.
+    ///
+    /// [`split_by_plane_with_caps`]: crate::graph::MeshGraph::split_by_plane_with_caps
+    pub fn split_by_plane(
+        self,
+        plane: &Plane<VertexPosition<G>>,
+    ) -> Result<(Self, Self), GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        Ok((
+            self.clip_by_plane(plane, false, false)?,
+            self.clip_by_plane(plane, true, false)?,
+        ))
+    }
+
+    /// Splits the graph along a plane and closes the section with faces.
+    ///
+    /// This behaves like [`split_by_plane`], but inserts a face into each
+    /// closed boundary that is formed by the section along the plane. Only
+    /// boundaries formed by clipping faces are closed, so existing boundaries
+    /// that lie within the plane, such as holes, remain open. If the graph is
+    /// closed, then so are the resulting graphs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clipped faces cannot be inserted into either
+    /// graph. Returns an error if a boundary of the section encloses another,
+    /// such as the section of a tube, because such sections cannot be closed
+    /// with a face per boundary.
+    ///
+    /// [`split_by_plane`]: crate::graph::MeshGraph::split_by_plane
+    pub fn split_by_plane_with_caps(
+        self,
+        plane: &Plane<VertexPosition<G>>,
+    ) -> Result<(Self, Self), GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        Ok((
+            self.clip_by_plane(plane, false, true)?,
+            self.clip_by_plane(plane, true, true)?,
+        ))
+    }
+
+    /// Mirrors the graph across a plane.
+    ///
+    /// The graph is duplicated and the copy is reflected across the plane. The
+    /// winding of the faces of the copy is reversed so that their normals are
+    /// consistent with the original faces. If `weld_seam` is `true`, then
+    /// vertices that lie in the plane are shared by the original and the copy
+    /// and the two are joined along the plane. Otherwise, the copy is disjoint.
+    ///
+    /// Vertices must lie exactly in the plane to be welded. See
+    /// [`mirror_with_tolerance`].
+    ///
+    /// Returns the graph so that operations can be chained.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reflected faces cannot be inserted into the
+    /// graph, such as when a face lies in the plane and the seam is welded. If
+    /// an error occurs, then the graph is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// # extern crate theon;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    /// use theon::query::{Plane, Unit};
+    /// use theon::space::{Basis, EuclideanSpace};
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph = MeshGraph::<E3>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 2, 3)],
+    ///     vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)],
+    /// )
+    /// .unwrap();
+    /// let plane = Plane::<E3> {
+    ///     origin: EuclideanSpace::origin(),
+    ///     normal: Unit::x(),
+    /// };
+    /// graph.mirror(&plane, true).unwrap();
+    ///
+    /// assert_eq!(2, graph.face_count());
+    /// assert_eq!(6, graph.vertex_count());
+    /// ```
+    ///
+    /// [`mirror_with_tolerance`]: crate::graph::MeshGraph::mirror_with_tolerance
+    pub fn mirror(
+        &mut self,
+        plane: &Plane<VertexPosition<G>>,
+        weld_seam: bool,
+    ) -> Result<&mut Self, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        self.mirror_with_tolerance(plane, weld_seam, Scalar::<VertexPosition<G>>::zero())
+    }
+
+    /// Mirrors the graph across a plane, welding vertices within a distance of
+    /// the plane.
+    ///
+    /// This behaves like [`mirror`], but vertices within the given distance of
+    /// the plane are considered to lie in the plane and are welded if
+    /// `weld_seam` is `true`. Welded vertices are not moved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reflected faces cannot be inserted into the
+    /// graph. If an error occurs, then the graph is not modified.
+    ///
+    /// [`mirror`]: crate::graph::MeshGraph::mirror
+    pub fn mirror_with_tolerance<T>(
+        &mut self,
+        plane: &Plane<VertexPosition<G>>,
+        weld_seam: bool,
+        tolerance: T,
+    ) -> Result<&mut Self, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        let tolerance = tolerance.into();
+        let normal = *plane.normal.get();
+        let mut mutation = Mutation::from(MeshGraph::new());
+        // Map the vertices of the graph to their copies and reflected images.
+        // Welded vertices are their own image.
+        let mut copies = HashMap::with_capacity(self.vertex_count());
+        let mut images = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let copy = mutation::vertex::insert(&mut mutation, vertex.data);
+            let position = *vertex.position();
+            let distance = normal.dot(position - plane.origin);
+            let image = if weld_seam && distance <= tolerance && -distance <= tolerance {
+                copy
+            }
+            else {
+                let mut data = vertex.data;
+                *data.as_position_mut() = position - (normal * (distance + distance));
+                mutation::vertex::insert(&mut mutation, data)
+            };
+            copies.insert(vertex.key(), copy);
+            images.insert(vertex.key(), image);
+        }
+        for face in self.faces() {
+            let perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| copies[&vertex.key()])
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), face.data))?;
+        }
+        for face in self.faces() {
+            let mut perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| images[&vertex.key()])
+                .collect::<SmallVec<[_; 4]>>();
+            // Reverse the winding of the reflected face.
+            perimeter.reverse();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), face.data))?;
+        }
+        let mut graph = mutation.commit()?;
+        // Copy arc and edge data. The arcs of the reflected copy are reversed.
+        for arc in self.arcs() {
+            let (a, b): (VertexKey, VertexKey) = arc.key().into();
+            for ab in [(copies[&a], copies[&b]), (images[&b], images[&a])].iter() {
+                if let Some(mut target) = graph.arc_mut((*ab).into()) {
+                    target.data = arc.data;
+                }
+            }
+        }
+        for edge in self.edges() {
+            let (a, b): (VertexKey, VertexKey) = edge.arc().key().into();
+            for ab in [(copies[&a], copies[&b]), (images[&a], images[&b])].iter() {
+                if let Some(mut target) = graph.arc_mut((*ab).into()).map(|arc| arc.into_edge()) {
+                    target.data = edge.data;
+                }
+            }
+        }
+        *self = graph;
+        Ok(self)
+    }
+
+    // TODO: Faces that are not convex may be clipped into more than one
+    //       polygon. Such faces are clipped into a single (degenerate) face.
+    fn clip_by_plane(
+        &self,
+        plane: &Plane<VertexPosition<G>>,
+        is_above: bool,
+        is_capped: bool,
+    ) -> Result<Self, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: InnerSpace,
+    {
+        // Distances are positive on the side of the plane that is retained.
+        let distance = |position: VertexPosition<G>| {
+            let distance = plane.normal.get().dot(position - plane.origin);
+            if is_above {
+                distance
+            }
+            else {
+                -distance
+            }
+        };
+        let zero = Zero::zero();
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut vertices = HashMap::new();
+        let mut crossings = HashMap::new();
+        // Arcs that oppose arcs inserted across clipped faces. These form the
+        // boundary of the section.
+        let mut cuts = HashSet::new();
+        for face in self.faces() {
+            let distances = face
+                .adjacent_vertices()
+                .map(|vertex| distance(*vertex.position()))
+                .collect::<SmallVec<[_; 4]>>();
+            if distances.iter().all(|distance| *distance <= zero) {
+                // Faces that lie within the plane are retained beneath it.
+                if is_above || distances.iter().any(|distance| *distance < zero) {
+                    continue;
+                }
+            }
+            // Each vertex in the perimeter is paired with whether or not the
+            // arc to the next vertex in the perimeter is inserted by the cut,
+            // which is the case if it bypasses discarded vertices.
+            let mut perimeter = SmallVec::<[_; 4]>::new();
+            for arc in face.adjacent_arcs() {
+                let (a, b) = (arc.source_vertex(), arc.destination_vertex());
+                let (da, db) = (distance(*a.position()), distance(*b.position()));
+                if da >= zero {
+                    let key = *vertices
+                        .entry(a.key())
+                        .or_insert_with(|| mutation::vertex::insert(&mut mutation, a.data));
+                    perimeter.push((key, da == zero && db < zero));
+                }
+                if (da > zero && db < zero) || (da < zero && db > zero) {
+                    let edge = arc.edge();
+                    let key = *crossings.entry(edge.key()).or_insert_with(|| {
+                        // Compute the intersection from the same arc of the
+                        // edge regardless of the arc being clipped.
+                        let arc = edge.arc();
+                        let (a, b) = (arc.source_vertex(), arc.destination_vertex());
+                        let (pa, pb) = (*a.position(), *b.position());
+                        let (da, db) = (distance(pa), distance(pb));
+                        let mut data = a.data;
+                        *data.as_position_mut() = pa + ((pb - pa) * (da / (da - db)));
+                        mutation::vertex::insert(&mut mutation, data)
+                    });
+                    perimeter.push((key, db < zero));
+                }
+            }
+            for ((a, is_cut), (b, _)) in perimeter.iter().zip(perimeter.iter().cycle().skip(1)) {
+                if *is_cut {
+                    cuts.insert(ArcKey::from((*b, *a)));
+                }
+            }
+            let perimeter = perimeter
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), face.data))?;
+        }
+        let mut graph = mutation.commit()?;
+        if is_capped {
+            // Only rings of arcs along the cut are capped. Other boundaries,
+            // such as holes that lie within the plane, remain open.
+            let mut indices = HashMap::new();
+            let mut positions = vec![];
+            let mut rings = vec![];
+            let mut is_visited = HashSet::new();
+            for arc in graph.arcs().filter(|arc| cuts.contains(&arc.key())) {
+                if !arc.is_boundary_arc() || is_visited.contains(&arc.key()) {
+                    continue;
+                }
+                let ring = arc.into_ring();
+                is_visited.extend(ring.arcs().map(|arc| arc.key()));
+                if ring.arcs().all(|arc| cuts.contains(&arc.key())) {
+                    let perimeter = ring
+                        .vertices()
+                        .map(|vertex| {
+                            *indices.entry(vertex.key()).or_insert_with(|| {
+                                positions.push(*vertex.position());
+                                positions.len() - 1
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    rings.push((ring.arc().key(), perimeter));
+                }
+            }
+            // Nested rings, such as the rings of an annular section, cannot be
+            // capped independently, because their caps would overlap. Rings
+            // are projected into the plane to detect nesting.
+            if let Some(origin) = positions.first().cloned() {
+                let longest = |vectors: Vec<Vector<VertexPosition<G>>>| {
+                    vectors
+                        .into_iter()
+                        .fold(Vector::<VertexPosition<G>>::zero(), |u, w| {
+                            if w.magnitude() > u.magnitude() {
+                                w
+                            }
+                            else {
+                                u
+                            }
+                        })
+                };
+                let u = longest(
+                    positions
+                        .iter()
+                        .map(|position| *position - origin)
+                        .collect(),
+                );
+                if u.dot(u) > zero {
+                    let v = longest(
+                        positions
+                            .iter()
+                            .map(|position| {
+                                let w = *position - origin;
+                                w - (u * (w.dot(u) / u.dot(u)))
+                            })
+                            .collect(),
+                    );
+                    let points = positions
+                        .iter()
+                        .map(|position| {
+                            let w = *position - origin;
+                            (w.dot(u), w.dot(v))
+                        })
+                        .collect::<Vec<_>>();
+                    let is_enclosed = |outer: &[usize], inner: &[usize]| {
+                        inner
+                            .iter()
+                            .find(|index| !outer.contains(index))
+                            .map_or(false, |index| {
+                                arrangement::contains::<VertexPosition<G>>(
+                                    &points,
+                                    outer,
+                                    points[*index],
+                                )
+                            })
+                    };
+                    let is_nested = rings
+                        .iter()
+                        .tuple_combinations()
+                        .any(|((_, a), (_, b))| is_enclosed(a, b) || is_enclosed(b, a));
+                    if is_nested {
+                        return Err(GraphError::Geometry);
+                    }
+                }
+            }
+            for (key, _) in rings {
+                graph
+                    .arc_mut(key)
+                    .expect_consistent()
+                    .into_ring()
+                    .get_or_insert_face();
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Computes the generalized winding number of the graph about a point.
+    ///
+    /// The winding number is the sum of the signed solid angles subtended by
+    /// the faces of the graph at the point divided by $4\pi$. Faces are
+    /// triangulated as fans. For a closed and consistently wound graph, the
+    /// winding number is one for points inside of the enclosed volume (or
+    /// negative one if faces are wound inward) and zero for points outside.
+    /// For open graphs, such as closed graphs with small holes, the winding
+    /// number varies smoothly and remains close to these values away from the
+    /// boundaries.
+    ///
+    /// See [`contains_point`].
+    ///
+    /// [`contains_point`]: crate::graph::MeshGraph::contains_point
+    pub fn winding_number(&self, point: &VertexPosition<G>) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let one = Scalar::<VertexPosition<G>>::one();
+        let two = one + one;
+        let mut angle = Scalar::<VertexPosition<G>>::zero();
+        for face in self.faces() {
+            let vectors = face
+                .adjacent_vertices()
+                .map(|vertex| *vertex.position() - *point)
+                .collect::<SmallVec<[_; 4]>>();
+            for index in 1..vectors.len().saturating_sub(1) {
+                let (a, b, c) = (vectors[0], vectors[index], vectors[index + 1]);
+                let (na, nb, nc) = (a.magnitude(), b.magnitude(), c.magnitude());
+                // Compute the solid angle of the triangle. See Van Oosterom
+                // and Strackee (1983).
+                let numerator = a.dot(b.cross(c));
+                let denominator =
+                    (na * nb * nc) + (a.dot(b) * nc) + (a.dot(c) * nb) + (b.dot(c) * na);
+                angle = angle + (two * Real::atan2(numerator, denominator));
+            }
+        }
+        angle / (two * two * Real::PI)
+    }
+
+    /// Returns `true` if the point is inside of the volume enclosed by the
+    /// graph.
+    ///
+    /// A point is inside of the graph if the magnitude of its [generalized
+    /// winding number][`winding_number`] is greater than one half. This
+    /// classification is exact for closed and consistently wound graphs and is
+    /// robust for graphs with small holes or other imperfections, though it
+    /// is not well-defined for points on the surface of the graph. Points are
+    /// never inside of graphs that do not enclose a volume, such as planar
+    /// graphs.
+    ///
+    /// This is $O(n)$ in the number of faces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// assert!(graph.contains_point(&Point3::origin()));
+    /// assert!(!graph.contains_point(&Point3::new(
+    ///     R64::from_inner(2.0),
+    ///     R64::from_inner(0.0),
+    ///     R64::from_inner(0.0),
+    /// )));
+    /// ```
+    ///
+    /// [`winding_number`]: crate::graph::MeshGraph::winding_number
+    pub fn contains_point(&self, point: &VertexPosition<G>) -> bool
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let one = Scalar::<VertexPosition<G>>::one();
+        let winding = self.winding_number(point);
+        let half = one / (one + one);
+        winding > half || winding < -half
+    }
+
+    /// Voxelizes the graph into a dense occupancy grid.
+    ///
+    /// The grid covers the [axis-aligned bounding box][`aabb`] of the graph
+    /// with cubic cells. The longest axis of the bounding box is divided into
+    /// `resolution` cells and the other axes are divided into as many cells
+    /// of the same size as needed to cover them. See [`VoxelKind`] for how
+    /// the occupancy of cells is determined.
+    ///
+    /// Solid voxelization tests the center of every cell for containment and
+    /// is $O(n^3m)$ for a resolution of $n$ and $m$ faces. It is only
+    /// meaningful for graphs that enclose a volume. Surface voxelization tests
+    /// each triangle of the (fan triangulated) faces against the cells of its
+    /// bounding box.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{MeshGraph, VoxelKind};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+    /// let grid = graph.voxelize(8, VoxelKind::Solid);
+    ///
+    /// assert_eq!([8, 8, 8], grid.dimensions);
+    /// assert_eq!(Some(true), grid.get([4, 4, 4]));
+    /// assert_eq!(Some(false), grid.get([0, 0, 0]));
+    /// ```
+    ///
+    /// [`aabb`]: crate::graph::MeshGraph::aabb
+    /// [`VoxelKind`]: crate::graph::VoxelKind
+    pub fn voxelize(&self, resolution: usize, kind: VoxelKind) -> VoxelGrid<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+        Scalar<VertexPosition<G>>: IntrinsicOrd + NumCast,
+    {
+        voxel::voxelize(self, resolution, kind)
+    }
+
+    /// Computes a boolean operation on the volumes enclosed by two graphs.
+    ///
+    /// Faces that cross the surface of the other graph are split along the
+    /// curves where the surfaces intersect. Each face and fragment is then
+    /// classified as inside or outside of the volume enclosed by the other
+    /// graph by casting a ray from its interior. Faces are retained or
+    /// discarded according to the operation, and faces of the other graph are
+    /// reversed when computing a difference. Retained faces of both graphs are
+    /// stitched together along the intersection, so the result is closed if
+    /// both graphs are closed.
+    ///
+    /// Both graphs should be closed and consistently wound and their faces
+    /// should be convex. Faces that are split are triangulated and replaced by
+    /// the fragments of their triangles. Vertex data for vertices inserted
+    /// along the intersection is copied from a vertex of the split edge and
+    /// face data is copied from the split face.
+    ///
+    /// This is $O(nm)$ for graphs with $n$ and $m$ faces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the surfaces of the graphs intersect degenerately
+    /// or if the retained faces cannot be inserted into the resulting graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::{Point3, Vector3};
+    /// use plexus::graph::{BooleanOp, MeshGraph};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let a: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let b: MeshGraph<E3> = Cube::new()
+    ///     .polygons::<Position<E3>>()
+    ///     .map_vertices(|position| {
+    ///         position
+    ///             + Vector3::new(
+    ///                 R64::from_inner(0.5),
+    ///                 R64::from_inner(0.25),
+    ///                 R64::from_inner(0.375),
+    ///             )
+    ///     })
+    ///     .collect();
+    ///
+    /// let graph = a.boolean(&b, BooleanOp::Union).unwrap();
+    /// assert!(graph.is_closed());
+    /// ```
+    pub fn boolean(&self, other: &Self, op: BooleanOp) -> Result<Self, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>> + InnerSpace,
+    {
+        boolean::boolean(self, other, op)
+    }
+
+    /// Splits the graph along a path.
+    ///
+    /// Splitting a graph creates boundaries along the given path and copies any
+    /// necessary vertex, arc, and edge geometry.
+    ///
+    /// If the path bisects the graph, then splitting will result in disjointed
+    /// sub-graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// // Create a graph from two triangles.
+    /// let mut graph = MeshGraph::<E2>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 1, 3)],
+    ///     vec![
+    ///         (-1.0, 0.0),
+    ///         (0.0, -1.0),
+    ///         (0.0, 1.0),
+    ///         (1.0, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// // Find the shared edge that bisects the triangles and then construct a path
+    /// // along the edge and split the graph.
+    /// let key = graph
+    ///     .edges()
+    ///     .find(|edge| !edge.is_boundary_edge())
+    ///     .map(|edge| edge.into_arc().key())
+    ///     .unwrap();
+    /// let mut path = graph.arc_mut(key).unwrap().into_path();
+    /// MeshGraph::split_at_path(path).unwrap();
+    /// ```
+    pub fn split_at_path(path: Path<&mut Self>) -> Result<(), GraphError> {
+        let _ = path;
+        unimplemented!()
+    }
+
+    /// Gets an iterator over a vertex within each disjoint sub-graph.
+    ///
+    /// Traverses the graph and returns an arbitrary vertex within each
+    /// _disjoint sub-graph_. A sub-graph is _disjoint_ if it cannot be reached
+    /// from all other topology in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// // Create a graph from two disjoint triangles.
+    /// let graph = MeshGraph::<E2>::from_raw_buffers(
+    ///     vec![Trigon::new(0u32, 1, 2), Trigon::new(3, 4, 5)],
+    ///     vec![
+    ///         (-2.0, 0.0),
+    ///         (-1.0, 0.0),
+    ///         (-1.0, 1.0),
+    ///         (1.0, 0.0),
+    ///         (2.0, 0.0),
+    ///         (1.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// // A vertex from each disjoint triangle is returned.
+    /// for vertex in graph.disjoint_subgraph_vertices() {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn disjoint_subgraph_vertices(&self) -> impl ExactSizeIterator<Item = VertexView<&Self>> {
+        let keys = self
+            .as_storage_of::<Vertex<_>>()
+            .keys()
+            .collect::<HashSet<_>>();
+        let mut subkeys = HashSet::with_capacity(self.vertex_count());
+        let mut vertices = SmallVec::<[VertexView<_>; 4]>::new();
+        while let Some(key) = keys.difference(&subkeys).nth(0) {
+            let vertex = VertexView::from(View::bind_unchecked(self, *key));
+            vertices.push(vertex);
+            subkeys.extend(vertex.traverse_by_depth().map(|vertex| vertex.key()));
+        }
+        vertices.into_iter()
+    }
+
+    /// Gets the vertices and axis-aligned bounding box of each connected
+    /// component (disjoint sub-graph) of the graph.
+    ///
+    /// Components are in the same order as [`disjoint_subgraph_vertices`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let graph = MeshGraph::<Point2<R64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(3, 4, 5)],
+    ///     vec![
+    ///         (0.0, 0.0),
+    ///         (1.0, 0.0),
+    ///         (0.0, 1.0),
+    ///         (4.0, 0.0),
+    ///         (5.0, 0.0),
+    ///         (4.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// for (keys, aabb) in graph.component_aabbs() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// [`disjoint_subgraph_vertices`]: crate::graph::MeshGraph::disjoint_subgraph_vertices
+    pub fn component_aabbs(&self) -> Vec<(Vec<VertexKey>, Aabb<VertexPosition<G>>)>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Scalar<VertexPosition<G>>: IntrinsicOrd,
+    {
+        self.disjoint_subgraph_vertices()
+            .map(|vertex| {
+                let keys = vertex
+                    .traverse_by_depth()
+                    .map(|vertex| vertex.key())
+                    .collect::<Vec<_>>();
+                let aabb = Aabb::from_points(
+                    keys.iter()
+                        .map(|key| *self.vertex(*key).expect_consistent().position()),
+                );
+                (keys, aabb)
+            })
+            .collect()
+    }
+
+    /// Translates each connected component (disjoint sub-graph) of the graph
+    /// such that its centroid is at the origin.
+    ///
+    /// Returns the original centroid of each component in the same order as
+    /// [`disjoint_subgraph_vertices`]. The centroid can be used as the pivot
+    /// of a transform that restores the position of the component.
+    ///
+    /// [`disjoint_subgraph_vertices`]: crate::graph::MeshGraph::disjoint_subgraph_vertices
+    pub fn recenter_components(&mut self) -> Vec<VertexPosition<G>>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let mut translations = HashMap::with_capacity(self.vertex_count());
+        let centroids = self
+            .disjoint_subgraph_vertices()
+            .map(|vertex| {
+                let keys = vertex
+                    .traverse_by_depth()
+                    .map(|vertex| vertex.key())
+                    .collect::<Vec<_>>();
+                let centroid = VertexPosition::<G>::centroid(
+                    keys.iter()
+                        .map(|key| *self.vertex(*key).expect_consistent().position()),
+                )
+                .expect_consistent();
+                for key in keys {
+                    translations.insert(key, centroid);
+                }
+                centroid
+            })
+            .collect();
+        for mut vertex in self.vertex_orphans() {
+            let centroid = translations[&vertex.key()];
+            let position = vertex.data.as_position_mut();
+            *position = VertexPosition::<G>::origin() + (*position - centroid);
+        }
+        centroids
+    }
+
+    /// Moves disjoint sub-graphs into separate graphs.
+    pub fn into_disjoint_subgraphs(self) -> Vec<Self> {
+        unimplemented!()
+    }
+
+    /// Creates a [`Buildable`] mesh data structure from the graph.
+    ///
+    /// The output is created from each unique vertex in the graph. No face data
+    /// is used, and the `Facet` type is always the unit type `()`.
+    ///
+    /// # Examples
+    ///
+    /// Creating a [`MeshBuffer`] from a [`MeshGraph`] used to modify a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::N64;
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBufferN;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<N64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph
+    ///     .face_mut(key)
+    ///     .unwrap()
+    ///     .extrude_with_offset(1.0)
+    ///     .unwrap();
+    ///
+    /// let buffer: MeshBufferN<usize, E3> = graph.to_mesh_by_vertex().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph does not have constant arity that is
+    /// compatible with the index buffer. Typically, a graph is triangulated
+    /// before being converted to a buffer.
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    /// [`Buildable`]: crate::builder::Buildable
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    pub fn to_mesh_by_vertex<B>(&self) -> Result<B, B::Error>
+    where
+        B: Buildable<Facet = ()>,
+        B::Vertex: FromGeometry<G::Vertex>,
+    {
+        self.to_mesh_by_vertex_with(|vertex| vertex.data.into_geometry())
+    }
+
+    /// Creates a [`Buildable`] mesh data structure from the graph.
+    ///
+    /// The output is created from each unique vertex in the graph, which is
+    /// converted by the given function. No face data is used, and the `Facet`
+    /// type is always the unit type `()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex geometry cannot be inserted into the
+    /// output, there are arity conflicts, or the output does not support
+    /// topology found in the graph.
+    ///
+    /// [`Buildable`]: crate::builder::Buildable
+    pub fn to_mesh_by_vertex_with<B, F>(&self, mut f: F) -> Result<B, B::Error>
+    where
+        B: Buildable<Facet = ()>,
+        F: FnMut(VertexView<&Self>) -> B::Vertex,
+    {
+        let mut builder = B::builder();
+        builder.surface_with(|builder| {
+            let mut keys = HashMap::with_capacity(self.vertex_count());
+            for vertex in self.vertices() {
+                keys.insert(vertex.key(), builder.insert_vertex(f(vertex))?);
+            }
+            builder.facets_with(|builder| {
+                for face in self.faces() {
+                    let indices = face
+                        .adjacent_vertices()
+                        .map(|vertex| keys[&vertex.key()])
+                        .collect::<SmallVec<[_; 8]>>();
+                    builder.insert_facet(indices.as_slice(), ())?;
+                }
+                Ok(())
+            })
+        })?;
+        builder.build()
+    }
+
+    /// Creates a [`Buildable`] mesh data structure from the graph.
+    ///
+    /// The output is created from each face in the graph. For each face, the
+    /// face data and data for each of its vertices is inserted into the mesh
+    /// via [`FromGeometry`]. This means that a vertex is inserted for each of
+    /// its adjacent faces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex geometry cannot be inserted into the
+    /// output, there are arity conflicts, or the output does not support
+    /// topology found in the graph.
+    ///
+    /// [`Buildable`]: crate::builder::Buildable
+    /// [`FromGeometry`]: crate::geometry::FromGeometry
+    pub fn to_mesh_by_face<B>(&self) -> Result<B, B::Error>
+    where
+        B: Buildable,
+        B::Vertex: FromGeometry<G::Vertex>,
+        B::Facet: FromGeometry<G::Face>,
+    {
+        self.to_mesh_by_face_with(|_, vertex| vertex.data.into_geometry())
+    }
+
+    /// Creates a [`Buildable`] mesh data structure from the graph.
+    ///
+    /// The output is created from each face in the graph. For each face, the
+    /// face data and data for each of its vertices is converted into the output
+    /// vertex data by the given function. This means that a vertex is inserted
+    /// for each of its adjacent faces. The data of each face is is inserted
+    /// into the output via [`FromGeometry`].
+    ///
+    /// # Examples
+    ///
+    /// Creating a [`MeshBuffer`] from a [`MeshGraph`] used to compute normals:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::geometry::Vector;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::BoundedPolygon;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// pub struct Vertex {
+    ///     pub position: E3,
+    ///     pub normal: Vector<E3>,
+    /// }
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    ///
+    /// let buffer: MeshBuffer<BoundedPolygon<usize>, _> = graph
+    ///     .to_mesh_by_face_with(|face, vertex| Vertex {
+    ///         position: *vertex.position(),
+    ///         normal: face.normal().unwrap(),
+    ///     })
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex geometry cannot be inserted into the
+    /// output, there are arity conflicts, or the output does not support
+    /// topology found in the graph.
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    /// [`Buildable`]: crate::builder::Buildable
+    /// [`FromGeometry`]: crate::geometry::FromGeometry
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    pub fn to_mesh_by_face_with<B, F>(&self, mut f: F) -> Result<B, B::Error>
+    where
+        B: Buildable,
+        B::Facet: FromGeometry<G::Face>,
+        F: FnMut(FaceView<&Self>, VertexView<&Self>) -> B::Vertex,
+    {
+        let mut builder = B::builder();
+        builder.surface_with(|builder| {
+            for face in self.faces() {
+                let indices = face
+                    .adjacent_vertices()
+                    .map(|vertex| builder.insert_vertex(f(face, vertex)))
+                    .collect::<Result<SmallVec<[_; 8]>, _>>()?;
+                builder
+                    .facets_with(|builder| builder.insert_facet(indices.as_slice(), face.data))?;
+            }
+            Ok(())
+        })?;
+        builder.build()
+    }
+
+    /// Creates a line buffer over the edges of the graph.
+    ///
+    /// The output contains each vertex in the graph and a pair of indices for
+    /// each edge, so that each edge forms exactly one line segment. This is
+    /// useful for rendering wireframes, because no triangulation is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::index::Flat2;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let buffer: MeshBuffer<Flat2, E3> = graph.to_line_buffer().unwrap();
+    /// assert_eq!(24, buffer.as_index_slice().len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indices of the vertices cannot be represented
+    /// by `N`.
+    pub fn to_line_buffer<N>(&self) -> Result<MeshBuffer<Flat2<N>, G::Vertex>, BufferError>
+    where
+        N: Copy + Integer + NumCast + Unsigned,
+    {
+        let mut keys = HashMap::with_capacity(self.vertex_count());
+        let mut vertices = Vec::with_capacity(self.vertex_count());
+        for (index, vertex) in self.vertices().enumerate() {
+            keys.insert(vertex.key(), index);
+            vertices.push(vertex.data);
+        }
+        let mut indices = Vec::with_capacity(self.edge_count() * 2);
+        for edge in self.edges() {
+            let (a, b) = edge.arc().key().into();
+            indices.push(keys[&a]);
+            indices.push(keys[&b]);
+        }
+        MeshBuffer::from_raw_buffers(indices, vertices)
+    }
+}
+
+// Finds defects in the topology of the given storage. Returns an empty `Vec`
+// if the storage is consistent.
+fn validate<M, G>(storage: &M) -> Vec<GraphError>
+where
+    M: AsStorage<Arc<G>> + AsStorage<Edge<G>> + AsStorage<Face<G>> + AsStorage<Vertex<G>>,
+    G: GraphData,
+{
+    let vertices = storage.as_storage_of::<Vertex<G>>();
+    let arcs = storage.as_storage_of::<Arc<G>>();
+    let edges = storage.as_storage_of::<Edge<G>>();
+    let faces = storage.as_storage_of::<Face<G>>();
+    let mut errors = Vec::new();
+    for (a, vertex) in vertices.iter() {
+        let error = match vertex.arc {
+            Some(ab) => {
+                let (source, _): (VertexKey, VertexKey) = ab.into();
+                match arcs.get(&ab) {
+                    Some(_) if source != a => Some(GraphError::TopologyMalformed),
+                    Some(_) => None,
+                    None => Some(GraphError::TopologyNotFound),
+                }
+            }
+            None => Some(GraphError::TopologyMalformed),
+        };
+        errors.extend(error.map(|error| error.with_entity(a, "vertex validation")));
+    }
+    for (ab, arc) in arcs.iter() {
+        let (a, b) = ab.into();
+        let error = (|| -> Result<(), GraphError> {
+            if !(vertices.contains_key(&a) && vertices.contains_key(&b)) {
+                return Err(GraphError::TopologyNotFound);
+            }
+            if !arcs.contains_key(&ab.into_opposite()) {
+                return Err(GraphError::TopologyNotFound);
+            }
+            let next = arc.next.ok_or_else(|| GraphError::TopologyMalformed)?;
+            let previous = arc.previous.ok_or_else(|| GraphError::TopologyMalformed)?;
+            let edge = arc.edge.ok_or_else(|| GraphError::TopologyMalformed)?;
+            let (c, _): (VertexKey, VertexKey) = next.into();
+            let (_, d): (VertexKey, VertexKey) = previous.into();
+            if c != b
+                || arcs
+                    .get(&next)
+                    .ok_or_else(|| GraphError::TopologyNotFound)?
+                    .previous
+                    != Some(ab)
+            {
+                return Err(GraphError::TopologyMalformed);
+            }
+            if d != a
+                || arcs
+                    .get(&previous)
+                    .ok_or_else(|| GraphError::TopologyNotFound)?
+                    .next
+                    != Some(ab)
+            {
+                return Err(GraphError::TopologyMalformed);
+            }
+            let edge = edges
+                .get(&edge)
+                .ok_or_else(|| GraphError::TopologyNotFound)?;
+            if edge.arc != ab && edge.arc != ab.into_opposite() {
+                return Err(GraphError::TopologyMalformed);
+            }
+            if let Some(face) = arc.face {
+                if !faces.contains_key(&face) {
+                    return Err(GraphError::TopologyNotFound);
+                }
+            }
+            Ok(())
+        })();
+        if let Err(error) = error {
+            errors.push(error.with_entity(ab, "arc validation"));
+        }
+    }
+    for (key, edge) in edges.iter() {
+        match arcs.get(&edge.arc) {
+            Some(arc) if arc.edge == Some(key) => {}
+            Some(_) => {
+                errors.push(GraphError::TopologyMalformed.with_entity(key, "edge validation"))
+            }
+            None => errors.push(GraphError::TopologyNotFound.with_entity(key, "edge validation")),
+        }
+    }
+    for (key, face) in faces.iter() {
+        // Walk the ring of the face. The walk is bounded by the number of arcs,
+        // so malformed rings that never return to the leading arc terminate.
+        let mut ab = face.arc;
+        let mut closed = false;
+        let mut arity = 0;
+        for _ in 0..arcs.len() {
+            match arcs.get(&ab) {
+                Some(arc) if arc.face == Some(key) => match arc.next {
+                    Some(next) => {
+                        ab = next;
+                        arity += 1;
+                        if ab == face.arc {
+                            closed = true;
+                            break;
+                        }
+                    }
+                    None => break,
+                },
+                _ => break,
+            }
+        }
+        if !closed {
+            errors.push(GraphError::TopologyMalformed.with_entity(key, "face validation"));
+        }
+        // The cached arity, if any, must agree with the ring.
+        else if face.arity.map_or(false, |cached| cached != arity) {
+            errors.push(GraphError::TopologyMalformed.with_entity(key, "face arity validation"));
+        }
+    }
+    errors
+}
+
+impl<G> AsStorage<Vertex<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage(&self) -> &Storage<Vertex<G>> {
+        self.core.as_storage_of::<Vertex<_>>()
+    }
+}
+
+impl<G> AsStorage<Arc<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage(&self) -> &Storage<Arc<G>> {
+        self.core.as_storage_of::<Arc<_>>()
+    }
+}
+
+impl<G> AsStorage<Edge<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage(&self) -> &Storage<Edge<G>> {
+        self.core.as_storage_of::<Edge<_>>()
+    }
+}
+
+impl<G> AsStorage<Face<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage(&self) -> &Storage<Face<G>> {
+        self.core.as_storage_of::<Face<_>>()
+    }
+}
+
+impl<G> AsStorageMut<Vertex<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage_mut(&mut self) -> &mut Storage<Vertex<G>> {
+        self.core.as_storage_mut_of::<Vertex<_>>()
+    }
+}
+
+impl<G> AsStorageMut<Arc<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage_mut(&mut self) -> &mut Storage<Arc<G>> {
+        self.core.as_storage_mut_of::<Arc<_>>()
+    }
+}
+
+impl<G> AsStorageMut<Edge<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage_mut(&mut self) -> &mut Storage<Edge<G>> {
+        self.core.as_storage_mut_of::<Edge<_>>()
+    }
+}
+
+impl<G> AsStorageMut<Face<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage_mut(&mut self) -> &mut Storage<Face<G>> {
+        self.core.as_storage_mut_of::<Face<_>>()
+    }
+}
+
+/// Exposes a [`MeshBuilder`] that can be used to construct a [`MeshGraph`]
+/// incrementally from _surfaces_ and _facets_.
+///
+/// See the [`builder`] module documentation for more.
+///
+/// # Examples
+///
+/// Creating a [`MeshGraph`] from a triangle:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point2;
+/// use plexus::builder::Buildable;
+/// use plexus::graph::MeshGraph;
+/// use plexus::prelude::*;
+///
+/// let mut builder = MeshGraph::<Point2<f64>>::builder();
+/// let graph = builder
+///     .surface_with(|builder| {
+///         let a = builder.insert_vertex((0.0, 0.0))?;
+///         let b = builder.insert_vertex((1.0, 0.0))?;
+///         let c = builder.insert_vertex((0.0, 1.0))?;
+///         builder.facets_with(|builder| builder.insert_facet(&[a, b, c], ()))
+///     })
+///     .and_then(|_| builder.build())
+///     .unwrap();
+/// ```
+///
+/// [`MeshBuilder`]: crate::builder::MeshBuilder
+/// [`builder`]: crate::builder
+/// [`MeshGraph`]: crate::graph::MeshGraph
+impl<G> Buildable for MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Builder = GraphBuilder<G>;
+    type Error = GraphError;
+
+    type Vertex = G::Vertex;
+    type Facet = G::Face;
+
+    fn builder() -> Self::Builder {
+        Default::default()
+    }
+}
+
+impl<G> Consistent for MeshGraph<G> where G: GraphData {}
+
+impl<G> Default for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn default() -> Self {
+        MeshGraph::new()
+    }
+}
+
+impl<G> DynamicArity for MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Dynamic = MeshArity;
+
+    fn arity(&self) -> Self::Dynamic {
+        MeshArity::from_components::<FaceView<_>, _>(self.faces())
+    }
+}
+
+impl<P, G> From<P> for MeshGraph<G>
+where
+    P: Polygonal,
+    G: GraphData,
+    G::Vertex: FromGeometry<P::Vertex>,
+{
+    fn from(polygon: P) -> Self {
+        let arity = polygon.arity();
+        MeshGraph::from_raw_buffers_with_arity(0..arity, polygon, arity)
+            .expect("inconsistent polygon")
+    }
+}
+
+impl<G> From<OwnedCore<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn from(core: OwnedCore<G>) -> Self {
+        MeshGraph { core }
+    }
+}
+
+impl<E, G> FromEncoding<E> for MeshGraph<G>
+where
+    E: FaceDecoder + VertexDecoder,
+    G: GraphData,
+    G::Face: FromGeometry<E::Face>,
+    G::Vertex: FromGeometry<E::Vertex>,
+{
+    type Error = GraphError;
+
+    fn from_encoding(
+        vertices: <E as VertexDecoder>::Output,
+        faces: <E as FaceDecoder>::Output,
+    ) -> Result<Self, Self::Error> {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let keys = vertices
+            .into_iter()
+            .map(|geometry| mutation::vertex::insert(&mut mutation, geometry.into_geometry()))
+            .collect::<Vec<_>>();
+        for (perimeter, geometry) in faces {
+            let perimeter = perimeter
+                .into_iter()
+                .map(|index| keys[index])
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, perimeter.as_slice())?;
+            let geometry = geometry.into_geometry();
+            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), geometry))?;
+        }
+        mutation.commit()
+    }
+}
+
+impl<G, P> FromIndexer<P, P> for MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: FromGeometry<P::Vertex>,
+    P: Map<usize> + Polygonal,
+    P::Output: Grouping<Group = P::Output> + IntoVertices + Polygonal<Vertex = usize>,
+    Vec<P::Output>: IndexBuffer<P::Output, Index = usize>,
+{
+    type Error = GraphError;
+
+    fn from_indexer<I, N>(input: I, indexer: N) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = P>,
+        N: Indexer<P, P::Vertex>,
+    {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let (indices, vertices) = input.into_iter().index_vertices(indexer);
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
+            .collect::<Vec<_>>();
+        for face in indices {
+            let perimeter = face
+                .into_vertices()
+                .into_iter()
+                .map(|index| vertices[index])
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit()
+    }
+}
+
+impl<G, P> FromIterator<P> for MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: FromGeometry<P::Vertex>,
+    P: Polygonal,
+    P::Vertex: Clone + Eq + Hash,
+    Self: FromIndexer<P, P>,
+{
+    fn from_iter<I>(input: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+    {
+        Self::from_indexer(input, HashIndexer::default()).unwrap_or_else(|_| Self::default())
+    }
+}
+
+impl<G, P, T> FromIterator<(P, T)> for MeshGraph<G>
+where
+    G: GraphData,
+    G::Face: FromGeometry<T>,
+    G::Vertex: FromGeometry<P::Vertex>,
+    P: Map<usize> + Polygonal,
+    P::Output: Grouping<Group = P::Output> + IntoVertices + Polygonal<Vertex = usize>,
+    P::Vertex: Clone + Eq + Hash,
+    Vec<P::Output>: IndexBuffer<P::Output, Index = usize>,
+{
+    /// Collects polygons paired with face data into a [`MeshGraph`].
+    ///
+    /// Vertices are indexed by their data. See [`WithFaceData`].
+    ///
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    /// [`WithFaceData`]: crate::primitive::WithFaceData
+    fn from_iter<I>(input: I) -> Self
+    where
+        I: IntoIterator<Item = (P, T)>,
+    {
+        let (polygons, data): (Vec<_>, Vec<_>) = input.into_iter().unzip();
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let (indices, vertices) = polygons.into_iter().index_vertices(HashIndexer::default());
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
+            .collect::<Vec<_>>();
+        let result = indices.into_iter().zip(data).try_for_each(|(face, data)| {
+            let perimeter = face
+                .into_vertices()
+                .into_iter()
+                .map(|index| vertices[index])
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            let data = data.into_geometry();
+            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))
+                .map(|_| ())
+        });
+        result
+            .and_then(|_| mutation.commit())
+            .unwrap_or_else(|_| Self::default())
+    }
+}
+
+impl<P, G, H> FromRawBuffers<P, H> for MeshGraph<G>
+where
+    P: IntoVertices + Polygonal,
+    P::Vertex: Integer + ToPrimitive + Unsigned,
+    G: GraphData,
+    G::Vertex: FromGeometry<H>,
+{
+    type Error = GraphError;
+
+    fn from_raw_buffers<I, J>(indices: I, vertices: J) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = P>,
+        J: IntoIterator<Item = H>,
+    {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
+            .collect::<Vec<_>>();
+        for face in indices {
+            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(face.arity());
+            for index in face.into_vertices() {
+                let index = <usize as NumCast>::from(index).unwrap();
+                perimeter.push(
+                    *vertices
+                        .get(index)
+                        .ok_or_else(|| GraphError::TopologyNotFound)?,
+                );
+            }
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit()
+    }
+}
+
+impl<A, N, H, G> FromMeshBuffer<Flat<A, N>, H> for MeshGraph<G>
+where
+    A: NonZero + typenum::Unsigned,
+    N: Copy + Integer + NumCast + Unsigned,
+    H: Clone,
+    G: GraphData,
+    G::Vertex: FromGeometry<H>,
+{
+    type Error = GraphError;
+
+    /// Creates a [`MeshGraph`] from a flat [`MeshBuffer`].
+    ///
+    /// Indices are grouped into faces by the arity of the [`MeshBuffer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a [`MeshGraph`] cannot represent the topology in the
+    /// [`MeshBuffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::index::Flat4;
+    /// use plexus::prelude::*;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// let buffer = MeshBuffer::<Flat4, E2>::from_raw_buffers(
+    ///     vec![0u64, 1, 2, 3],
+    ///     vec![(0.0f64, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let mut graph = MeshGraph::<E2>::from_mesh_buffer(&buffer).unwrap();
+    /// ```
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    fn from_mesh_buffer(buffer: &MeshBuffer<Flat<A, N>, H>) -> Result<Self, Self::Error> {
+        let arity = buffer.arity();
+        let mut builder = MeshGraph::<G>::builder();
+        builder.surface_with(|builder| {
+            let keys = buffer
+                .as_vertex_slice()
+                .iter()
+                .cloned()
+                .map(|vertex| builder.insert_vertex(vertex))
+                .collect::<Result<Vec<_>, _>>()?;
+            builder.facets_with(|builder| {
+                for face in buffer.as_index_slice().chunks(arity) {
+                    let perimeter = face
+                        .iter()
+                        .map(|index| {
+                            <usize as NumCast>::from(*index)
+                                .and_then(|index| keys.get(index).cloned())
+                                .ok_or_else(|| GraphError::TopologyNotFound)
+                        })
+                        .collect::<Result<SmallVec<[_; 4]>, _>>()?;
+                    builder.insert_facet(perimeter.as_slice(), G::Face::default())?;
+                }
+                Ok(())
+            })
+        })?;
+        builder.build()
+    }
+}
+
+impl<P, H, G> FromMeshBuffer<P, H> for MeshGraph<G>
+where
+    P: Clone + Grouping<Group = P> + IntoVertices + Polygonal,
+    P::Vertex: Copy + Integer + NumCast + Unsigned,
+    H: Clone,
+    G: GraphData,
+    G::Vertex: FromGeometry<H>,
+{
+    type Error = GraphError;
+
+    /// Creates a [`MeshGraph`] from a structured [`MeshBuffer`].
+    ///
+    /// The arity of each face is the arity of the corresponding polygon in the
+    /// index buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a [`MeshGraph`] cannot represent the topology in the
+    /// [`MeshBuffer`].
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    fn from_mesh_buffer(buffer: &MeshBuffer<P, H>) -> Result<Self, Self::Error> {
+        let mut builder = MeshGraph::<G>::builder();
+        builder.surface_with(|builder| {
+            let keys = buffer
+                .as_vertex_slice()
+                .iter()
+                .cloned()
+                .map(|vertex| builder.insert_vertex(vertex))
+                .collect::<Result<Vec<_>, _>>()?;
+            builder.facets_with(|builder| {
+                for face in buffer.as_index_slice() {
+                    let perimeter = face
+                        .clone()
+                        .into_vertices()
+                        .into_iter()
+                        .map(|index| {
+                            <usize as NumCast>::from(index)
+                                .and_then(|index| keys.get(index).cloned())
+                                .ok_or_else(|| GraphError::TopologyNotFound)
+                        })
+                        .collect::<Result<SmallVec<[_; 4]>, _>>()?;
+                    builder.insert_facet(perimeter.as_slice(), G::Face::default())?;
+                }
+                Ok(())
+            })
+        })?;
+        builder.build()
+    }
+}
+
+impl<N, G, H> FromRawBuffersWithArity<N, H> for MeshGraph<G>
+where
+    N: Integer + ToPrimitive + Unsigned,
+    G: GraphData,
+    G::Vertex: FromGeometry<H>,
+{
+    type Error = GraphError;
+
+    /// Creates a [`MeshGraph`] from [raw buffers][`buffer`]. The arity of the
+    /// polygons in the index buffer must be given and constant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arity of the index buffer is not constant, any
+    /// index is out of bounds, or there is an error inserting topology into the
+    /// graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::index::{Flat3, LruIndexer};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let (indices, positions) = UvSphere::new(16, 16)
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .index_vertices::<Flat3, _>(LruIndexer::with_capacity(256));
+    /// let mut graph = MeshGraph::<E3>::from_raw_buffers_with_arity(indices, positions, 3).unwrap();
+    /// ```
+    ///
+    /// [`buffer`]: crate::buffer
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    fn from_raw_buffers_with_arity<I, J>(
+        indices: I,
+        vertices: J,
+        arity: usize,
+    ) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = N>,
+        J: IntoIterator<Item = H>,
+    {
+        if arity < 3 {
+            return Err(GraphError::ArityNonPolygonal);
+        }
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
+            .collect::<Vec<_>>();
+        for face in &indices
+            .into_iter()
+            .map(|index| <usize as NumCast>::from(index).unwrap())
+            .chunks(arity)
+        {
+            let face = face.collect::<Vec<_>>();
+            if face.len() != arity {
+                // Index buffer length is not a multiple of arity.
+                return Err(GraphError::ArityConflict {
+                    expected: arity,
+                    actual: face.len(),
+                });
+            }
+            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(arity);
+            for index in face {
+                perimeter.push(
+                    *vertices
+                        .get(index)
+                        .ok_or_else(|| GraphError::TopologyNotFound)?,
+                );
+            }
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit()
+    }
+}
+
+impl<G> Parametric for MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Data = G;
+}
+
+impl<G> Into<OwnedCore<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn into(self) -> OwnedCore<G> {
+        let MeshGraph { core, .. } = self;
+        core
+    }
+}
+
+/// Iterates over the faces of a [`MeshGraph`].
+///
+/// This is equivalent to [`MeshGraph::faces`].
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::graph::MeshGraph;
+/// use plexus::prelude::*;
+/// use plexus::primitive::cube::Cube;
+/// use plexus::primitive::generate::Position;
+///
+/// type E3 = Point3<R64>;
+///
+/// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+/// for face in &graph {
+///     assert_eq!(4, face.arity());
+/// }
+/// ```
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::faces`]: crate::graph::MeshGraph::faces
+impl<'a, G> IntoIterator for &'a MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Item = FaceView<&'a MeshGraph<G>>;
+    type IntoIter = Box<dyn 'a + ExactSizeIterator<Item = Self::Item>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.faces())
+    }
+}
+
+impl<G> IntoPolygons for MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Output = vec::IntoIter<Self::Polygon>;
+    type Polygon = UnboundedPolygon<G::Vertex>;
+
+    fn into_polygons(self) -> Self::Output {
+        self.faces()
+            .map(|face| {
+                // The arity of a face in a graph must be polygonal (three or
+                // higher) so this should never fail.
+                let vertices = face.adjacent_vertices().map(|vertex| vertex.data);
+                UnboundedPolygon::from_items(vertices).expect_consistent()
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<G> StaticArity for MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Static = (usize, Option<usize>);
+
+    const ARITY: Self::Static = (3, None);
+}
+
+impl<A, N, H, G> TryFrom<MeshBuffer<Flat<A, N>, H>> for MeshGraph<G>
+where
+    A: NonZero + typenum::Unsigned,
+    N: Copy + Integer + NumCast + Unsigned,
+    H: Clone,
+    G: GraphData,
+    G::Vertex: FromGeometry<H>,
+{
+    type Error = GraphError;
+
+    /// Creates a [`MeshGraph`] from a flat [`MeshBuffer`]. The arity of the
+    /// polygons in the index buffer must be known and constant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a [`MeshGraph`] cannot represent the topology in the
+    /// [`MeshBuffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::index::Flat4;
+    /// use plexus::prelude::*;
+    /// use std::convert::TryFrom;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// let buffer = MeshBuffer::<Flat4, E2>::from_raw_buffers(
+    ///     vec![0u64, 1, 2, 3],
+    ///     vec![(0.0f64, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let mut graph = MeshGraph::<E2>::try_from(buffer).unwrap();
+    /// ```
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    fn try_from(buffer: MeshBuffer<Flat<A, N>, H>) -> Result<Self, Self::Error> {
+        let arity = buffer.arity();
+        let (indices, vertices) = buffer.into_raw_buffers();
+        MeshGraph::from_raw_buffers_with_arity(indices, vertices, arity)
+    }
+}
+
+impl<P, H, G> TryFrom<MeshBuffer<P, H>> for MeshGraph<G>
+where
+    P: Grouping<Group = P> + IntoVertices + Polygonal,
+    P::Vertex: Copy + Integer + NumCast + Unsigned,
+    H: Clone,
+    G: GraphData,
+    G::Vertex: FromGeometry<H>,
+{
+    type Error = GraphError;
+
+    /// Creates a [`MeshGraph`] from a structured [`MeshBuffer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a [`MeshGraph`] cannot represent the topology in the
+    /// [`MeshBuffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    /// use std::convert::TryFrom;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// let buffer = MeshBuffer::<Tetragon<u64>, E2>::from_raw_buffers(
+    ///     vec![Tetragon::new(0u64, 1, 2, 3)],
+    ///     vec![(0.0f64, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let mut graph = MeshGraph::<E2>::try_from(buffer).unwrap();
+    /// ```
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    fn try_from(buffer: MeshBuffer<P, H>) -> Result<Self, Self::Error> {
+        let (indices, vertices) = buffer.into_raw_buffers();
+        MeshGraph::from_raw_buffers(indices, vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point2, Point3, Vector2, Vector3};
+    use num::Zero;
+    use std::collections::{HashMap, HashSet};
+    use std::f64::consts::FRAC_PI_4;
+    use std::ops::ControlFlow;
+    use theon::query::{Aabb, Plane, Unit};
+    use theon::space::{Basis, EuclideanSpace, InnerSpace};
+
+    use crate::buffer::MeshBuffer3;
+    use crate::builder::Buildable;
+    use crate::encoding::{Color, ColorRamp};
+    use crate::entity::storage::AsStorageOf;
+    use crate::geometry::{AsPosition, AsPositionMut, AsUv, AsUvMut};
+    use crate::graph::core::OwnedCore;
+    use crate::graph::edge::{Arc, Edge};
+    use crate::graph::mutation::face::FaceRemoveCache;
+    use crate::graph::mutation::{self, Mutation};
+    use crate::graph::{CoreBuilder, GraphData, GraphError, GraphKey, MeshGraph, VertexView};
+    use crate::index::HashIndexer;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::{Position, Winding};
+    use crate::primitive::platonic::Icosahedron;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::{NGon, Tetragon, Trigon, UnboundedPolygon};
+    use crate::transact::Transact;
+
+    type E2 = Point2<R64>;
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn collect() {
+        let graph: MeshGraph<Point3<f64>> = UvSphere::new(3, 2)
+            .polygons::<Position<E3>>() // 6 triangles, 18 vertices.
+            .collect();
+
+        assert_eq!(5, graph.vertex_count());
+        assert_eq!(18, graph.arc_count());
+        assert_eq!(6, graph.face_count());
+    }
+
+    #[test]
+    fn iterate() {
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(4, 2)
+            .polygons::<Position<E3>>() // 8 triangles, 24 vertices.
+            .collect();
+
+        assert_eq!(6, graph.vertices().count());
+        assert_eq!(24, graph.arcs().count());
+        assert_eq!(8, graph.faces().count());
+        for vertex in graph.vertices() {
+            // Every vertex is connected to 4 triangles with 4 (incoming) arcs.
+            // Traversal of topology should be possible.
+            assert_eq!(4, vertex.incoming_arcs().count());
+        }
+        for mut vertex in graph.vertex_orphans() {
+            // Data should be mutable.
+            vertex.data += Vector3::zero();
+        }
+    }
+
+    #[test]
+    fn capacity() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        let capacity = graph.capacity();
+        assert_eq!(8, capacity.vertices.len);
+        assert_eq!(24, capacity.arcs.len);
+        assert_eq!(12, capacity.edges.len);
+        assert_eq!(6, capacity.faces.len);
+        assert!(capacity.faces.capacity >= capacity.faces.len);
+
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+        graph.compact();
+
+        let capacity = graph.capacity();
+        assert_eq!(5, capacity.faces.len);
+        assert!(capacity.faces.capacity >= capacity.faces.len);
+        assert!(graph.faces().all(|face| face.arity() == 4));
+    }
+
+    #[test]
+    fn abort_mutation() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+
+        let cache = FaceRemoveCache::from_face(graph.face(key).unwrap()).unwrap();
+        let result = Mutation::from_graph(&mut graph).commit_with(|mutation| {
+            mutation::face::remove(mutation, cache)?;
+            Err::<(), _>(GraphError::TopologyMalformed)
+        });
+        assert_eq!(Err(GraphError::TopologyMalformed), result.map(|_| ()));
+        // The mutated graph is moved back when the mutation is aborted.
+        assert_eq!(5, graph.face_count());
+        assert!(graph.face(key).is_none());
+        assert!(!graph.is_closed());
+    }
+
+    #[test]
+    fn is_closed() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert!(graph.is_closed());
+        assert_eq!(0, graph.open_edge_count());
+
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+        assert!(!graph.is_closed());
+        assert_eq!(4, graph.open_edge_count());
+
+        assert!(MeshGraph::<E3>::new().is_closed());
+    }
+
+    #[test]
+    fn edge_counts() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(12, graph.interior_edge_count());
+        assert_eq!(2 * graph.edge_count(), graph.arc_count());
+
+        // Removing a face opens the edges of its ring. Each open edge has one
+        // boundary arc.
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+        assert_eq!(8, graph.interior_edge_count());
+        assert_eq!(4, graph.open_edge_count());
+        assert_eq!(4, graph.boundary_arc_count());
+        assert_eq!(2 * graph.edge_count(), graph.arc_count());
+    }
+
+    #[test]
+    fn diff() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut other = graph.clone();
+        assert!(graph.diff(&other).is_empty());
+
+        let key = other.faces().nth(0).unwrap().key();
+        other.face_mut(key).unwrap().remove();
+        let diff = graph.diff(&other);
+
+        assert_eq!(0, diff.vertex_count_delta);
+        assert_eq!(0, diff.edge_count_delta);
+        assert_eq!(-1, diff.face_count_delta);
+        assert_eq!(1, diff.removed_faces.len());
+        assert!(diff.inserted_faces.is_empty());
+        assert!(format!("{:?}", diff).contains("faces: -1 (0 inserted, 1 removed)"));
+
+        let diff = other.diff(&graph);
+        assert_eq!(vec![key], diff.inserted_faces);
+    }
+
+    #[test]
+    fn builder_close() {
+        let mut builder = MeshGraph::<Point2<f64>>::builder();
+        let graph = builder
+            .surface_with(|builder| {
+                let keys = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]
+                    .iter()
+                    .map(|position| builder.insert_vertex(*position))
+                    .collect::<Result<Vec<_>, _>>()?;
+                builder.facets_with(|builder| builder.insert_facet(&keys, ()))?;
+                builder.close()
+            })
+            .and_then(|count| {
+                assert_eq!(1, count);
+                builder.commit()
+            })
+            .unwrap();
+
+        assert_eq!(2, graph.face_count());
+        assert!(graph.is_closed());
+    }
+
+    #[test]
+    fn builder_assemble_facet() {
+        struct Weight;
+
+        impl GraphData for Weight {
+            type Vertex = Point2<f64>;
+            type Arc = f64;
+            type Edge = ();
+            type Face = ();
+        }
+
+        let mut builder = MeshGraph::<Weight>::builder();
+        let graph = builder
+            .surface_with(|builder| {
+                let keys = [
+                    Point2::new(0.0, 0.0),
+                    Point2::new(1.0, 0.0),
+                    Point2::new(1.0, 1.0),
+                    Point2::new(0.0, 1.0),
+                ]
+                .iter()
+                .map(|position| builder.insert_vertex(*position))
+                .collect::<Result<Vec<_>, _>>()?;
+                let arcs = (0..keys.len())
+                    .map(|index| {
+                        builder.connect_arc(keys[index], keys[(index + 1) % keys.len()], 1.0)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                // Connecting an existing arc writes its data.
+                builder.connect_arc(keys[0], keys[1], 2.0)?;
+                builder.facets_with(|builder| builder.assemble_facet(&arcs, ()))
+            })
+            .and_then(|_| builder.commit())
+            .unwrap();
+
+        assert_eq!(1, graph.face_count());
+        assert_eq!(8, graph.arc_count());
+        assert_eq!(3, graph.arcs().filter(|arc| arc.data == 1.0).count());
+        assert_eq!(1, graph.arcs().filter(|arc| arc.data == 2.0).count());
+        assert_eq!(4, graph.boundary_arc_count());
+
+        // Arcs that do not form a ring cannot be assembled and arcs that are
+        // not part of a facet cannot be committed.
+        let mut builder = MeshGraph::<Weight>::builder();
+        let result = builder
+            .surface_with(|builder| {
+                let a = builder.insert_vertex(Point2::new(0.0, 0.0))?;
+                let b = builder.insert_vertex(Point2::new(1.0, 0.0))?;
+                let c = builder.insert_vertex(Point2::new(1.0, 1.0))?;
+                let ab = builder.connect_arc(a, b, 1.0)?;
+                let bc = builder.connect_arc(b, c, 1.0)?;
+                let ca = builder.connect_arc(c, a, 1.0)?;
+                assert_eq!(
+                    Err(GraphError::TopologyMalformed),
+                    builder.assemble_facet(&[ab, ca, bc], ())
+                );
+                assert_eq!(
+                    Err(GraphError::TopologyMalformed),
+                    builder.connect_arc(a, a, 1.0)
+                );
+                Ok::<_, GraphError>(())
+            })
+            .and_then(|_| builder.commit());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn insert_face_with_arc_data() {
+        struct Length;
+
+        impl GraphData for Length {
+            type Vertex = Point2<f64>;
+            type Arc = f64;
+            type Edge = ();
+            type Face = ();
+        }
+
+        let positions = [
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ];
+        let mut builder = MeshGraph::<Length>::builder();
+        let mut graph = builder
+            .surface_with(|builder| {
+                let keys = positions
+                    .iter()
+                    .map(|position| builder.insert_vertex(*position))
+                    .collect::<Result<Vec<_>, _>>()?;
+                builder.facets_with(|builder| {
+                    builder.insert_facet_with_arc_data(&keys, (), |a, b| {
+                        let index = |key| keys.iter().position(|k| *k == key).unwrap();
+                        nalgebra::distance(&positions[index(a)], &positions[index(b)])
+                    })
+                })
+            })
+            .and_then(|_| builder.commit())
+            .unwrap();
+
+        assert_eq!(8, graph.arc_count());
+        for arc in graph.arcs() {
+            let (a, b) = arc.key().into();
+            let (a, b) = (graph.vertex(a).unwrap(), graph.vertex(b).unwrap());
+            assert_eq!(nalgebra::distance(&a.data, &b.data), arc.data);
+        }
+
+        // Replace the face with a pair of triangles. Only the arcs of the new
+        // diagonal edge are inserted.
+        let key = graph.faces().nth(0).unwrap().key();
+        let keys = graph
+            .face(key)
+            .unwrap()
+            .adjacent_vertices()
+            .map(|vertex| vertex.key())
+            .collect::<Vec<_>>();
+        graph.face_mut(key).unwrap().remove();
+        let mut count = 0;
+        for triangle in &[[keys[0], keys[1], keys[2]], [keys[2], keys[3], keys[0]]] {
+            graph
+                .insert_face_with_arc_data(triangle, (), |_, _| {
+                    count += 1;
+                    -1.0
+                })
+                .unwrap();
+        }
+
+        assert_eq!(2, count);
+        assert_eq!(2, graph.face_count());
+        assert_eq!(2, graph.arcs().filter(|arc| arc.data == -1.0).count());
+    }
+
+    #[test]
+    fn feature_vertices() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(8, graph.feature_vertices(R64::from_inner(0.1)).len());
+
+        // The vertices of a subdivided plane are flat, including the boundary
+        // vertices that are not at corners.
+        let graph = MeshGraph::<Point2<R64>>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 4, 3, 1, 2, 5, 4],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 1.0),
+                (2.0, 1.0),
+            ],
+            4,
+        )
+        .unwrap();
+        let keys = graph.feature_vertices(R64::from_inner(0.1));
+        assert_eq!(4, keys.len());
+        for key in keys {
+            assert_eq!(1, graph.vertex(key).unwrap().adjacent_faces().count());
+        }
+    }
+
+    #[test]
+    fn remove_face_reporting() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        let expected = graph.face(key).unwrap().vertex_keys().collect::<Vec<_>>();
+
+        let (ring, keys) = graph.face_mut(key).unwrap().remove_reporting();
+        assert_eq!(4, ring.unwrap().arity());
+        assert_eq!(expected, keys);
+        for key in keys {
+            assert_eq!(2, graph.vertex(key).unwrap().adjacent_faces().count());
+        }
+    }
+
+    #[test]
+    fn boundary_arc_count() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(0, graph.boundary_arc_count());
+
+        let key = graph.faces().nth(0).unwrap().key();
+        let arc = graph.face_mut(key).unwrap().remove().unwrap().arc().key();
+        assert_eq!(4, graph.boundary_arc_count());
+        assert_eq!(
+            graph.arcs().filter(|arc| arc.is_boundary_arc()).count(),
+            graph.boundary_arc_count()
+        );
+
+        // Filling the hole closes the graph again.
+        graph.arc_mut(arc).unwrap().into_ring().get_or_insert_face();
+        assert_eq!(0, graph.boundary_arc_count());
+        assert!(graph.is_closed());
+
+        // The count is maintained through local mutations.
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().poke_at_centroid();
+        let arc = graph.arcs().nth(0).unwrap().key();
+        graph.arc_mut(arc).unwrap().split_at_midpoint();
+        assert_eq!(Ok(()), graph.validate());
+
+        // An incorrect count is detected by validation.
+        let core: OwnedCore<_> = graph.into();
+        let graph = MeshGraph::from(core.with_boundary_arc_count(1));
+        assert_eq!(Err(vec![GraphError::TopologyMalformed]), graph.validate());
+    }
+
+    #[test]
+    fn hole_count() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(0, graph.hole_count());
+
+        // Removing opposing faces of a cube forms a tube with two holes.
+        let key = graph.faces().nth(0).unwrap().key();
+        let opposite = graph
+            .faces()
+            .find(|face| {
+                face.key() != key
+                    && face
+                        .adjacent_vertices()
+                        .all(|vertex| vertex.adjacent_faces().all(|face| face.key() != key))
+            })
+            .unwrap()
+            .key();
+        graph.face_mut(key).unwrap().remove();
+        assert_eq!(1, graph.hole_count());
+        graph.face_mut(opposite).unwrap().remove();
+        assert_eq!(2, graph.hole_count());
+    }
+
+    #[test]
+    fn merge_coplanar_faces() {
+        // A planar grid of four quadrilaterals.
+        let mut graph = MeshGraph::<E3>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 4, 3, 1, 2, 5, 4, 3, 4, 7, 6, 4, 5, 8, 7],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (2.0, 1.0, 0.0),
+                (0.0, 2.0, 0.0),
+                (1.0, 2.0, 0.0),
+                (2.0, 2.0, 0.0),
+            ],
+            4,
+        )
+        .unwrap();
+        let count = graph.merge_coplanar_faces(R64::from_inner(0.01)).unwrap();
+
+        // After two merges, the remaining faces share two edges about the
+        // center vertex. Merging them would not form a simple polygon.
+        assert_eq!(2, count);
+        assert_eq!(2, graph.face_count());
+
+        // Faces of a cube meet at right angles and are never merged.
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(
+            0,
+            graph.merge_coplanar_faces(R64::from_inner(0.01)).unwrap()
+        );
+        assert_eq!(6, graph.face_count());
+    }
+
+    #[test]
+    fn select_edges_by_length_and_degenerate_faces() {
+        // A triangle with a sliver adjacent to its hypotenuse.
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([2, 1, 3])],
+            vec![(0.0, 0.0), (2.0, 0.0), (0.0, 2.0), (2.0, 0.1)],
+        )
+        .unwrap();
+
+        let edges = graph.edges_shorter_than(R64::from_inner(1.0));
+        assert_eq!(1, edges.clone().count());
+        assert_eq!(1, edges.count());
+        assert_eq!(2, graph.edges_longer_than(R64::from_inner(2.5)).count());
+        assert_eq!(4, graph.edges_longer_than(R64::from_inner(1.0)).count());
+
+        assert_eq!(0, graph.degenerate_faces(R64::from_inner(0.0)).count());
+        let faces = graph
+            .degenerate_faces(R64::from_inner(0.5))
+            .map(|face| face.arity())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![3], faces);
+    }
+
+    #[test]
+    fn isomorphic_and_structurally_eq() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        // Construct the same cube with different keys and a perturbed vertex.
+        let mut other: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+        assert!(graph.is_isomorphic_to(&other));
+
+        let key = other.vertices().nth(0).unwrap().key();
+        other.vertex_mut(key).unwrap().data +=
+            Vector3::new(R64::from_inner(1e-6), Zero::zero(), Zero::zero());
+        assert!(!graph.is_isomorphic_to(&other));
+        assert!(graph.structurally_eq(&other, R64::from_inner(1e-3)));
+        assert!(!graph.structurally_eq(&other, R64::from_inner(1e-9)));
+
+        let key = other.faces().nth(0).unwrap().key();
+        other.face_mut(key).unwrap().triangulate();
+        assert!(!graph.structurally_eq(&other, R64::from_inner(1e-3)));
+    }
+
+    #[test]
+    fn triangulate_with_progress() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        let mut reports = vec![];
+        graph
+            .triangulate_with_progress(|progress| {
+                reports.push(progress);
+                if progress < 0.5 {
+                    ControlFlow::Continue(())
+                }
+                else {
+                    ControlFlow::Break(())
+                }
+            })
+            .unwrap();
+        // Three of the six faces are triangulated before cancellation.
+        assert_eq!(9, graph.face_count());
+        assert_eq!(vec![0.0, 1.0 / 6.0, 2.0 / 6.0, 0.5], reports);
+
+        let mut last = 0.0;
+        graph
+            .triangulate_with_progress(|progress| {
+                assert!(progress >= last);
+                last = progress;
+                ControlFlow::Continue(())
+            })
+            .unwrap();
+        assert_eq!(1.0, last);
+        assert_eq!(12, graph.face_count());
     }
-}
 
-impl<G> AsStorage<Vertex<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage(&self) -> &Storage<Vertex<G>> {
-        self.core.as_storage_of::<Vertex<_>>()
+    #[test]
+    fn displace_along_normals() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(8, 8).polygons::<Position<E3>>().collect();
+        let radii = graph
+            .vertices()
+            .map(|vertex| (vertex.key(), vertex.position().coords.magnitude()))
+            .collect::<HashMap<_, _>>();
+
+        graph
+            .displace_along_normals(|_| R64::from_inner(0.25))
+            .unwrap();
+        // Vertex normals of a sphere point away from its center, so displacing
+        // vertices along their normals increases their distance from the
+        // center.
+        for vertex in graph.vertices() {
+            let radius = vertex.position().coords.magnitude();
+            let expected = radii[&vertex.key()] + R64::from_inner(0.25);
+            assert!((radius - expected).abs() < R64::from_inner(0.05));
+            assert!(radius > radii[&vertex.key()]);
+        }
+        assert!(graph.is_closed());
     }
-}
 
-impl<G> AsStorage<Arc<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage(&self) -> &Storage<Arc<G>> {
-        self.core.as_storage_of::<Arc<_>>()
+    #[test]
+    fn remove_degenerate() {
+        let mut graph: MeshGraph<E3> = Icosahedron::new().polygons::<Position<E3>>().collect();
+        let (a, b) = {
+            let arc = graph.arcs().nth(0).unwrap();
+            (
+                arc.source_vertex().key(),
+                *arc.destination_vertex().position(),
+            )
+        };
+        // Move a vertex onto its neighbor, which degenerates their edge and
+        // the triangles connected to it.
+        *graph.vertex_mut(a).unwrap().data.as_position_mut() = b;
+        assert_eq!(2, graph.degenerate_faces(R64::from_inner(1e-9)).count());
+
+        assert_eq!(
+            (1, 0),
+            graph
+                .remove_degenerate(R64::from_inner(1e-9), R64::from_inner(1e-9))
+                .unwrap()
+        );
+        assert_eq!(11, graph.vertex_count());
+        assert_eq!(18, graph.face_count());
+        assert!(graph.is_closed());
+
+        let mut graph = MeshGraph::<Point2<R64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 1, 3)],
+            vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1e-3)],
+        )
+        .unwrap();
+        assert_eq!(
+            (0, 1),
+            graph
+                .remove_degenerate(R64::from_inner(1e-3), R64::from_inner(1e-2))
+                .unwrap()
+        );
+        assert_eq!(1, graph.face_count());
     }
-}
 
-impl<G> AsStorage<Edge<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage(&self) -> &Storage<Edge<G>> {
-        self.core.as_storage_of::<Edge<_>>()
+    #[test]
+    fn extrude_each() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let keys = graph
+            .faces()
+            .take(2)
+            .map(|face| face.key())
+            .collect::<Vec<_>>();
+        let centroids = keys
+            .iter()
+            .map(|key| graph.face(*key).unwrap().centroid())
+            .collect::<Vec<_>>();
+
+        let faces = graph.extrude_each(&keys, R64::from_inner(1.0)).unwrap();
+        assert_eq!(2, faces.len());
+        assert_eq!(16, graph.vertex_count());
+        assert_eq!(14, graph.face_count());
+        assert!(graph.is_closed());
+        for (face, centroid) in faces.into_iter().zip(centroids) {
+            let face = graph.face(face).unwrap();
+            assert_eq!(
+                R64::from_inner(1.0),
+                (face.centroid() - centroid).magnitude()
+            );
+        }
+
+        let key = graph.faces().nth(0).unwrap().key();
+        assert_eq!(
+            Err(GraphError::TopologyConflict),
+            graph.extrude_each(&[key, key], R64::from_inner(1.0))
+        );
+        assert_eq!(14, graph.face_count());
     }
-}
 
-impl<G> AsStorage<Face<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage(&self) -> &Storage<Face<G>> {
-        self.core.as_storage_of::<Face<_>>()
+    #[test]
+    fn faces_with_arity() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().poke_at_centroid();
+
+        let faces = graph.faces_with_arity(3);
+        assert_eq!(4, faces.clone().count());
+        assert!(faces.all(|face| face.arity() == 3));
+        assert_eq!(5, graph.faces_with_arity(4).count());
+        assert_eq!(0, graph.faces_with_arity(5).count());
+        assert_eq!(
+            vec![(3, 4), (4, 5)],
+            graph.arity_histogram().into_iter().collect::<Vec<_>>()
+        );
     }
-}
 
-impl<G> AsStorageMut<Vertex<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage_mut(&mut self) -> &mut Storage<Vertex<G>> {
-        self.core.as_storage_mut_of::<Vertex<_>>()
+    #[test]
+    fn poke_all() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        graph.poke_all().unwrap();
+        assert_eq!(24, graph.face_count());
+        assert_eq!(14, graph.vertex_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        assert!(graph.is_closed());
+        // Each inserted vertex is shared by the four triangles of its face.
+        assert_eq!(
+            6,
+            graph
+                .vertices()
+                .filter(|vertex| vertex.adjacent_faces().count() == 4)
+                .count()
+        );
     }
-}
 
-impl<G> AsStorageMut<Arc<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage_mut(&mut self) -> &mut Storage<Arc<G>> {
-        self.core.as_storage_mut_of::<Arc<_>>()
+    #[test]
+    fn bounding_sphere() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let (center, radius) = graph.bounding_sphere().unwrap();
+
+        for vertex in graph.vertices() {
+            assert!((*vertex.position() - center).magnitude() <= radius);
+        }
+        // The cube has unit width, so its minimal enclosing sphere has a radius
+        // of half the length of its diagonal.
+        assert!(radius < R64::from_inner(3.0f64.sqrt() * 0.5 * 1.05));
+        assert!(MeshGraph::<E3>::new().bounding_sphere().is_none());
     }
-}
 
-impl<G> AsStorageMut<Edge<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage_mut(&mut self) -> &mut Storage<Edge<G>> {
-        self.core.as_storage_mut_of::<Edge<_>>()
+    #[test]
+    fn compact() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        let abc = graph.face_mut(key).unwrap().poke_at_centroid().key();
+        let face = graph.faces().nth(2).unwrap();
+        let (key, data) = (face.key(), face.data);
+
+        let rekeying = graph.compact();
+
+        assert_eq!(9, graph.vertex_count());
+        assert_eq!(9, graph.face_count());
+        assert_eq!(9, rekeying.vertices.len());
+        assert_eq!(9, rekeying.faces.len());
+        assert_eq!(data, graph.face(rekeying.face(key).unwrap()).unwrap().data);
+        let abc = rekeying.vertex(abc).unwrap();
+        assert_eq!(4, graph.vertex(abc).unwrap().adjacent_faces().count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
     }
-}
 
-impl<G> AsStorageMut<Face<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage_mut(&mut self) -> &mut Storage<Face<G>> {
-        self.core.as_storage_mut_of::<Face<_>>()
+    #[test]
+    fn uv_stretch_report() {
+        #[derive(Clone, Copy)]
+        struct Vertex {
+            position: E3,
+            uv: E2,
+        }
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+        }
+
+        impl AsPosition for Vertex {
+            type Position = E3;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        impl AsUv for Vertex {
+            type Uv = E2;
+
+            fn as_uv(&self) -> &Self::Uv {
+                &self.uv
+            }
+        }
+
+        // Two unit squares side by side. The texture coordinates of the left
+        // square are compressed to half of the width of the right square.
+        let vertex = |x: f64, y: f64, u: f64| Vertex {
+            position: E3::new(R64::from_inner(x), R64::from_inner(y), Zero::zero()),
+            uv: E2::new(R64::from_inner(u), R64::from_inner(y)),
+        };
+        let graph = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![NGon([0usize, 1, 4, 3]), NGon([1, 2, 5, 4])],
+            vec![
+                vertex(0.0, 0.0, 0.0),
+                vertex(1.0, 0.0, 0.5),
+                vertex(2.0, 0.0, 1.5),
+                vertex(0.0, 1.0, 0.0),
+                vertex(1.0, 1.0, 0.5),
+                vertex(2.0, 1.0, 1.5),
+            ],
+        )
+        .unwrap();
+        for face in graph.faces() {
+            assert_eq!(R64::from_inner(1.0), face.area());
+        }
+
+        let report = graph.uv_stretch_report().unwrap();
+        assert_eq!(R64::from_inner(0.5), report.min);
+        assert_eq!(R64::from_inner(1.0), report.max);
+        assert_eq!(R64::from_inner(0.75), report.mean);
+        assert_eq!(R64::from_inner(2.0), report.ratio());
+        assert_eq!(2, report.densities.len());
+        assert!(report.degenerate.is_empty());
     }
-}
 
-/// Exposes a [`MeshBuilder`] that can be used to construct a [`MeshGraph`]
-/// incrementally from _surfaces_ and _facets_.
-///
-/// See the [`builder`] module documentation for more.
-///
-/// # Examples
-///
-/// Creating a [`MeshGraph`] from a triangle:
-///
-/// ```rust
-/// # extern crate nalgebra;
-/// # extern crate plexus;
-/// #
-/// use nalgebra::Point2;
-/// use plexus::builder::Buildable;
-/// use plexus::graph::MeshGraph;
-/// use plexus::prelude::*;
-///
-/// let mut builder = MeshGraph::<Point2<f64>>::builder();
-/// let graph = builder
-///     .surface_with(|builder| {
-///         let a = builder.insert_vertex((0.0, 0.0))?;
-///         let b = builder.insert_vertex((1.0, 0.0))?;
-///         let c = builder.insert_vertex((0.0, 1.0))?;
-///         builder.facets_with(|builder| builder.insert_facet(&[a, b, c], ()))
-///     })
-///     .and_then(|_| builder.build())
-///     .unwrap();
-/// ```
-///
-/// [`MeshBuilder`]: crate::builder::MeshBuilder
-/// [`builder`]: crate::builder
-/// [`MeshGraph`]: crate::graph::MeshGraph
-impl<G> Buildable for MeshGraph<G>
-where
-    G: GraphData,
-{
-    type Builder = GraphBuilder<G>;
-    type Error = GraphError;
+    #[test]
+    fn uv_islands() {
+        struct Seamed;
 
-    type Vertex = G::Vertex;
-    type Facet = G::Face;
+        impl GraphData for Seamed {
+            type Vertex = E3;
+            type Arc = bool;
+            type Edge = ();
+            type Face = ();
+        }
 
-    fn builder() -> Self::Builder {
-        Default::default()
+        let mut graph: MeshGraph<Seamed> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(1, graph.uv_islands().len());
+
+        // Cut the cube along the ring of edges about one face.
+        let key = graph.faces().nth(0).unwrap().key();
+        let keys = graph
+            .face(key)
+            .unwrap()
+            .adjacent_arcs()
+            .map(|arc| arc.key())
+            .collect::<Vec<_>>();
+        for key in keys {
+            graph.arc_mut(key).unwrap().data = true;
+        }
+        let mut islands = graph.uv_islands();
+        islands.sort_by_key(|island| island.len());
+        assert_eq!(2, islands.len());
+        assert_eq!(vec![key], islands[0]);
+        assert_eq!(5, islands[1].len());
     }
-}
 
-impl<G> Consistent for MeshGraph<G> where G: GraphData {}
+    #[test]
+    fn parameterize_harmonic() {
+        #[derive(Clone, Copy, Eq, Hash, PartialEq)]
+        struct Vertex {
+            position: E3,
+            uv: E2,
+        }
 
-impl<G> Default for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn default() -> Self {
-        MeshGraph::new()
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+        }
+
+        impl AsPosition for Vertex {
+            type Position = E3;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        impl AsUv for Vertex {
+            type Uv = E2;
+
+            fn as_uv(&self) -> &Self::Uv {
+                &self.uv
+            }
+        }
+
+        impl AsUvMut for Vertex {
+            fn as_uv_mut(&mut self) -> &mut Self::Uv {
+                &mut self.uv
+            }
+        }
+
+        // A triangulated grid in the plane with a single interior vertex.
+        let vertex = |x: usize, y: usize| Vertex {
+            position: E3::new(
+                R64::from_inner(x as f64),
+                R64::from_inner(y as f64),
+                Zero::zero(),
+            ),
+            uv: E2::origin(),
+        };
+        let mut graph: MeshGraph<Vertex> = (0..2)
+            .flat_map(|x| (0..2).map(move |y| (x, y)))
+            .flat_map(|(x, y)| {
+                vec![
+                    NGon([vertex(x, y), vertex(x + 1, y), vertex(x + 1, y + 1)]),
+                    NGon([vertex(x, y), vertex(x + 1, y + 1), vertex(x, y + 1)]),
+                ]
+            })
+            .collect();
+        graph.parameterize_harmonic().unwrap();
+
+        let center = E2::new(R64::from_inner(0.5), R64::from_inner(0.5));
+        let is_boundary = |vertex: &VertexView<_>| {
+            vertex
+                .outgoing_arcs()
+                .any(|arc| arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc())
+        };
+        let interior = graph
+            .vertices()
+            .find(|vertex| !is_boundary(vertex))
+            .unwrap();
+        assert!((interior.data.uv - center).magnitude() < R64::from_inner(1e-6));
+        for vertex in graph.vertices().filter(|vertex| is_boundary(vertex)) {
+            let radius = (vertex.data.uv - center).magnitude();
+            assert!((radius - R64::from_inner(0.5)).abs() < R64::from_inner(1e-6));
+        }
+        // Faces are wound counter-clockwise in texture space.
+        for face in graph.faces() {
+            let uvs = face
+                .adjacent_vertices()
+                .map(|vertex| vertex.data.uv)
+                .collect::<Vec<_>>();
+            let (ab, ac) = (uvs[1] - uvs[0], uvs[2] - uvs[0]);
+            assert!((ab.x * ac.y) - (ab.y * ac.x) > Zero::zero());
+        }
+
+        // Closed graphs cannot be parameterized.
+        let mut graph: MeshGraph<Vertex> = UvSphere::new(4, 4)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .map_vertices(|position| Vertex {
+                position,
+                uv: E2::origin(),
+            })
+            .collect();
+        assert_eq!(
+            Err(GraphError::TopologyMalformed),
+            graph.parameterize_harmonic()
+        );
     }
-}
 
-impl<G> DynamicArity for MeshGraph<G>
-where
-    G: GraphData,
-{
-    type Dynamic = MeshArity;
+    #[test]
+    fn vertices_and_faces_within() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let corner = E3::new(
+            R64::from_inner(0.5),
+            R64::from_inner(0.5),
+            R64::from_inner(0.5),
+        );
 
-    fn arity(&self) -> Self::Dynamic {
-        MeshArity::from_components::<FaceView<_>, _>(self.faces())
+        assert_eq!(1, graph.vertices_within(corner, R64::from_inner(0.5)).len());
+        // Edges have unit length, so adjacent vertices are included.
+        assert_eq!(4, graph.vertices_within(corner, R64::from_inner(1.0)).len());
+        assert_eq!(8, graph.vertices_within(corner, R64::from_inner(2.0)).len());
+        assert_eq!(0, graph.faces_within(corner, R64::from_inner(0.5)).len());
+        // Centroids of the faces adjacent to the corner are at a distance of
+        // the square root of one half.
+        assert_eq!(3, graph.faces_within(corner, R64::from_inner(0.75)).len());
     }
-}
 
-impl<P, G> From<P> for MeshGraph<G>
-where
-    P: Polygonal,
-    G: GraphData,
-    G::Vertex: FromGeometry<P::Vertex>,
-{
-    fn from(polygon: P) -> Self {
-        let arity = polygon.arity();
-        MeshGraph::from_raw_buffers_with_arity(0..arity, polygon, arity)
-            .expect("inconsistent polygon")
+    #[test]
+    fn smooth_weighted() {
+        let sphere = || -> MeshGraph<E3> {
+            UvSphere::new(8, 8)
+                .polygons::<Position<E3>>()
+                .triangulate()
+                .collect()
+        };
+        let positions = |graph: &MeshGraph<E3>| {
+            graph
+                .vertices()
+                .map(|vertex| (vertex.key(), *vertex.position()))
+                .collect::<HashMap<_, _>>()
+        };
+
+        // Uniform weights are equivalent to `smooth`.
+        let mut uniform = sphere();
+        let mut weighted = sphere();
+        uniform.smooth(0.5).unwrap();
+        weighted
+            .smooth_weighted(|_| R64::from_inner(2.0), 0.5, 1)
+            .unwrap();
+        assert_eq!(positions(&uniform), positions(&weighted));
+
+        // Zero weights do not translate vertices.
+        let mut graph = sphere();
+        let expected = positions(&graph);
+        graph
+            .smooth_weighted(|_| R64::from_inner(0.0), 0.5, 2)
+            .unwrap();
+        assert_eq!(expected, positions(&graph));
+
+        // Negative weights are rejected.
+        assert_eq!(
+            Some(GraphError::Geometry),
+            graph
+                .smooth_weighted(|_| R64::from_inner(-1.0), 0.5, 1)
+                .err()
+        );
+        assert_eq!(expected, positions(&graph));
+
+        assert!(graph.smooth_cotangent(0.5, 2).is_ok());
+        let mut cube: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(
+            Some(GraphError::ArityConflict {
+                expected: 3,
+                actual: 4,
+            }),
+            cube.smooth_cotangent(0.5, 1).err()
+        );
     }
-}
 
-impl<G> From<OwnedCore<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn from(core: OwnedCore<G>) -> Self {
-        MeshGraph { core }
+    #[test]
+    fn chain_operations() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        graph
+            .poke_all()
+            .unwrap()
+            .triangulate()
+            .unwrap()
+            .smooth_cotangent(0.5, 2)
+            .unwrap()
+            .smooth(0.5)
+            .unwrap();
+        assert_eq!(24, graph.face_count());
+        assert!(graph.is_closed());
     }
-}
 
-impl<E, G> FromEncoding<E> for MeshGraph<G>
-where
-    E: FaceDecoder + VertexDecoder,
-    G: GraphData,
-    G::Face: FromGeometry<E::Face>,
-    G::Vertex: FromGeometry<E::Vertex>,
-{
-    type Error = GraphError;
+    #[test]
+    fn smooth_boundary() {
+        // A grid of two by two quadrilaterals with one interior vertex.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![
+                NGon([0usize, 1, 4, 3]),
+                NGon([1, 2, 5, 4]),
+                NGon([3, 4, 7, 6]),
+                NGon([4, 5, 8, 7]),
+            ],
+            vec![
+                (0.0, 0.0),
+                (1.0, -1.0), // Noisy boundary vertex.
+                (2.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 1.0),
+                (2.0, 1.0),
+                (0.0, 2.0),
+                (1.0, 2.0),
+                (2.0, 2.0),
+            ],
+        )
+        .unwrap();
+        let find = |graph: &MeshGraph<E2>, x: f64, y: f64| {
+            let position = E2::new(R64::from_inner(x), R64::from_inner(y));
+            graph
+                .vertices()
+                .find(|vertex| *vertex.position() == position)
+                .map(|vertex| vertex.key())
+        };
+        let corner = find(&graph, 0.0, 0.0).unwrap();
+        let noisy = find(&graph, 1.0, -1.0).unwrap();
+        let interior = find(&graph, 1.0, 1.0).unwrap();
+
+        graph.smooth_boundary(1);
+        assert_eq!(
+            E2::new(R64::from_inner(1.0), R64::from_inner(0.0)),
+            *graph.vertex(noisy).unwrap().position()
+        );
+        assert_eq!(
+            E2::new(R64::from_inner(0.5), R64::from_inner(0.5)),
+            *graph.vertex(corner).unwrap().position()
+        );
+        assert_eq!(
+            E2::new(R64::from_inner(1.0), R64::from_inner(1.0)),
+            *graph.vertex(interior).unwrap().position()
+        );
+    }
 
-    fn from_encoding(
-        vertices: <E as VertexDecoder>::Output,
-        faces: <E as FaceDecoder>::Output,
-    ) -> Result<Self, Self::Error> {
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let keys = vertices
-            .into_iter()
-            .map(|geometry| mutation::vertex::insert(&mut mutation, geometry.into_geometry()))
+    #[test]
+    fn to_line_buffer() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let buffer = graph.to_line_buffer::<u32>().unwrap();
+
+        assert_eq!(8, buffer.as_vertex_slice().len());
+        assert_eq!(2 * graph.edge_count(), buffer.as_index_slice().len());
+        let mut segments = buffer
+            .as_index_slice()
+            .chunks(2)
+            .map(|segment| {
+                let (a, b) = (segment[0], segment[1]);
+                (a.min(b), a.max(b))
+            })
             .collect::<Vec<_>>();
-        for (perimeter, geometry) in faces {
-            let perimeter = perimeter
-                .into_iter()
-                .map(|index| keys[index])
-                .collect::<SmallVec<[_; 4]>>();
-            let cache = FaceInsertCache::from_storage(&mutation, perimeter.as_slice())?;
-            let geometry = geometry.into_geometry();
-            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), geometry))?;
-        }
-        mutation.commit()
+        segments.sort_unstable();
+        segments.dedup();
+        assert_eq!(12, segments.len());
     }
-}
 
-impl<G, P> FromIndexer<P, P> for MeshGraph<G>
-where
-    G: GraphData,
-    G::Vertex: FromGeometry<P::Vertex>,
-    P: Map<usize> + Polygonal,
-    P::Output: Grouping<Group = P::Output> + IntoVertices + Polygonal<Vertex = usize>,
-    Vec<P::Output>: IndexBuffer<P::Output, Index = usize>,
-{
-    type Error = GraphError;
+    #[test]
+    fn validate() {
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        assert_eq!(Ok(()), graph.validate());
 
-    fn from_indexer<I, N>(input: I, indexer: N) -> Result<Self, Self::Error>
-    where
-        I: IntoIterator<Item = P>,
-        N: Indexer<P, P::Vertex>,
-    {
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let (indices, vertices) = input.into_iter().index_vertices(indexer);
-        let vertices = vertices
-            .into_iter()
-            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
-            .collect::<Vec<_>>();
-        for face in indices {
-            let perimeter = face
-                .into_vertices()
-                .into_iter()
-                .map(|index| vertices[index])
-                .collect::<SmallVec<[_; 4]>>();
-            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
-            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
-        }
-        mutation.commit()
+        // A consistent core round-trips.
+        let core: OwnedCore<_> = graph.into();
+        let graph = MeshGraph::try_from_core(core).unwrap();
+        assert_eq!(1, graph.face_count());
+
+        // Corrupt the next arc of an arc in the ring of the face.
+        let mut core: OwnedCore<_> = graph.into();
+        let ab = {
+            let (ab, arc) = core
+                .as_storage_mut_of::<Arc<_>>()
+                .iter_mut()
+                .find(|(_, arc)| arc.face.is_some())
+                .unwrap();
+            arc.next = arc.previous;
+            ab
+        };
+        let errors = MeshGraph::try_from_core(core).err().unwrap();
+        assert!(errors
+            .iter()
+            .all(|error| *error.root() == GraphError::TopologyMalformed));
+        assert!(errors.iter().any(|error| match error {
+            GraphError::Entity { key, .. } => *key == GraphKey::Arc(ab),
+            _ => false,
+        }));
     }
-}
 
-impl<G, P> FromIterator<P> for MeshGraph<G>
-where
-    G: GraphData,
-    G::Vertex: FromGeometry<P::Vertex>,
-    P: Polygonal,
-    P::Vertex: Clone + Eq + Hash,
-    Self: FromIndexer<P, P>,
-{
-    fn from_iter<I>(input: I) -> Self
-    where
-        I: IntoIterator<Item = P>,
-    {
-        Self::from_indexer(input, HashIndexer::default()).unwrap_or_else(|_| Self::default())
+    #[test]
+    fn from_core_checked() {
+        let mut core = CoreBuilder::<E2>::new();
+        let a = core.insert_vertex(E2::new(R64::from_inner(0.0), R64::from_inner(0.0)));
+        let b = core.insert_vertex(E2::new(R64::from_inner(1.0), R64::from_inner(0.0)));
+        let ab = core.insert_arc(a, b, ()).unwrap();
+        let ba = core.insert_arc(b, a, ()).unwrap();
+        core.insert_edge(ab, ()).unwrap();
+        core.set_leading_arc(a, ab).unwrap();
+        core.set_leading_arc(b, ba).unwrap();
+        core.join_arcs(ab, ba).unwrap();
+        assert_eq!(
+            Some(&GraphError::TopologyConflict),
+            core.insert_arc(a, b, ())
+                .err()
+                .as_ref()
+                .map(GraphError::root)
+        );
+
+        // The arc `BA` is not joined to the arc `AB`.
+        let errors = MeshGraph::from_core_checked(core).err().unwrap();
+        assert!(errors.iter().any(|error| match error {
+            GraphError::Entity { key, .. } => *key == GraphKey::Arc(ab),
+            _ => false,
+        }));
     }
-}
 
-impl<P, G, H> FromRawBuffers<P, H> for MeshGraph<G>
-where
-    P: IntoVertices + Polygonal,
-    P::Vertex: Integer + ToPrimitive + Unsigned,
-    G: GraphData,
-    G::Vertex: FromGeometry<H>,
-{
-    type Error = GraphError;
+    #[test]
+    fn from_raw_buffers_unchecked() {
+        let (indices, vertices) = Cube::new()
+            .polygons::<Position<E3>>()
+            .index_vertices::<Tetragon<usize>, _>(HashIndexer::default());
+        let checked = MeshGraph::<E3>::from_raw_buffers(indices.clone(), vertices.clone()).unwrap();
+        let unchecked = MeshGraph::<E3>::from_raw_buffers_unchecked(indices, vertices);
 
-    fn from_raw_buffers<I, J>(indices: I, vertices: J) -> Result<Self, Self::Error>
-    where
-        I: IntoIterator<Item = P>,
-        J: IntoIterator<Item = H>,
-    {
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let vertices = vertices
-            .into_iter()
-            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
-            .collect::<Vec<_>>();
-        for face in indices {
-            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(face.arity());
-            for index in face.into_vertices() {
-                let index = <usize as NumCast>::from(index).unwrap();
-                perimeter.push(
-                    *vertices
-                        .get(index)
-                        .ok_or_else(|| GraphError::TopologyNotFound)?,
-                );
-            }
-            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
-            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
-        }
-        mutation.commit()
+        assert_eq!(Ok(()), unchecked.validate());
+        assert_eq!(checked.vertex_count(), unchecked.vertex_count());
+        assert_eq!(checked.arc_count(), unchecked.arc_count());
+        assert_eq!(checked.edge_count(), unchecked.edge_count());
+        assert_eq!(checked.face_count(), unchecked.face_count());
+
+        // Boundary arcs are linked into a ring.
+        let graph = MeshGraph::<E2>::from_raw_buffers_unchecked(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        );
+
+        assert_eq!(Ok(()), graph.validate());
+        assert_eq!(10, graph.arc_count());
+        assert_eq!(5, graph.edge_count());
+        let arc = graph.arcs().find(|arc| arc.is_boundary_arc()).unwrap();
+        assert_eq!(4, arc.into_ring().arity());
     }
-}
 
-impl<N, G, H> FromRawBuffersWithArity<N, H> for MeshGraph<G>
-where
-    N: Integer + ToPrimitive + Unsigned,
-    G: GraphData,
-    G::Vertex: FromGeometry<H>,
-{
-    type Error = GraphError;
+    #[test]
+    fn colorize_by() {
+        pub enum Colored {}
 
-    /// Creates a [`MeshGraph`] from [raw buffers][`buffer`]. The arity of the
-    /// polygons in the index buffer must be given and constant.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the arity of the index buffer is not constant, any
-    /// index is out of bounds, or there is an error inserting topology into the
-    /// graph.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # extern crate nalgebra;
-    /// # extern crate plexus;
-    /// #
-    /// use nalgebra::Point3;
-    /// use plexus::graph::MeshGraph;
-    /// use plexus::index::{Flat3, LruIndexer};
-    /// use plexus::prelude::*;
-    /// use plexus::primitive::generate::Position;
-    /// use plexus::primitive::sphere::UvSphere;
-    ///
-    /// type E3 = Point3<f64>;
-    ///
-    /// let (indices, positions) = UvSphere::new(16, 16)
-    ///     .polygons::<Position<E3>>()
-    ///     .triangulate()
-    ///     .index_vertices::<Flat3, _>(LruIndexer::with_capacity(256));
-    /// let mut graph = MeshGraph::<E3>::from_raw_buffers_with_arity(indices, positions, 3).unwrap();
-    /// ```
-    ///
-    /// [`buffer`]: crate::buffer
-    /// [`MeshGraph`]: crate::graph::MeshGraph
-    fn from_raw_buffers_with_arity<I, J>(
-        indices: I,
-        vertices: J,
-        arity: usize,
-    ) -> Result<Self, Self::Error>
-    where
-        I: IntoIterator<Item = N>,
-        J: IntoIterator<Item = H>,
-    {
-        if arity < 3 {
-            return Err(GraphError::ArityNonPolygonal);
+        impl GraphData for Colored {
+            type Vertex = E3;
+            type Arc = ();
+            type Edge = ();
+            type Face = Color;
         }
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let vertices = vertices
-            .into_iter()
-            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
-            .collect::<Vec<_>>();
-        for face in &indices
-            .into_iter()
-            .map(|index| <usize as NumCast>::from(index).unwrap())
-            .chunks(arity)
-        {
-            let face = face.collect::<Vec<_>>();
-            if face.len() != arity {
-                // Index buffer length is not a multiple of arity.
-                return Err(GraphError::ArityConflict {
-                    expected: arity,
-                    actual: face.len(),
-                });
-            }
-            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(arity);
-            for index in face {
-                perimeter.push(
-                    *vertices
-                        .get(index)
-                        .ok_or_else(|| GraphError::TopologyNotFound)?,
-                );
+
+        let mut graph: MeshGraph<Colored> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().poke_at_centroid();
+        graph.colorize_by(|face| face.arity() as f64, &ColorRamp::heat());
+
+        for face in graph.faces() {
+            let expected = if face.arity() == 3 {
+                Color::from_rgb(0.0, 0.0, 1.0)
             }
-            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
-            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+            else {
+                Color::from_rgb(1.0, 0.0, 0.0)
+            };
+            assert_eq!(expected, face.data);
         }
-        mutation.commit()
-    }
-}
-
-impl<G> Parametric for MeshGraph<G>
-where
-    G: GraphData,
-{
-    type Data = G;
-}
 
-impl<G> Into<OwnedCore<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn into(self) -> OwnedCore<G> {
-        let MeshGraph { core, .. } = self;
-        core
+        // Uniform fields map to the middle of the ramp.
+        graph.colorize_by(|_| 1.0, &ColorRamp::heat());
+        assert!(graph
+            .faces()
+            .all(|face| face.data == Color::from_rgb(0.0, 1.0, 0.0)));
     }
-}
 
-impl<G> IntoPolygons for MeshGraph<G>
-where
-    G: GraphData,
-{
-    type Output = vec::IntoIter<Self::Polygon>;
-    type Polygon = UnboundedPolygon<G::Vertex>;
+    #[test]
+    fn component_aabbs() {
+        let mut graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([3, 4, 5])],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (0.0, 1.0),
+                (4.0, 0.0),
+                (7.0, 0.0),
+                (4.0, 3.0),
+            ],
+        )
+        .unwrap();
+        let mut components = graph.component_aabbs();
+        components.sort_by_key(|(_, aabb)| aabb.origin.x);
+        assert_eq!(2, components.len());
+        for (keys, _) in components.iter() {
+            assert_eq!(3, keys.len());
+        }
+        assert_eq!(
+            E2::new(R64::from_inner(0.0), R64::from_inner(0.0)),
+            components[0].1.origin
+        );
+        assert_eq!(
+            Vector2::new(R64::from_inner(1.0), R64::from_inner(1.0)),
+            components[0].1.extent
+        );
+        assert_eq!(
+            E2::new(R64::from_inner(4.0), R64::from_inner(0.0)),
+            components[1].1.origin
+        );
 
-    fn into_polygons(self) -> Self::Output {
-        self.faces()
-            .map(|face| {
-                // The arity of a face in a graph must be polygonal (three or
-                // higher) so this should never fail.
-                let vertices = face.adjacent_vertices().map(|vertex| vertex.data);
-                UnboundedPolygon::from_items(vertices).expect_consistent()
-            })
-            .collect::<Vec<_>>()
-            .into_iter()
+        let centroids = graph.recenter_components();
+        assert!(centroids
+            .iter()
+            .any(|centroid| *centroid == E2::new(R64::from_inner(5.0), R64::from_inner(1.0))));
+        for (keys, _) in graph.component_aabbs() {
+            let centroid = E2::centroid(
+                keys.iter()
+                    .map(|key| *graph.vertex(*key).unwrap().position()),
+            )
+            .unwrap();
+            assert_eq!(E2::origin(), centroid);
+        }
     }
-}
-
-impl<G> StaticArity for MeshGraph<G>
-where
-    G: GraphData,
-{
-    type Static = (usize, Option<usize>);
 
-    const ARITY: Self::Static = (3, None);
-}
+    #[test]
+    fn orphaned_arcs() {
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        assert!(graph.orphaned_arcs().is_empty());
 
-impl<A, N, H, G> TryFrom<MeshBuffer<Flat<A, N>, H>> for MeshGraph<G>
-where
-    A: NonZero + typenum::Unsigned,
-    N: Copy + Integer + NumCast + Unsigned,
-    H: Clone,
-    G: GraphData,
-    G::Vertex: FromGeometry<H>,
-{
-    type Error = GraphError;
+        // Remove the edge of an arc. Both of its arcs are orphaned.
+        let mut core: OwnedCore<_> = graph.into();
+        let (ab, edge) = {
+            let (ab, arc) = core
+                .as_storage_mut_of::<Arc<_>>()
+                .iter_mut()
+                .next()
+                .unwrap();
+            (ab, arc.edge.unwrap())
+        };
+        core.as_storage_mut_of::<Edge<_>>().remove(&edge);
+        let graph = MeshGraph::from(core);
 
-    /// Creates a [`MeshGraph`] from a flat [`MeshBuffer`]. The arity of the
-    /// polygons in the index buffer must be known and constant.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if a [`MeshGraph`] cannot represent the topology in the
-    /// [`MeshBuffer`].
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # extern crate nalgebra;
-    /// # extern crate plexus;
-    /// #
-    /// use nalgebra::Point2;
-    /// use plexus::buffer::MeshBuffer;
-    /// use plexus::graph::MeshGraph;
-    /// use plexus::index::Flat4;
-    /// use plexus::prelude::*;
-    /// use std::convert::TryFrom;
-    ///
-    /// type E2 = Point2<f64>;
-    ///
-    /// let buffer = MeshBuffer::<Flat4, E2>::from_raw_buffers(
-    ///     vec![0u64, 1, 2, 3],
-    ///     vec![(0.0f64, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
-    /// )
-    /// .unwrap();
-    /// let mut graph = MeshGraph::<E2>::try_from(buffer).unwrap();
-    /// ```
-    ///
-    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
-    /// [`MeshGraph`]: crate::graph::MeshGraph
-    fn try_from(buffer: MeshBuffer<Flat<A, N>, H>) -> Result<Self, Self::Error> {
-        let arity = buffer.arity();
-        let (indices, vertices) = buffer.into_raw_buffers();
-        MeshGraph::from_raw_buffers_with_arity(indices, vertices, arity)
+        let arcs = graph.orphaned_arcs().into_iter().collect::<HashSet<_>>();
+        assert_eq!(
+            [ab, ab.into_opposite()]
+                .iter()
+                .cloned()
+                .collect::<HashSet<_>>(),
+            arcs
+        );
     }
-}
 
-impl<P, H, G> TryFrom<MeshBuffer<P, H>> for MeshGraph<G>
-where
-    P: Grouping<Group = P> + IntoVertices + Polygonal,
-    P::Vertex: Copy + Integer + NumCast + Unsigned,
-    H: Clone,
-    G: GraphData,
-    G::Vertex: FromGeometry<H>,
-{
-    type Error = GraphError;
+    #[test]
+    fn mean_curvature_skeleton() {
+        // Graphs must be triangular.
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(
+            Err(GraphError::ArityConflict {
+                expected: 3,
+                actual: 4
+            }),
+            graph.mean_curvature_skeleton(1, R64::from_inner(0.1))
+        );
 
-    /// Creates a [`MeshGraph`] from a structured [`MeshBuffer`].
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if a [`MeshGraph`] cannot represent the topology in the
-    /// [`MeshBuffer`].
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// # extern crate nalgebra;
-    /// # extern crate plexus;
-    /// #
-    /// use nalgebra::Point2;
-    /// use plexus::buffer::MeshBuffer;
-    /// use plexus::graph::MeshGraph;
-    /// use plexus::prelude::*;
-    /// use plexus::primitive::Tetragon;
-    /// use std::convert::TryFrom;
-    ///
-    /// type E2 = Point2<f64>;
-    ///
-    /// let buffer = MeshBuffer::<Tetragon<u64>, E2>::from_raw_buffers(
-    ///     vec![Tetragon::new(0u64, 1, 2, 3)],
-    ///     vec![(0.0f64, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
-    /// )
-    /// .unwrap();
-    /// let mut graph = MeshGraph::<E2>::try_from(buffer).unwrap();
-    /// ```
-    ///
-    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
-    /// [`MeshGraph`]: crate::graph::MeshGraph
-    fn try_from(buffer: MeshBuffer<P, H>) -> Result<Self, Self::Error> {
-        let (indices, vertices) = buffer.into_raw_buffers();
-        MeshGraph::from_raw_buffers(indices, vertices)
+        let mut graph: MeshGraph<E3> = UvSphere::new(16, 8)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+        let radius = |positions: &[E3]| {
+            positions
+                .iter()
+                .map(|position| (*position - E3::origin()).magnitude())
+                .fold(R64::from_inner(0.0), |max, radius| {
+                    if radius > max {
+                        radius
+                    }
+                    else {
+                        max
+                    }
+                })
+        };
+        let before = graph
+            .vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+        let after = graph
+            .mean_curvature_skeleton(2, R64::from_inner(0.1))
+            .unwrap();
+
+        assert_eq!(before.len(), after.len());
+        assert!(radius(&after) < radius(&before));
+        assert_eq!(
+            after,
+            graph
+                .vertices()
+                .map(|vertex| *vertex.position())
+                .collect::<Vec<_>>()
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use decorum::R64;
-    use nalgebra::{Point2, Point3, Vector3};
-    use num::Zero;
+    #[test]
+    fn edge_segments_with_data() {
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        let segments = graph.edge_segments_with_data();
 
-    use crate::buffer::MeshBuffer3;
-    use crate::graph::{GraphData, GraphError, MeshGraph};
-    use crate::prelude::*;
-    use crate::primitive::generate::Position;
-    use crate::primitive::sphere::UvSphere;
-    use crate::primitive::NGon;
+        assert_eq!(4, segments.len());
+        assert_eq!(4, segments.clone().count());
+        for (key, a, b, _) in segments {
+            let (source, destination) = {
+                let arc = graph.edge(key).unwrap().into_arc();
+                (
+                    *arc.source_vertex().position(),
+                    *arc.destination_vertex().position(),
+                )
+            };
+            assert_eq!((source, destination), (*a, *b));
+            assert_eq!(R64::from_inner(1.0), (*b - *a).magnitude());
+        }
+    }
 
-    type E2 = Point2<R64>;
-    type E3 = Point3<R64>;
+    #[test]
+    fn iterate_over_faces() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        let mut n = 0;
+        for face in &graph {
+            assert_eq!(4, face.arity());
+            n += 1;
+        }
+        assert_eq!(graph.face_count(), n);
+        assert_eq!(6, (&graph).into_iter().len());
+    }
 
     #[test]
-    fn collect() {
-        let graph: MeshGraph<Point3<f64>> = UvSphere::new(3, 2)
+    fn collect_polygons() {
+        // Bounded (structured) polygons.
+        let graph: MeshGraph<E3> = UvSphere::new(3, 2)
             .polygons::<Position<E3>>() // 6 triangles, 18 vertices.
             .collect();
-
         assert_eq!(5, graph.vertex_count());
-        assert_eq!(18, graph.arc_count());
         assert_eq!(6, graph.face_count());
-    }
 
-    #[test]
-    fn iterate() {
-        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(4, 2)
-            .polygons::<Position<E3>>() // 8 triangles, 24 vertices.
+        // Triangles.
+        let graph: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>()
+            .triangulate()
             .collect();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.face_count());
 
-        assert_eq!(6, graph.vertices().count());
-        assert_eq!(24, graph.arcs().count());
-        assert_eq!(8, graph.faces().count());
-        for vertex in graph.vertices() {
-            // Every vertex is connected to 4 triangles with 4 (incoming) arcs.
-            // Traversal of topology should be possible.
-            assert_eq!(4, vertex.incoming_arcs().count());
-        }
-        for mut vertex in graph.vertex_orphans() {
-            // Data should be mutable.
-            vertex.data += Vector3::zero();
-        }
+        // Unbounded polygons.
+        let graph: MeshGraph<E2> = vec![UnboundedPolygon::from(NGon([
+            E2::new(R64::from_inner(0.0), R64::from_inner(0.0)),
+            E2::new(R64::from_inner(1.0), R64::from_inner(0.0)),
+            E2::new(R64::from_inner(1.0), R64::from_inner(1.0)),
+            E2::new(R64::from_inner(0.5), R64::from_inner(1.5)),
+            E2::new(R64::from_inner(0.0), R64::from_inner(1.0)),
+        ]))]
+        .into_iter()
+        .collect();
+        assert_eq!(5, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
     }
 
     #[test]
@@ -1670,8 +7136,302 @@ mod tests {
         assert_eq!(graph.err().unwrap(), GraphError::TopologyConflict);
     }
 
-    // This test is a sanity check for iterators over orphan views and the
-    // unsafe transmutations used to coerce lifetimes.
+    #[test]
+    fn convex_hull() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let hull = graph.convex_hull().unwrap();
+
+        // Each quadrilateral of the cube is covered by two triangles.
+        assert_eq!(8, hull.vertex_count());
+        assert_eq!(12, hull.face_count());
+        assert!(hull.faces().all(|face| face.arity() == 3));
+        assert!(hull.arcs().all(|arc| !arc.is_boundary_arc()));
+    }
+
+    #[test]
+    fn convex_hull_coplanar_error() {
+        let graph = MeshGraph::<E3>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+            ],
+            4,
+        )
+        .unwrap();
+
+        assert_eq!(Err(GraphError::Geometry), graph.convex_hull().map(|_| ()));
+    }
+
+    #[test]
+    fn from_points_delaunay_2d() {
+        let points = vec![
+            (0.0, 0.0, 1.0),
+            (1.0, 0.0, 0.0),
+            (1.0, 1.0, 2.0),
+            (0.0, 1.0, 1.0),
+            (0.4, 0.6, 3.0),
+        ]
+        .into_iter()
+        .map(|point| point.into_geometry())
+        .collect::<Vec<E3>>();
+        let graph = MeshGraph::<E3>::from_points_delaunay_2d(&points).unwrap();
+
+        assert_eq!(5, graph.vertex_count());
+        assert_eq!(8, graph.edge_count());
+        assert_eq!(4, graph.face_count());
+        assert_eq!(
+            4,
+            graph.edges().filter(|edge| edge.is_boundary_edge()).count()
+        );
+
+        let points = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.4, 0.6)]
+            .into_iter()
+            .map(|point| point.into_geometry())
+            .collect::<Vec<E2>>();
+        let graph = MeshGraph::<E2>::from_points_delaunay_2d(&points).unwrap();
+
+        assert_eq!(4, graph.face_count());
+    }
+
+    #[test]
+    fn from_points_delaunay_2d_collinear_error() {
+        let points = vec![(0.0, 0.0, 0.0), (1.0, 1.0, 0.0), (2.0, 2.0, 1.0)]
+            .into_iter()
+            .map(|point| point.into_geometry())
+            .collect::<Vec<E3>>();
+
+        assert_eq!(
+            Err(GraphError::Geometry),
+            MeshGraph::<E3>::from_points_delaunay_2d(&points).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn intersect_plane() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let polylines = graph.intersect_plane(&Plane {
+            origin: EuclideanSpace::origin(),
+            normal: Unit::x(),
+        });
+
+        // The plane crosses four faces of the cube, forming a single closed
+        // polyline that repeats its first position.
+        assert_eq!(1, polylines.len());
+        assert_eq!(5, polylines[0].len());
+        assert_eq!(polylines[0][0], polylines[0][4]);
+    }
+
+    #[test]
+    fn split_by_plane() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let plane = Plane {
+            origin: EuclideanSpace::origin(),
+            normal: Unit::x(),
+        };
+        let (beneath, above) = graph.split_by_plane(&plane).unwrap();
+
+        // Each half retains one face of the cube and a clipped portion of the
+        // four faces that cross the plane.
+        for half in &[&beneath, &above] {
+            assert_eq!(8, half.vertex_count());
+            assert_eq!(5, half.face_count());
+        }
+
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let (beneath, above) = graph.split_by_plane_with_caps(&plane).unwrap();
+
+        // Each half is closed by a face along the section.
+        for half in &[&beneath, &above] {
+            assert_eq!(6, half.face_count());
+            assert!(half.arcs().all(|arc| !arc.is_boundary_arc()));
+        }
+    }
+
+    #[test]
+    fn split_by_plane_with_caps_hole() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let x = graph
+            .vertices()
+            .map(|vertex| vertex.position().x)
+            .max()
+            .unwrap();
+        let key = graph
+            .faces()
+            .find(|face| {
+                face.adjacent_vertices()
+                    .all(|vertex| vertex.position().x == x)
+            })
+            .unwrap()
+            .key();
+        graph.face_mut(key).unwrap().remove();
+        let plane = Plane {
+            origin: Point3::new(x, R64::from_inner(0.0), R64::from_inner(0.0)),
+            normal: Unit::x(),
+        };
+        let (beneath, above) = graph.split_by_plane_with_caps(&plane).unwrap();
+
+        // The hole lies within the plane, but is not formed by the cut and so
+        // is not capped.
+        assert_eq!(5, beneath.face_count());
+        assert!(!beneath.is_closed());
+        assert_eq!(0, above.face_count());
+    }
+
+    #[test]
+    fn split_by_plane_with_caps_nested() {
+        // Construct a graph with a cube enclosed by another cube. The section
+        // through both cubes forms nested rings.
+        let graph: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>()
+            .chain(
+                Cube::new()
+                    .polygons::<Position<E3>>()
+                    .map_vertices(|position| Point3::from(position.coords * R64::from_inner(0.5))),
+            )
+            .collect();
+        let plane = Plane {
+            origin: EuclideanSpace::origin(),
+            normal: Unit::x(),
+        };
+        assert_eq!(
+            Err(GraphError::Geometry),
+            graph.split_by_plane_with_caps(&plane).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn mirror_2d() {
+        // Planes in two dimensions are lines.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        let plane = Plane {
+            origin: EuclideanSpace::origin(),
+            normal: Unit::x(),
+        };
+        graph.mirror(&plane, true).unwrap();
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(2, graph.face_count());
+
+        let polylines = graph.intersect_plane(&Plane {
+            origin: E2::new(R64::from_inner(0.5), Zero::zero()),
+            normal: Unit::x(),
+        });
+        assert_eq!(1, polylines.len());
+        assert_eq!(2, polylines[0].len());
+    }
+
+    #[test]
+    fn mirror() {
+        let plane = Plane {
+            origin: EuclideanSpace::origin(),
+            normal: Unit::x(),
+        };
+        let half = || {
+            let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+            graph.split_by_plane(&plane).unwrap().0
+        };
+
+        // Welding the seam restores a closed surface.
+        let mut graph = half();
+        graph.mirror(&plane, true).unwrap();
+        assert_eq!(12, graph.vertex_count());
+        assert_eq!(10, graph.face_count());
+        assert!(graph.is_closed());
+
+        // Without welding, the reflected copy is disjoint.
+        let mut graph = half();
+        graph.mirror(&plane, false).unwrap();
+        assert_eq!(16, graph.vertex_count());
+        assert_eq!(10, graph.face_count());
+        assert_eq!(2, graph.disjoint_subgraph_vertices().count());
+        assert!(graph
+            .vertices()
+            .all(|vertex| vertex.position().x <= R64::from_inner(1.0)));
+    }
+
+    #[test]
+    fn from_mesh_buffer() {
+        let buffer: MeshBuffer3<usize, E3> = UvSphere::new(8, 8)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+        let graph = MeshGraph::<E3>::from_mesh_buffer(&buffer).unwrap();
+
+        assert_eq!(buffer.as_vertex_slice().len(), graph.vertex_count());
+        assert_eq!(buffer.as_index_slice().len(), graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+    }
+
+    #[test]
+    fn compute_smooth_normals() {
+        struct Corner;
+
+        impl GraphData for Corner {
+            type Vertex = E3;
+            type Arc = Vector3<R64>;
+            type Edge = ();
+            type Face = ();
+        }
+
+        let mut graph: MeshGraph<Corner> = Cube::new().polygons::<Position<E3>>().collect();
+
+        // The faces of a cube meet at right angles, so every edge is hard.
+        graph
+            .compute_smooth_normals(R64::from_inner(FRAC_PI_4))
+            .unwrap();
+        for arc in graph.arcs() {
+            let normal = arc.face().unwrap().normal().unwrap();
+            assert!((normal - arc.data).magnitude() < R64::from_inner(1e-9));
+        }
+
+        // Every edge is smooth, so the corners of each vertex share a normal.
+        graph
+            .compute_smooth_normals(R64::from_inner(3.0 * FRAC_PI_4))
+            .unwrap();
+        for vertex in graph.vertices() {
+            let normal = vertex.outgoing_arcs().next().unwrap().data;
+            assert!(vertex.outgoing_arcs().all(|arc| arc.data == normal));
+        }
+    }
+
+    #[test]
+    fn winding_number_and_contains_point() {
+        let outside = E3::new(
+            R64::from_inner(2.0),
+            R64::from_inner(0.0),
+            R64::from_inner(0.0),
+        );
+        let approx = |x: R64, y: f64| (x.into_inner() - y).abs() < 1e-9;
+
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert!(approx(graph.winding_number(&E3::origin()), 1.0));
+        assert!(approx(graph.winding_number(&outside), 0.0));
+        assert!(graph.contains_point(&E3::origin()));
+        assert!(!graph.contains_point(&outside));
+
+        // Removing a face opens the graph. The center subtends five of the six
+        // faces.
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+        assert!(approx(graph.winding_number(&E3::origin()), 5.0 / 6.0));
+        assert!(graph.contains_point(&E3::origin()));
+
+        // Inward winding negates the winding number.
+        let graph: MeshGraph<E3> = Cube::new()
+            .with_winding(Winding::Inward)
+            .polygons::<Position<E3>>()
+            .collect();
+        assert!(approx(graph.winding_number(&E3::origin()), -1.0));
+        assert!(graph.contains_point(&E3::origin()));
+    }
+
+    // This test is a sanity check for iterators over orphan views.
     #[test]
     fn read_write_geometry_ref() {
         struct Weight;
@@ -1696,4 +7456,35 @@ mod tests {
             assert_eq!(value, face.data);
         }
     }
+
+    // This test is a sanity check for iterators over orphan views of adjacent
+    // entities, which borrow disjoint entities from storage.
+    #[test]
+    fn read_write_adjacent_geometry_ref() {
+        struct Weight;
+
+        impl GraphData for Weight {
+            type Vertex = Point3<f64>;
+            type Arc = ();
+            type Edge = ();
+            type Face = u64;
+        }
+
+        let mut graph: MeshGraph<Weight> = Cube::new().polygons::<Position<E3>>().collect();
+        let keys = graph
+            .vertices()
+            .map(|vertex| vertex.key())
+            .collect::<Vec<_>>();
+        for key in keys {
+            let mut vertex = graph.vertex_mut(key).unwrap();
+            for mut face in vertex.adjacent_face_orphans() {
+                face.data += 1;
+            }
+        }
+
+        // Each face of a cube is adjacent to four vertices.
+        for face in graph.faces() {
+            assert_eq!(4, face.data);
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use fool::and;
+use std::collections::HashSet;
 use std::ops::{Deref, DerefMut};
 
 use crate::entity::borrow::Reborrow;
@@ -31,6 +32,10 @@ where
     // TODO: Split this into two fields.
     #[allow(clippy::type_complexity)]
     storage: (Storage<Arc<Data<M>>>, Storage<Edge<Data<M>>>),
+    // The number of arcs that are not connected to a face. This is maintained
+    // as arcs are inserted, removed, and connected to or disconnected from
+    // faces, so it need not be recomputed when the mutation is committed.
+    boundary_arc_count: usize,
 }
 
 impl<M, G> EdgeMutation<M>
@@ -74,11 +79,40 @@ where
     }
 
     pub fn connect_arc_to_face(&mut self, ab: ArcKey, abc: FaceKey) -> Result<(), GraphError> {
-        self.with_arc_mut(ab, |arc| arc.face = Some(abc))
+        if self
+            .with_arc_mut(ab, |arc| arc.face.replace(abc))?
+            .is_none()
+        {
+            self.boundary_arc_count -= 1;
+        }
+        Ok(())
     }
 
     pub fn disconnect_arc_from_face(&mut self, ab: ArcKey) -> Result<Option<FaceKey>, GraphError> {
-        self.with_arc_mut(ab, |arc| arc.face.take())
+        let abc = self.with_arc_mut(ab, |arc| arc.face.take())?;
+        if abc.is_some() {
+            self.boundary_arc_count += 1;
+        }
+        Ok(abc)
+    }
+
+    fn insert_arc(&mut self, ab: ArcKey, arc: Arc<G>) {
+        if arc.face.is_none() {
+            self.boundary_arc_count += 1;
+        }
+        self.storage.0.insert_with_key(ab, arc);
+    }
+
+    fn remove_arc(&mut self, ab: ArcKey) -> Result<Arc<G>, GraphError> {
+        let arc = self
+            .storage
+            .0
+            .remove(&ab)
+            .ok_or_else(|| GraphError::TopologyNotFound)?;
+        if arc.face.is_none() {
+            self.boundary_arc_count -= 1;
+        }
+        Ok(arc)
     }
 
     fn with_arc_mut<T, F>(&mut self, ab: ArcKey, mut f: F) -> Result<T, GraphError>
@@ -141,10 +175,12 @@ where
     G: GraphData,
 {
     fn from(core: OwnedCore<G>) -> Self {
+        let boundary_arc_count = core.boundary_arc_count();
         let (vertices, arcs, edges, ..) = core.unfuse();
         EdgeMutation {
             inner: Core::empty().fuse(vertices).into(),
             storage: (arcs, edges),
+            boundary_arc_count,
         }
     }
 }
@@ -161,16 +197,33 @@ where
         let EdgeMutation {
             inner,
             storage: (arcs, edges),
-            ..
+            boundary_arc_count,
         } = self;
         // In a consistent graph, all arcs must have adjacent arcs and an
         // associated edge.
-        for (_, arc) in arcs.iter() {
+        for (key, arc) in arcs.iter() {
             if !(and!(&arc.next, &arc.previous, &arc.edge)) {
-                return Err(GraphError::TopologyMalformed);
+                return Err(GraphError::TopologyMalformed.with_entity(key, "arc commit"));
             }
         }
-        inner.commit().map(move |core| core.fuse(arcs).fuse(edges))
+        inner.commit().map(move |core| {
+            core.fuse(arcs)
+                .fuse(edges)
+                .with_boundary_arc_count(boundary_arc_count)
+        })
+    }
+
+    fn abort_into(self) -> Option<OwnedCore<G>> {
+        let EdgeMutation {
+            inner,
+            storage: (arcs, edges),
+            boundary_arc_count,
+        } = self;
+        inner.abort_into().map(move |core| {
+            core.fuse(arcs)
+                .fuse(edges)
+                .with_boundary_arc_count(boundary_arc_count)
+        })
     }
 }
 
@@ -263,32 +316,130 @@ impl EdgeSplitCache {
             + AsStorage<Vertex<Data<B>>>
             + Parametric,
     {
+        let ab = arc.key();
         let opposite = arc
             .to_ref()
             .into_reachable_opposite_arc()
-            .ok_or_else(|| GraphError::TopologyMalformed)?;
+            .ok_or_else(|| GraphError::TopologyMalformed.with_entity(ab, "edge split"))?;
         let source = opposite
             .to_ref()
             .into_reachable_destination_vertex()
-            .ok_or_else(|| GraphError::TopologyMalformed)?;
+            .ok_or_else(|| GraphError::TopologyMalformed.with_entity(ab, "edge split"))?;
         let destination = arc
             .to_ref()
             .into_reachable_destination_vertex()
-            .ok_or_else(|| GraphError::TopologyMalformed)?;
+            .ok_or_else(|| GraphError::TopologyMalformed.with_entity(ab, "edge split"))?;
         let edge = arc
             .to_ref()
             .into_reachable_edge()
-            .ok_or_else(|| GraphError::TopologyNotFound)?;
+            .ok_or_else(|| GraphError::TopologyNotFound.with_entity(ab, "edge split"))?;
         Ok(EdgeSplitCache {
             a: source.key(),
             b: destination.key(),
-            ab: arc.key(),
+            ab,
             ba: opposite.key(),
             ab_ba: edge.key(),
         })
     }
 }
 
+pub struct EdgeCollapseCache {
+    a: VertexKey,
+    b: VertexKey,
+    // Arcs of the edges connected to `A` or `B`.
+    arcs: Vec<ArcKey>,
+    // Faces connected to `A` or `B` and their perimeters.
+    faces: Vec<(Vec<VertexKey>, FaceRemoveCache)>,
+}
+
+impl EdgeCollapseCache {
+    pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Edge<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let ab = arc.key();
+        let source = arc.source_vertex();
+        let destination = arc.destination_vertex();
+        let (a, b) = (source.key(), destination.key());
+        let mut arcs = Vec::new();
+        let mut faces = Vec::new();
+        let mut keys = HashSet::new();
+        // The neighborhoods of `A` and `B` must be surrounded by faces,
+        // because faces are rebuilt about `A` after the collapse.
+        for outgoing in source.outgoing_arcs().chain(destination.outgoing_arcs()) {
+            let face = match (outgoing.face(), outgoing.opposite_arc().face()) {
+                (Some(face), Some(_)) => face,
+                _ => return Err(GraphError::TopologyConflict.with_entity(ab, "edge collapse")),
+            };
+            if outgoing.key() != (b, a).into() {
+                arcs.push(outgoing.key());
+            }
+            if keys.insert(face.key()) {
+                faces.push((face.vertex_keys().collect::<Vec<_>>(), face));
+            }
+        }
+        // The vertices adjacent to both `A` and `B` must be exactly the
+        // vertices opposite `AB` in its adjacent triangles (the link
+        // condition). Otherwise, the collapse produces non-manifold topology.
+        let adjacent = |vertex: &VertexView<_>| {
+            vertex
+                .adjacent_vertices()
+                .map(|vertex| vertex.key())
+                .collect::<HashSet<_>>()
+        };
+        let (na, nb) = (adjacent(&source), adjacent(&destination));
+        let common = na.intersection(&nb).cloned().collect::<HashSet<_>>();
+        let mut expected = HashSet::new();
+        for (perimeter, face) in faces.iter() {
+            if perimeter.contains(&a) && perimeter.contains(&b) {
+                let key = Some(face.key());
+                if key != arc.face().map(|face| face.key())
+                    && key != arc.opposite_arc().face().map(|face| face.key())
+                {
+                    return Err(GraphError::TopologyConflict.with_entity(ab, "edge collapse"));
+                }
+                if perimeter.len() == 3 {
+                    expected.extend(
+                        perimeter
+                            .iter()
+                            .cloned()
+                            .filter(|key| *key != a && *key != b),
+                    );
+                }
+            }
+        }
+        if common != expected {
+            return Err(GraphError::TopologyConflict.with_entity(ab, "edge collapse"));
+        }
+        // Vertices must retain a valence of at least three.
+        if (na.len() + nb.len()) < (common.len() + 5) {
+            return Err(GraphError::TopologyConflict.with_entity(ab, "edge collapse"));
+        }
+        for vertex in destination.adjacent_vertices() {
+            if common.contains(&vertex.key()) && vertex.valence() <= 3 {
+                return Err(GraphError::TopologyConflict.with_entity(ab, "edge collapse"));
+            }
+        }
+        Ok(EdgeCollapseCache {
+            a,
+            b,
+            arcs,
+            faces: faces
+                .into_iter()
+                .map(|(perimeter, face)| {
+                    FaceRemoveCache::from_face(face).map(|cache| (perimeter, cache))
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
 pub struct ArcBridgeCache {
     a: VertexKey,
     b: VertexKey,
@@ -302,29 +453,30 @@ impl ArcBridgeCache {
         B: Reborrow,
         B::Target: AsStorage<Arc<Data<B>>> + AsStorage<Vertex<Data<B>>> + Parametric,
     {
+        let ab = arc.key();
         let destination: ArcView<_> = arc
             .to_ref()
             .rebind(destination)
-            .ok_or_else(|| GraphError::TopologyNotFound)?;
+            .ok_or_else(|| GraphError::TopologyNotFound.with_entity(destination, "arc bridge"))?;
         let a = arc
             .to_ref()
             .into_reachable_source_vertex()
-            .ok_or_else(|| GraphError::TopologyMalformed)?
+            .ok_or_else(|| GraphError::TopologyMalformed.with_entity(ab, "arc bridge"))?
             .key();
         let b = arc
             .to_ref()
             .into_reachable_destination_vertex()
-            .ok_or_else(|| GraphError::TopologyMalformed)?
+            .ok_or_else(|| GraphError::TopologyMalformed.with_entity(ab, "arc bridge"))?
             .key();
         let c = destination
             .to_ref()
             .into_reachable_source_vertex()
-            .ok_or_else(|| GraphError::TopologyMalformed)?
+            .ok_or_else(|| GraphError::TopologyMalformed.with_entity(ab, "arc bridge"))?
             .key();
         let d = destination
             .to_ref()
             .into_reachable_destination_vertex()
-            .ok_or_else(|| GraphError::TopologyMalformed)?
+            .ok_or_else(|| GraphError::TopologyMalformed.with_entity(ab, "arc bridge"))?
             .key();
         // Ensure that existing interior arcs are boundaries.
         for arc in [a, b, c, d]
@@ -334,7 +486,7 @@ impl ArcBridgeCache {
             .flat_map(|ab| -> Option<ArcView<_>> { arc.to_ref().rebind(ab.into()) })
         {
             if !arc.is_boundary_arc() {
-                return Err(GraphError::TopologyConflict);
+                return Err(GraphError::TopologyConflict.with_entity(arc.key(), "arc bridge"));
             }
         }
         Ok(ArcBridgeCache { a, b, c, d })
@@ -371,7 +523,7 @@ impl ArcExtrudeCache {
             + Parametric,
     {
         if !arc.is_boundary_arc() {
-            Err(GraphError::TopologyConflict)
+            Err(GraphError::TopologyConflict.with_entity(arc.key(), "arc extrusion"))
         }
         else {
             Ok(ArcExtrudeCache { ab: arc.key() })
@@ -380,7 +532,7 @@ impl ArcExtrudeCache {
 }
 
 pub fn get_or_insert_with<M, N, F>(
-    mut mutation: N,
+    mutation: N,
     endpoints: (VertexKey, VertexKey),
     f: F,
 ) -> Result<CompositeEdgeKey, GraphError>
@@ -389,43 +541,53 @@ where
     M: Mutable,
     F: FnOnce() -> (<Data<M> as GraphData>::Edge, <Data<M> as GraphData>::Arc),
 {
-    fn get_or_insert_arc<M, N>(
+    let (edge, arc) = f();
+    get_or_insert_with_arc_data(mutation, endpoints, edge, move |_, _| arc)
+}
+
+// Gets or inserts the edge with the given endpoints. The data of any arcs that
+// are inserted is computed from their source and destination vertices by the
+// given function. Arcs that already exist are not modified.
+pub fn get_or_insert_with_arc_data<M, N, F>(
+    mut mutation: N,
+    endpoints: (VertexKey, VertexKey),
+    geometry: <Data<M> as GraphData>::Edge,
+    mut f: F,
+) -> Result<CompositeEdgeKey, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    F: FnMut(VertexKey, VertexKey) -> <Data<M> as GraphData>::Arc,
+{
+    fn get_or_insert_arc<M, N, F>(
         mut mutation: N,
         endpoints: (VertexKey, VertexKey),
-        geometry: <Data<M> as GraphData>::Arc,
+        f: F,
     ) -> (Option<EdgeKey>, ArcKey)
     where
         N: AsMut<Mutation<M>>,
         M: Mutable,
+        F: FnOnce(VertexKey, VertexKey) -> <Data<M> as GraphData>::Arc,
     {
-        let (a, _) = endpoints;
+        let (a, b) = endpoints;
         let ab = endpoints.into();
         if let Some(arc) = mutation.as_mut().storage.0.get(&ab) {
             (arc.edge, ab)
         }
         else {
-            mutation
-                .as_mut()
-                .storage
-                .0
-                .insert_with_key(ab, Arc::new(geometry));
+            mutation.as_mut().insert_arc(ab, Arc::new(f(a, b)));
             let _ = mutation.as_mut().connect_outgoing_arc(a, ab);
             (None, ab)
         }
     }
 
-    let geometry = f();
     let (a, b) = endpoints;
-    let (e1, ab) = get_or_insert_arc(mutation.as_mut(), (a, b), geometry.1);
-    let (e2, ba) = get_or_insert_arc(mutation.as_mut(), (b, a), geometry.1);
+    let (e1, ab) = get_or_insert_arc(mutation.as_mut(), (a, b), &mut f);
+    let (e2, ba) = get_or_insert_arc(mutation.as_mut(), (b, a), &mut f);
     match (e1, e2) {
         (Some(e1), Some(e2)) if e1 == e2 => Ok((e1, (ab, ba))),
         (None, None) => {
-            let ab_ba = mutation
-                .as_mut()
-                .storage
-                .1
-                .insert(Edge::new(ab, geometry.0));
+            let ab_ba = mutation.as_mut().storage.1.insert(Edge::new(ab, geometry));
             mutation.as_mut().connect_arc_to_edge(ab, ab_ba)?;
             mutation.as_mut().connect_arc_to_edge(ba, ab_ba)?;
             Ok((ab_ba, (ab, ba)))
@@ -437,6 +599,35 @@ where
     }
 }
 
+// Gets or inserts the edge with the given endpoints and writes the given data
+// into the arc directed from the source to the destination vertex. The opposite
+// arc is given default data if it is inserted.
+pub fn connect_arc<M, N>(
+    mut mutation: N,
+    endpoints: (VertexKey, VertexKey),
+    geometry: <Data<M> as GraphData>::Arc,
+) -> Result<ArcKey, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+{
+    let (a, b) = endpoints;
+    if a == b {
+        return Err(GraphError::TopologyMalformed);
+    }
+    {
+        let vertices = AsStorage::<Vertex<Data<M>>>::as_storage(mutation.as_mut());
+        if !(vertices.contains_key(&a) && vertices.contains_key(&b)) {
+            return Err(GraphError::TopologyNotFound);
+        }
+    }
+    let (_, (ab, _)) = get_or_insert_with(mutation.as_mut(), endpoints, Default::default)?;
+    mutation
+        .as_mut()
+        .with_arc_mut(ab, |arc| arc.data = geometry)?;
+    Ok(ab)
+}
+
 // TODO: Removing arcs must also remove disjoint vertices. More importantly, the
 //       leading arc of vertices may be invalidated by this operation and must
 //       be healed. This code does not handle these cases, and so can become
@@ -458,12 +649,7 @@ where
         if let Some(cache) = cache {
             face::remove(mutation.as_mut(), cache)?;
         }
-        mutation
-            .as_mut()
-            .storage
-            .0
-            .remove(&ab)
-            .ok_or_else(|| GraphError::TopologyNotFound)
+        mutation.as_mut().remove_arc(ab)
     }
 
     let EdgeRemoveCache {
@@ -502,6 +688,100 @@ where
     ))
 }
 
+// Removes an edge that is not connected to any faces. Unlike `remove`, this
+// does not heal the connectivity of adjacent arcs or the leading arcs of the
+// vertices of the edge, so the neighborhood of the edge must be rebuilt.
+pub fn remove_disjoint<M, N>(
+    mut mutation: N,
+    ab: ArcKey,
+) -> Result<CompositeEdge<Data<M>>, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+{
+    let ba = ab.into_opposite();
+    let ab_ba = {
+        let arcs = &mutation.as_mut().storage.0;
+        let arc = arcs.get(&ab).ok_or_else(|| GraphError::TopologyNotFound)?;
+        let opposite = arcs.get(&ba).ok_or_else(|| GraphError::TopologyMalformed)?;
+        if arc.face.is_some() || opposite.face.is_some() {
+            return Err(GraphError::TopologyConflict);
+        }
+        arc.edge.ok_or_else(|| GraphError::TopologyMalformed)?
+    };
+    let edge = mutation
+        .as_mut()
+        .storage
+        .1
+        .remove(&ab_ba)
+        .ok_or_else(|| GraphError::TopologyNotFound)?;
+    let arc = mutation.as_mut().remove_arc(ab)?;
+    let opposite = mutation.as_mut().remove_arc(ba)?;
+    Ok((edge, (arc, opposite)))
+}
+
+// Collapses an edge `AB` into its source vertex `A`. Faces connected to `A` or
+// `B` are rebuilt with `A` in place of `B` and triangles that are connected to
+// `AB` are removed.
+pub fn collapse<M, N>(mutation: N, cache: EdgeCollapseCache) -> Result<VertexKey, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+{
+    collapse_with(mutation, cache, |a, _| *a)
+}
+
+// Collapses an edge `AB` into its source vertex `A`. The data of `A` is
+// computed from the data of `A` and `B` by the given function.
+pub fn collapse_with<M, N, F>(
+    mut mutation: N,
+    cache: EdgeCollapseCache,
+    mut f: F,
+) -> Result<VertexKey, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    F: FnMut(
+        &<Data<M> as GraphData>::Vertex,
+        &<Data<M> as GraphData>::Vertex,
+    ) -> <Data<M> as GraphData>::Vertex,
+{
+    let EdgeCollapseCache { a, b, arcs, faces } = cache;
+    // Remove the faces and edges connected to `A` or `B` and the vertex `B`.
+    // The leading arcs of `A` and its adjacent vertices may refer to removed
+    // arcs, but are restored when the faces are rebuilt.
+    let faces = faces
+        .into_iter()
+        .map(|(perimeter, cache)| {
+            face::remove(mutation.as_mut(), cache).map(|face| (perimeter, face.data))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for ab in arcs {
+        remove_disjoint(mutation.as_mut(), ab)?;
+    }
+    let vertex = vertex::remove_disjoint(mutation.as_mut(), b)?;
+    mutation
+        .as_mut()
+        .map_vertex_data(a, |data| f(data, &vertex.data))?;
+    for (perimeter, data) in faces {
+        let mut perimeter = perimeter
+            .into_iter()
+            .map(|key| if key == b { a } else { key })
+            .collect::<Vec<_>>();
+        perimeter.dedup();
+        if perimeter.len() > 1 && perimeter.first() == perimeter.last() {
+            perimeter.pop();
+        }
+        // Triangles connected to `AB` collapse into edges.
+        if perimeter.len() < 3 {
+            continue;
+        }
+        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &perimeter)?;
+        face::insert_with(mutation.as_mut(), cache, || (Default::default(), data))?;
+    }
+    Ok(a)
+}
+
 pub fn split_with<M, N, F>(
     mut mutation: N,
     cache: EdgeSplitCache,
@@ -526,7 +806,7 @@ where
         // mutation.as_mut().disconnect_outgoing_arc(a)?;
         let xa = mutation.as_mut().disconnect_previous_arc(ab)?;
         let bx = mutation.as_mut().disconnect_next_arc(ab)?;
-        let mut arc = mutation.as_mut().storage.0.remove(&ab).unwrap();
+        let mut arc = mutation.as_mut().remove_arc(ab)?;
         // Restore the connectivity of the arc. The mutations will clear this
         // data, because it is still a part of the mesh at that point.
         arc.previous = xa;
@@ -571,6 +851,7 @@ where
             mutation.as_mut().connect_face_to_arc(am, abc)?;
             mutation.as_mut().connect_arc_to_face(am, abc)?;
             mutation.as_mut().connect_arc_to_face(mb, abc)?;
+            mutation.as_mut().increment_face_arity(abc)?;
         }
         Ok((am, mb))
     }
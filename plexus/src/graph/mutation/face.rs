@@ -1,7 +1,10 @@
 use smallvec::SmallVec;
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::ops::{Deref, DerefMut};
+use theon::space::{EuclideanSpace, InnerSpace, Vector};
+use theon::AsPosition;
 
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::{AsStorage, Fuse, Storage};
@@ -10,6 +13,7 @@ use crate::graph::core::{Core, OwnedCore, RefCore};
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcView};
 use crate::graph::face::{Face, FaceKey, FaceView, ToRing};
+use crate::graph::geometry::VertexPosition;
 use crate::graph::mutation::edge::{self, ArcBridgeCache, EdgeMutation};
 use crate::graph::mutation::vertex;
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
@@ -114,6 +118,16 @@ where
         Ok(())
     }
 
+    // Increments the cached arity of a face whose ring has been extended by an
+    // arc without reinserting the face.
+    pub fn increment_face_arity(&mut self, abc: FaceKey) -> Result<(), GraphError> {
+        self.with_face_mut(abc, |face| {
+            if let Some(arity) = face.arity.as_mut() {
+                *arity += 1;
+            }
+        })
+    }
+
     fn with_face_mut<T, F>(&mut self, abc: FaceKey, mut f: F) -> Result<T, GraphError>
     where
         F: FnMut(&mut Face<G>) -> T,
@@ -163,10 +177,16 @@ where
     G: GraphData,
 {
     fn from(core: OwnedCore<G>) -> Self {
+        let boundary_arc_count = core.boundary_arc_count();
         let (vertices, arcs, edges, faces) = core.unfuse();
         FaceMutation {
             storage: faces,
-            inner: Core::empty().fuse(vertices).fuse(arcs).fuse(edges).into(),
+            inner: Core::empty()
+                .fuse(vertices)
+                .fuse(arcs)
+                .fuse(edges)
+                .with_boundary_arc_count(boundary_arc_count)
+                .into(),
         }
     }
 }
@@ -187,6 +207,15 @@ where
         } = self;
         inner.commit().map(move |core| core.fuse(faces))
     }
+
+    fn abort_into(self) -> Option<OwnedCore<G>> {
+        let FaceMutation {
+            inner,
+            storage: faces,
+            ..
+        } = self;
+        inner.abort_into().map(move |core| core.fuse(faces))
+    }
 }
 
 pub struct FaceInsertCache {
@@ -228,17 +257,22 @@ impl FaceInsertCache {
             .map(|key| *key.borrow())
             .collect::<SmallVec<_>>();
         let arity = perimeter.len();
-        let set = perimeter.iter().cloned().collect::<HashSet<_>>();
-        if set.len() != arity {
-            // Vertex keys are not unique.
-            return Err(GraphError::TopologyMalformed);
+        let mut set = HashSet::with_capacity(arity);
+        for key in perimeter.iter().cloned() {
+            if !set.insert(key) {
+                // Vertex keys are not unique.
+                return Err(GraphError::TopologyMalformed.with_entity(key, "face insertion"));
+            }
         }
 
         let storage = storage.reborrow();
         let vertices = perimeter
             .iter()
             .cloned()
-            .map(|key| VertexView::bind(storage, key).ok_or_else(|| GraphError::TopologyNotFound))
+            .map(|key| {
+                VertexView::bind(storage, key)
+                    .ok_or_else(|| GraphError::TopologyNotFound.with_entity(key, "face insertion"))
+            })
             .collect::<Result<SmallVec<[_; 4]>, _>>()?;
         for (previous, next) in perimeter
             .iter()
@@ -250,7 +284,9 @@ impl FaceInsertCache {
             if let Some(previous) = previous {
                 if previous.face.is_some() {
                     // A face already occupies an interior arc.
-                    return Err(GraphError::TopologyConflict);
+                    return Err(
+                        GraphError::TopologyConflict.with_entity(previous.key(), "face insertion")
+                    );
                 }
                 // Let the previous arc be AB and the next arc be BC. The
                 // vertices A, B, and C lie within the implied ring in order.
@@ -263,7 +299,8 @@ impl FaceInsertCache {
                     if let Some(next) = previous.into_reachable_next_arc() {
                         let (_, destination) = next.key().into();
                         if set.contains(&destination) {
-                            return Err(GraphError::TopologyConflict);
+                            return Err(GraphError::TopologyConflict
+                                .with_entity(next.key(), "face insertion"));
                         }
                     }
                 }
@@ -353,6 +390,7 @@ impl FaceSplitCache {
             }
             false
         };
+        let abc = face.key();
         face.shortest_metric(source.into(), destination.into())
             .and_then(|metric| {
                 if metric <= 1 {
@@ -361,7 +399,8 @@ impl FaceSplitCache {
                 else {
                     Ok(())
                 }
-            })?;
+            })
+            .map_err(|error| error.with_entity(abc, "face split"))?;
         // Note that the winding of the perimeters must be relatively oriented.
         let left = perimeter(face.to_ref())
             .perimeter()
@@ -376,7 +415,7 @@ impl FaceSplitCache {
             .map(|(_, b)| b)
             .collect::<Vec<_>>();
         if is_intersecting(&left) || is_intersecting(&right) {
-            return Err(GraphError::TopologyConflict);
+            return Err(GraphError::TopologyConflict.with_entity(abc, "face split"));
         }
         Ok(FaceSplitCache {
             cache: FaceRemoveCache::from_face(face)?,
@@ -428,14 +467,14 @@ impl FaceBridgeCache {
         let destination: FaceView<_> = face
             .to_ref()
             .rebind(destination)
-            .ok_or_else(|| GraphError::TopologyNotFound)?;
+            .ok_or_else(|| GraphError::TopologyNotFound.with_entity(destination, "face bridge"))?;
         let cache = (
             FaceRemoveCache::from_face(face.to_ref())?,
             FaceRemoveCache::from_face(destination.to_ref())?,
         );
         // Ensure that the opposite face exists and has the same arity.
         if face.arity() != destination.arity() {
-            return Err(GraphError::ArityNonUniform);
+            return Err(GraphError::ArityNonUniform.with_entity(face.key(), "face bridge"));
         }
         Ok(FaceBridgeCache {
             source: face.adjacent_arcs().keys().collect(),
@@ -445,6 +484,88 @@ impl FaceBridgeCache {
     }
 }
 
+pub struct FaceBridgeTriangulatedCache {
+    triangles: Vec<[VertexKey; 3]>,
+    cache: (FaceRemoveCache, FaceRemoveCache),
+}
+
+impl FaceBridgeTriangulatedCache {
+    pub fn from_face<B>(face: FaceView<B>, destination: FaceKey) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+        <Data<B> as GraphData>::Vertex: AsPosition,
+        VertexPosition<Data<B>>: EuclideanSpace,
+        Vector<VertexPosition<Data<B>>>: InnerSpace,
+    {
+        let destination: FaceView<_> = face
+            .to_ref()
+            .rebind(destination)
+            .ok_or_else(|| GraphError::TopologyNotFound.with_entity(destination, "face bridge"))?;
+        let cache = (
+            FaceRemoveCache::from_face(face.to_ref())?,
+            FaceRemoveCache::from_face(destination.to_ref())?,
+        );
+        // Ensure that the faces are distinct and share no vertices. Otherwise,
+        // the inserted triangles would be degenerate or overlap.
+        let keys = face.adjacent_vertices().keys().collect::<HashSet<_>>();
+        if face.key() == destination.key()
+            || destination
+                .adjacent_vertices()
+                .any(|vertex| keys.contains(&vertex.key()))
+        {
+            return Err(GraphError::TopologyConflict.with_entity(face.key(), "face bridge"));
+        }
+        let source = face
+            .adjacent_vertices()
+            .map(|vertex| (vertex.key(), *vertex.position()))
+            .collect::<Vec<_>>();
+        // The perimeter of the destination face winds in the opposite
+        // direction along the connecting tube.
+        let mut destination = destination
+            .adjacent_vertices()
+            .map(|vertex| (vertex.key(), *vertex.position()))
+            .collect::<Vec<_>>();
+        destination.reverse();
+        let distance = |p: VertexPosition<Data<B>>, q: VertexPosition<Data<B>>| (p - q).magnitude();
+        // Begin stitching at the destination vertex nearest to the first
+        // source vertex.
+        let offset = destination
+            .iter()
+            .map(|(_, position)| distance(source[0].1, *position))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+        destination.rotate_left(offset);
+        let (n, m) = (source.len(), destination.len());
+        let (mut i, mut j) = (0, 0);
+        let mut triangles = Vec::with_capacity(n + m);
+        // Advance along whichever perimeter yields the shorter diagonal. Each
+        // triangle includes exactly one arc of either the source or
+        // destination face, which determines its winding.
+        while i < n || j < m {
+            let (a, p) = source[i % n];
+            let (b, q) = source[(i + 1) % n];
+            let (c, r) = destination[j % m];
+            let (d, s) = destination[(j + 1) % m];
+            if j >= m || (i < n && distance(q, r) <= distance(p, s)) {
+                triangles.push([a, b, c]);
+                i += 1;
+            }
+            else {
+                triangles.push([d, c, a]);
+                j += 1;
+            }
+        }
+        Ok(FaceBridgeTriangulatedCache { triangles, cache })
+    }
+}
+
 pub struct FaceExtrudeCache {
     sources: Vec<VertexKey>,
     //destinations: Vec<G::Vertex>,
@@ -470,7 +591,7 @@ impl FaceExtrudeCache {
 
 // TODO: Should this accept arc geometry at all?
 pub fn insert_with<M, N, F>(
-    mut mutation: N,
+    mutation: N,
     cache: FaceInsertCache,
     f: F,
 ) -> Result<FaceKey, GraphError>
@@ -478,29 +599,45 @@ where
     N: AsMut<Mutation<M>>,
     M: Mutable,
     F: FnOnce() -> (<Data<M> as GraphData>::Arc, <Data<M> as GraphData>::Face),
+{
+    let (arc, face) = f();
+    insert_with_arc_data(mutation, cache, move || face, move |_, _| arc)
+}
+
+// Inserts a face. The data of any arcs that are inserted is computed from
+// their source and destination vertices by the given function. This includes
+// the exterior arcs of any edges that are inserted.
+pub fn insert_with_arc_data<M, N, F, G>(
+    mut mutation: N,
+    cache: FaceInsertCache,
+    f: F,
+    mut g: G,
+) -> Result<FaceKey, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    F: FnOnce() -> <Data<M> as GraphData>::Face,
+    G: FnMut(VertexKey, VertexKey) -> <Data<M> as GraphData>::Arc,
 {
     let FaceInsertCache {
         perimeter,
         connectivity,
     } = cache;
-    let geometry = f();
     // Insert edges and collect the interior arcs.
     let arcs = perimeter
         .iter()
         .cloned()
         .perimeter()
         .map(|(a, b)| {
-            edge::get_or_insert_with(mutation.as_mut(), (a, b), || {
-                (Default::default(), geometry.0)
-            })
-            .map(|(_, (ab, _))| ab)
+            edge::get_or_insert_with_arc_data(mutation.as_mut(), (a, b), Default::default(), &mut g)
+                .map(|(_, (ab, _))| ab)
         })
         .collect::<Result<Vec<_>, _>>()?;
-    // Insert the face.
-    let face = mutation
-        .as_mut()
-        .storage
-        .insert(Face::new(arcs[0], geometry.1));
+    // Insert the face. Its ring is formed from its interior arcs, so its arity
+    // is known.
+    let mut face = Face::new(arcs[0], f());
+    face.arity = Some(arcs.len());
+    let face = mutation.as_mut().storage.insert(face);
     mutation.as_mut().connect_face_interior(&arcs, face)?;
     mutation
         .as_mut()
@@ -586,6 +723,24 @@ where
     Ok(())
 }
 
+pub fn bridge_triangulated<M, N>(
+    mut mutation: N,
+    cache: FaceBridgeTriangulatedCache,
+) -> Result<(), GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+{
+    let FaceBridgeTriangulatedCache { triangles, cache } = cache;
+    remove(mutation.as_mut(), cache.0)?;
+    remove(mutation.as_mut(), cache.1)?;
+    for triangle in triangles {
+        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &triangle)?;
+        insert_with(mutation.as_mut(), cache, Default::default)?;
+    }
+    Ok(())
+}
+
 pub fn extrude_with<M, N, F>(
     mut mutation: N,
     cache: FaceExtrudeCache,
@@ -5,15 +5,15 @@ pub mod vertex;
 
 use std::ops::{Deref, DerefMut};
 
-use crate::entity::storage::{AsStorage, Storage};
-use crate::graph::core::OwnedCore;
+use crate::entity::storage::{AsStorage, Fuse, Storage};
+use crate::graph::core::{Core, OwnedCore};
 use crate::graph::data::{Data, Parametric};
 use crate::graph::edge::{Arc, Edge};
 use crate::graph::face::Face;
 use crate::graph::mutation::face::FaceMutation;
 use crate::graph::vertex::Vertex;
 use crate::graph::{GraphData, GraphError};
-use crate::transact::Transact;
+use crate::transact::{Mutate, Replace, Transact};
 
 /// Marker trait for graph representations that promise to be in a consistent
 /// state.
@@ -45,6 +45,26 @@ where
     M: Consistent + From<OwnedCore<G>> + Parametric<Data = G> + Into<OwnedCore<G>>,
     G: GraphData,
 {
+    /// Begins a mutation of the graph behind a mutable reference.
+    ///
+    /// The graph is moved into the mutation and an empty graph is swapped in
+    /// until the mutation is committed or aborted, at which point the mutated
+    /// graph is moved back. Unlike [`Mutate::replace`], the empty graph is
+    /// constructed from an empty core, so this does not require `M: Default`.
+    ///
+    /// Aborting does not roll back the mutation: the graph is moved back as
+    /// it was mutated so far. Mutations must validate their topology before
+    /// modifying it so that an aborted mutation leaves the graph consistent.
+    ///
+    /// [`Mutate::replace`]: crate::transact::Mutate::replace
+    pub fn from_graph(graph: &mut M) -> Replace<M, Self> {
+        let core = Core::empty()
+            .fuse(Storage::<Vertex<G>>::new())
+            .fuse(Storage::<Arc<G>>::new())
+            .fuse(Storage::<Edge<G>>::new())
+            .fuse(Storage::<Face<G>>::new());
+        Mutation::replace(graph, M::from(core))
+    }
 }
 
 impl<M, G> AsRef<Self> for Mutation<M>
@@ -161,6 +181,10 @@ where
     fn commit(self) -> Result<Self::Output, Self::Error> {
         self.inner.commit().map(|core| core.into())
     }
+
+    fn abort_into(self) -> Option<M> {
+        self.inner.abort_into().map(|core| core.into())
+    }
 }
 
 pub trait Mutable:
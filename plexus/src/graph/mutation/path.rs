@@ -29,8 +29,8 @@ impl PathExtrudeCache {
             + Consistent
             + Parametric,
     {
-        if path.arcs().any(|arc| !arc.is_boundary_arc()) {
-            Err(GraphError::TopologyMalformed)
+        if let Some(arc) = path.arcs().find(|arc| !arc.is_boundary_arc()) {
+            Err(GraphError::TopologyMalformed.with_entity(arc.key(), "path extrusion"))
         }
         else {
             Ok(PathExtrudeCache {
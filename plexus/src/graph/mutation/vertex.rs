@@ -1,9 +1,14 @@
+use std::collections::HashMap;
+
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::{AsStorage, Fuse, Storage};
+use crate::entity::view::ClosedView;
 use crate::graph::core::Core;
 use crate::graph::data::{Data, GraphData, Parametric};
-use crate::graph::edge::ArcKey;
+use crate::graph::edge::{Arc, ArcKey};
+use crate::graph::face::{Face, FaceKey};
 use crate::graph::mutation::edge::{self, EdgeRemoveCache};
+use crate::graph::mutation::face::{self, FaceInsertCache, FaceRemoveCache};
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
 use crate::graph::GraphError;
@@ -32,6 +37,13 @@ where
         self.with_vertex_mut(a, |vertex| vertex.arc = Some(ab))
     }
 
+    pub fn map_vertex_data<F>(&mut self, a: VertexKey, mut f: F) -> Result<(), GraphError>
+    where
+        F: FnMut(&G::Vertex) -> G::Vertex,
+    {
+        self.with_vertex_mut(a, |vertex| vertex.data = f(&vertex.data))
+    }
+
     // TODO: See `edge::split_with_cache`.
     #[allow(dead_code)]
     pub fn disconnect_outgoing_arc(&mut self, a: VertexKey) -> Result<Option<ArcKey>, GraphError> {
@@ -84,13 +96,17 @@ where
             storage: vertices, ..
         } = self;
         // In a consistent graph, all vertices must have a leading arc.
-        for (_, vertex) in vertices.iter() {
+        for (key, vertex) in vertices.iter() {
             if vertex.arc.is_none() {
-                return Err(GraphError::TopologyMalformed);
+                return Err(GraphError::TopologyMalformed.with_entity(key, "vertex commit"));
             }
         }
         Ok(Core::empty().fuse(vertices))
     }
+
+    fn abort_into(self) -> Option<OwnedCore<G>> {
+        Some(Core::empty().fuse(self.storage))
+    }
 }
 
 pub struct VertexRemoveCache {
@@ -108,6 +124,50 @@ impl VertexRemoveCache {
     }
 }
 
+pub struct VertexChamferCache {
+    a: VertexKey,
+    // For each outgoing arc `AB`, the perimeter of its face beginning with `B`
+    // and excluding `A`.
+    faces: Vec<(Vec<VertexKey>, FaceRemoveCache)>,
+}
+
+impl VertexChamferCache {
+    pub fn from_vertex<B>(vertex: VertexView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let a = vertex.key();
+        let mut faces = Vec::new();
+        for arc in vertex.outgoing_arcs() {
+            // The vertex must be surrounded by faces, because otherwise the
+            // inserted vertices cannot form a closed perimeter.
+            let face = match (arc.face(), arc.opposite_arc().face()) {
+                (Some(face), Some(_)) => face,
+                _ => return Err(GraphError::TopologyConflict.with_entity(a, "vertex chamfer")),
+            };
+            // The keys of the face are the destination vertices of its arcs,
+            // so rotating past `A` yields a perimeter that begins with `B`.
+            let mut perimeter = face.vertex_keys().collect::<Vec<_>>();
+            let index = perimeter
+                .iter()
+                .position(|key| *key == a)
+                .ok_or_else(|| GraphError::TopologyMalformed.with_entity(a, "vertex chamfer"))?;
+            perimeter.rotate_left(index + 1);
+            perimeter.pop();
+            if perimeter.contains(&a) {
+                return Err(GraphError::TopologyConflict.with_entity(a, "vertex chamfer"));
+            }
+            faces.push((perimeter, FaceRemoveCache::from_face(face)?));
+        }
+        Ok(VertexChamferCache { a, faces })
+    }
+}
+
 pub fn insert<M, N>(mut mutation: N, geometry: <Data<M> as GraphData>::Vertex) -> VertexKey
 where
     N: AsMut<Mutation<M>>,
@@ -116,6 +176,21 @@ where
     mutation.as_mut().storage.insert(Vertex::new(geometry))
 }
 
+// Removes a vertex that is not connected to any arcs. Unlike `remove`, this does
+// not remove any connected entities, so the neighborhood of the vertex must be
+// rebuilt.
+pub fn remove_disjoint<M, N>(mut mutation: N, a: VertexKey) -> Result<Vertex<Data<M>>, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+{
+    mutation
+        .as_mut()
+        .storage
+        .remove(&a)
+        .ok_or_else(|| GraphError::TopologyNotFound)
+}
+
 pub fn remove<M, N>(
     mut mutation: N,
     cache: VertexRemoveCache,
@@ -130,3 +205,75 @@ where
     }
     unimplemented!()
 }
+
+// Chamfers a vertex `A` by replacing it with a face. A vertex is inserted
+// along each edge `AB` with data computed from `B` by the given function. Each
+// face about `A` is rebuilt with these vertices in place of `A`.
+pub fn chamfer_with<M, N, F>(
+    mut mutation: N,
+    cache: VertexChamferCache,
+    mut f: F,
+) -> Result<FaceKey, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    F: FnMut(VertexKey) -> <Data<M> as GraphData>::Vertex,
+{
+    let VertexChamferCache { a, faces } = cache;
+    // Remove the faces, edges, and vertex `A`. The leading arcs of adjacent
+    // vertices may refer to removed arcs, but are restored when the faces are
+    // rebuilt.
+    let faces = faces
+        .into_iter()
+        .map(|(perimeter, cache)| {
+            face::remove(mutation.as_mut(), cache).map(|face| (perimeter, face.data))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for (perimeter, _) in faces.iter() {
+        edge::remove_disjoint(mutation.as_mut(), (a, perimeter[0]).into())?;
+    }
+    remove_disjoint(mutation.as_mut(), a)?;
+    let vertices = faces
+        .iter()
+        .map(|(perimeter, _)| {
+            let b = perimeter[0];
+            (b, insert(mutation.as_mut(), f(b)))
+        })
+        .collect::<HashMap<_, _>>();
+    // Rebuild each face `AB...C` as `MB...CN`, where `M` and `N` are the
+    // vertices inserted along `AB` and `AC`, respectively. The inserted face
+    // is formed from the arcs `MN`.
+    let mut ring = HashMap::with_capacity(faces.len());
+    let mut start = None;
+    for (perimeter, data) in faces {
+        let (b, c) = (perimeter[0], perimeter[perimeter.len() - 1]);
+        let m = vertices[&b];
+        let n = *vertices
+            .get(&c)
+            .ok_or_else(|| GraphError::TopologyMalformed)?;
+        ring.insert(m, n);
+        start = start.or(Some(m));
+        let perimeter = Some(m)
+            .into_iter()
+            .chain(perimeter)
+            .chain(Some(n))
+            .collect::<Vec<_>>();
+        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &perimeter)?;
+        face::insert_with(mutation.as_mut(), cache, || (Default::default(), data))?;
+    }
+    let start = start.ok_or_else(|| GraphError::TopologyMalformed)?;
+    let mut perimeter = vec![start];
+    let mut m = ring[&start];
+    while m != start {
+        if perimeter.len() >= ring.len() {
+            return Err(GraphError::TopologyMalformed);
+        }
+        perimeter.push(m);
+        m = ring[&m];
+    }
+    if perimeter.len() != ring.len() {
+        return Err(GraphError::TopologyMalformed);
+    }
+    let cache = FaceInsertCache::from_storage(mutation.as_mut(), &perimeter)?;
+    face::insert_with(mutation.as_mut(), cache, Default::default)
+}
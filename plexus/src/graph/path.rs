@@ -15,7 +15,7 @@ use crate::graph::mutation::path::{self, PathExtrudeCache};
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
 use crate::graph::{GraphError, OptionExt as _, ResultExt as _, Selector};
-use crate::transact::{Mutate, Transact};
+use crate::transact::Transact;
 use crate::IteratorExt as _;
 
 use Selector::ByKey;
@@ -272,6 +272,15 @@ where
         })
     }
 
+    /// Gets the topological length of the path.
+    ///
+    /// The length of a path is the number of arcs that form the path. A path
+    /// $\overrightarrow{(A,B,C)}$ has a length of two. Paths are never empty
+    /// and so always have a length of at least one.
+    pub fn length(&self) -> usize {
+        self.keys.len()
+    }
+
     /// Returns `true` if the path is open.
     ///
     /// An _open path_ is a path that terminates and does **not** form a loop.
@@ -459,7 +468,6 @@ where
         + AsStorage<Edge<G>>
         + AsStorage<Face<G>>
         + AsStorage<Vertex<G>>
-        + Default
         + Mutable<Data = G>,
     G: GraphData,
 {
@@ -484,7 +492,7 @@ where
     {
         let cache = PathExtrudeCache::from_path(self.to_ref())?;
         let Path { storage, .. } = self;
-        Ok(Mutation::replace(storage, Default::default())
+        Ok(Mutation::from_graph(storage)
             .commit_with(|mutation| path::extrude_contour_with(mutation, cache, f))
             .map(|(storage, face)| Bind::bind(storage, face).expect_consistent())
             .expect_consistent())
@@ -633,4 +641,40 @@ mod tests {
         assert_eq!(left.arcs().count(), 1);
         assert_eq!(right.arcs().count(), 1);
     }
+
+    #[test]
+    fn path_between() {
+        let graph =
+            MeshGraph::<()>::from_raw_buffers(vec![Tetragon::from([0usize, 1, 2, 3])], vec![(); 4])
+                .unwrap();
+        let source = graph.vertices().nth(0).unwrap().key();
+        let destination = graph
+            .vertex(source)
+            .unwrap()
+            .into_outgoing_arc()
+            .into_next_arc()
+            .into_destination_vertex()
+            .key();
+
+        let path = graph.path_between(source, destination).unwrap();
+        assert!(path.is_open());
+        assert_eq!(path.length(), 2);
+        assert_eq!(path.arcs().len(), 2);
+        assert_eq!(path.back().key(), source);
+        assert_eq!(path.front().key(), destination);
+
+        let opposite = path.opposite_path();
+        assert_eq!(opposite.length(), 2);
+        assert_eq!(opposite.back().key(), destination);
+        assert_eq!(opposite.front().key(), source);
+        assert_eq!(
+            opposite.vertices().keys().collect::<Vec<_>>(),
+            path.vertices()
+                .keys()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .collect::<Vec<_>>(),
+        );
+    }
 }
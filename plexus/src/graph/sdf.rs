@@ -0,0 +1,205 @@
+use decorum::cmp::IntrinsicOrd;
+use decorum::Real;
+use num::{NumCast, One, ToPrimitive, Zero};
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use theon::ops::Cross;
+use theon::query::Aabb;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
+use theon::AsPosition;
+use typenum::U3;
+
+use crate::graph::data::GraphData;
+use crate::graph::geometry::{Handedness, VertexPosition};
+use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::{self, Mutation};
+use crate::graph::{GraphError, MeshGraph};
+use crate::transact::Transact;
+
+pub fn from_sdf_with<G, F, H>(
+    f: F,
+    bounds: Aabb<VertexPosition<G>>,
+    resolution: usize,
+    mut g: H,
+) -> Result<MeshGraph<G>, GraphError>
+where
+    G: GraphData,
+    F: Fn(VertexPosition<G>) -> Scalar<VertexPosition<G>>,
+    H: FnMut(VertexPosition<G>) -> G::Vertex,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    Scalar<VertexPosition<G>>: IntrinsicOrd + NumCast,
+{
+    // Tetrahedra of the Kuhn decomposition of a cell. The bits of each
+    // index are the offsets of a corner along the x, y, and z axes. All
+    // tetrahedra share the diagonal from corner zero to corner seven, so
+    // adjacent cells agree on the diagonals of their shared faces.
+    const TETRAHEDRA: [[usize; 4]; 6] = [
+        [0, 1, 3, 7],
+        [0, 3, 2, 7],
+        [0, 2, 6, 7],
+        [0, 6, 4, 7],
+        [0, 4, 5, 7],
+        [0, 5, 1, 7],
+    ];
+
+    let zero = Scalar::<VertexPosition<G>>::zero();
+    let one = Scalar::<VertexPosition<G>>::one();
+    let half = one / (one + one);
+    let resolution = resolution.max(1);
+    let (ex, ey, ez) = bounds.extent.into_xyz();
+    let extent = [ex, ey, ez].iter().fold(
+        zero,
+        |max, extent| if *extent > max { *extent } else { max },
+    );
+    let cell_size = if extent > zero {
+        extent / <Scalar<VertexPosition<G>> as NumCast>::from(resolution).unwrap()
+    }
+    else {
+        one
+    };
+    let count = |extent: Scalar<VertexPosition<G>>| {
+        Real::ceil(extent / cell_size)
+            .to_usize()
+            .unwrap_or(1)
+            .max(1)
+            .min(resolution)
+    };
+    let [nx, ny, nz] = [count(ex), count(ey), count(ez)];
+    let (ox, oy, oz) = bounds.origin.into_xyz();
+    let coordinate =
+        |index: usize| <Scalar<VertexPosition<G>> as NumCast>::from(index).unwrap() * cell_size;
+    let index = |x: usize, y: usize, z: usize| x + ((nx + 1) * (y + ((ny + 1) * z)));
+    let mut points = Vec::with_capacity((nx + 1) * (ny + 1) * (nz + 1));
+    for z in 0..=nz {
+        for y in 0..=ny {
+            for x in 0..=nx {
+                points.push(VertexPosition::<G>::from_xyz(
+                    ox + coordinate(x),
+                    oy + coordinate(y),
+                    oz + coordinate(z),
+                ));
+            }
+        }
+    }
+    let values = points.iter().map(|point| f(*point)).collect::<Vec<_>>();
+    let midpoint = |(a, b): (usize, usize)| points[a] + ((points[b] - points[a]) * half);
+    let mut mutation = Mutation::from(MeshGraph::new());
+    // Vertices are keyed by the lattice edge that they intersect, directed
+    // from the inside to the outside of the volume.
+    let mut keys = HashMap::new();
+    for z in 0..nz {
+        for y in 0..ny {
+            for x in 0..nx {
+                let corner = |corner: usize| {
+                    index(
+                        x + (corner & 1),
+                        y + ((corner >> 1) & 1),
+                        z + ((corner >> 2) & 1),
+                    )
+                };
+                for tetrahedron in TETRAHEDRA.iter() {
+                    let (inside, outside): (SmallVec<[usize; 4]>, SmallVec<[usize; 4]>) =
+                        tetrahedron
+                            .iter()
+                            .map(|index| corner(*index))
+                            .partition(|point| values[*point] < zero);
+                    let polygon: SmallVec<[(usize, usize); 4]> = match (inside.len(), outside.len())
+                    {
+                        (1, 3) => outside.iter().map(|point| (inside[0], *point)).collect(),
+                        (3, 1) => inside.iter().map(|point| (*point, outside[0])).collect(),
+                        (2, 2) => SmallVec::from_buf([
+                            (inside[0], outside[0]),
+                            (inside[0], outside[1]),
+                            (inside[1], outside[1]),
+                            (inside[1], outside[0]),
+                        ]),
+                        _ => continue,
+                    };
+                    // Orient the polygon using the midpoints of its edges,
+                    // which never form a degenerate polygon.
+                    let (a, b, c) = (
+                        midpoint(polygon[0]),
+                        midpoint(polygon[1]),
+                        midpoint(polygon[2]),
+                    );
+                    let direction = points[outside[0]] - points[inside[0]];
+                    let reversed = ((b - a).cross(c - a).dot(direction) < zero)
+                        != (G::HANDEDNESS == Handedness::Left);
+                    let mut perimeter = polygon
+                        .iter()
+                        .map(|(a, b)| {
+                            *keys.entry((*a, *b)).or_insert_with(|| {
+                                let t = values[*a] / (values[*a] - values[*b]);
+                                let position = points[*a] + ((points[*b] - points[*a]) * t);
+                                mutation::vertex::insert(&mut mutation, g(position))
+                            })
+                        })
+                        .collect::<SmallVec<[_; 4]>>();
+                    if reversed {
+                        perimeter.reverse();
+                    }
+                    for n in 1..(perimeter.len() - 1) {
+                        let triangle = [perimeter[0], perimeter[n], perimeter[n + 1]];
+                        let cache = FaceInsertCache::from_storage(&mutation, &triangle)?;
+                        mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+                    }
+                }
+            }
+        }
+    }
+    mutation.commit()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use num::Zero;
+    use theon::query::Aabb;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn from_sdf() {
+        let bounds = Aabb::<E3>::from_points(
+            vec![(-1.5, -1.5, -1.5), (1.5, 1.5, 1.5)]
+                .into_iter()
+                .map(|point| point.into_geometry()),
+        );
+        let radius = R64::from_inner(1.0);
+        let graph = MeshGraph::<E3>::from_sdf(
+            |point| (point - E3::origin()).magnitude() - radius,
+            bounds,
+            12,
+        )
+        .unwrap();
+
+        assert_eq!(Ok(()), graph.validate());
+        assert!(graph.face_count() > 0);
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+        // The surface is a sphere and so its Euler characteristic is two.
+        assert_eq!(
+            2,
+            graph.vertex_count() as isize - graph.edge_count() as isize
+                + graph.face_count() as isize
+        );
+        // Faces are oriented outward, so the signed volume is positive.
+        let volume = graph
+            .faces()
+            .map(|face| {
+                let positions = face
+                    .vertices()
+                    .map(|vertex| *vertex.position() - E3::origin())
+                    .collect::<Vec<_>>();
+                positions[0].dot(&positions[1].cross(&positions[2])) / R64::from_inner(6.0)
+            })
+            .fold(R64::zero(), |sum, volume| sum + volume);
+        assert!(volume > R64::from_inner(3.5) && volume < R64::from_inner(4.5));
+    }
+}
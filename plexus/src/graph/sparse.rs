@@ -0,0 +1,458 @@
+use decorum::Real;
+use num::{NumCast, One, Zero};
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar};
+use theon::AsPosition;
+
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph};
+
+/// Vertex adjacency of a [`MeshGraph`] in [compressed sparse row][csr] form.
+///
+/// Each vertex is assigned a row. The columns of a row are the rows of the
+/// adjacent vertices, sorted in ascending order. The columns of row `i` are
+/// `columns[offsets[i]..offsets[i + 1]]`, so `offsets` has one more element
+/// than there are rows.
+///
+/// See [`MeshGraph::vertex_adjacency_csr`].
+///
+/// [csr]: https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::vertex_adjacency_csr`]: crate::graph::MeshGraph::vertex_adjacency_csr
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VertexAdjacency {
+    pub offsets: Vec<usize>,
+    pub columns: Vec<usize>,
+    /// The key of the vertex of each row.
+    pub keys: Vec<VertexKey>,
+}
+
+impl VertexAdjacency {
+    /// Gets the number of rows (vertices).
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Gets the row of the vertex with the given key.
+    pub fn row(&self, key: VertexKey) -> Option<usize> {
+        self.keys.iter().position(|row| *row == key)
+    }
+
+    /// Gets the columns of the given row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn columns(&self, row: usize) -> &[usize] {
+        &self.columns[self.offsets[row]..self.offsets[row + 1]]
+    }
+
+    /// Converts the adjacency into a dense matrix in row-major order.
+    ///
+    /// The matrix has `len() * len()` elements and is symmetric. This is only
+    /// suitable for small graphs.
+    pub fn to_dense(&self) -> Vec<bool> {
+        let n = self.len();
+        let mut matrix = vec![false; n * n];
+        for row in 0..n {
+            for column in self.columns(row) {
+                matrix[(row * n) + column] = true;
+            }
+        }
+        matrix
+    }
+}
+
+/// Discretization of the Laplace operator.
+///
+/// See [`MeshGraph::laplacian_matrix`].
+///
+/// [`MeshGraph::laplacian_matrix`]: crate::graph::MeshGraph::laplacian_matrix
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LaplacianKind {
+    /// The uniform graph Laplacian.
+    ///
+    /// Each adjacent vertex is weighted equally. This only considers topology
+    /// and supports any graph.
+    Uniform,
+    /// The cotangent Laplace-Beltrami operator.
+    ///
+    /// Each adjacent vertex is weighted by half of the sum of the cotangents
+    /// of the angles opposite the edge between the vertices. This considers
+    /// geometry and requires a triangular graph.
+    Cotangent,
+}
+
+/// Square sparse matrix over the vertices of a [`MeshGraph`] in [compressed
+/// sparse row][csr] form.
+///
+/// Each vertex is assigned a row and a column in the same order. The nonzero
+/// elements of row `i` are in the columns `columns[offsets[i]..offsets[i +
+/// 1]]` and have the values `values[offsets[i]..offsets[i + 1]]`. The columns
+/// of a row are sorted in ascending order.
+///
+/// See [`MeshGraph::laplacian_matrix`] and [`VertexAdjacency`].
+///
+/// [csr]: https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::laplacian_matrix`]: crate::graph::MeshGraph::laplacian_matrix
+/// [`VertexAdjacency`]: crate::graph::VertexAdjacency
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMatrix<T> {
+    pub offsets: Vec<usize>,
+    pub columns: Vec<usize>,
+    pub values: Vec<T>,
+    /// The key of the vertex of each row and column.
+    pub keys: Vec<VertexKey>,
+}
+
+impl<T> SparseMatrix<T>
+where
+    T: Copy,
+{
+    /// Gets the number of rows (vertices).
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Gets the row of the vertex with the given key.
+    pub fn row(&self, key: VertexKey) -> Option<usize> {
+        self.keys.iter().position(|row| *row == key)
+    }
+
+    /// Gets the columns of the nonzero elements of the given row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn columns(&self, row: usize) -> &[usize] {
+        &self.columns[self.offsets[row]..self.offsets[row + 1]]
+    }
+
+    /// Gets the values of the nonzero elements of the given row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn values(&self, row: usize) -> &[T] {
+        &self.values[self.offsets[row]..self.offsets[row + 1]]
+    }
+
+    /// Gets the element in the given row and column.
+    ///
+    /// Returns `None` if the element is zero or out of bounds.
+    pub fn get(&self, row: usize, column: usize) -> Option<T> {
+        if row >= self.len() {
+            return None;
+        }
+        self.columns(row)
+            .binary_search(&column)
+            .ok()
+            .map(|index| self.values(row)[index])
+    }
+
+    /// Gets an iterator over the nonzero elements of the matrix as `(row,
+    /// column, value)` triplets.
+    pub fn triplets(&self) -> impl '_ + Iterator<Item = (usize, usize, T)> {
+        (0..self.len()).flat_map(move |row| {
+            self.columns(row)
+                .iter()
+                .zip(self.values(row))
+                .map(move |(column, value)| (row, *column, *value))
+        })
+    }
+}
+
+pub fn vertex_adjacency<G>(graph: &MeshGraph<G>) -> VertexAdjacency
+where
+    G: GraphData,
+{
+    let keys = graph
+        .vertices()
+        .map(|vertex| vertex.key())
+        .collect::<Vec<_>>();
+    let rows = keys
+        .iter()
+        .enumerate()
+        .map(|(row, key)| (*key, row))
+        .collect::<HashMap<_, _>>();
+    let mut offsets = Vec::with_capacity(keys.len() + 1);
+    let mut columns = Vec::with_capacity(graph.arc_count());
+    offsets.push(0);
+    for vertex in graph.vertices() {
+        let mut row = vertex
+            .one_ring_keys()
+            .map(|key| rows[&key])
+            .collect::<SmallVec<[_; 8]>>();
+        row.sort_unstable();
+        row.dedup();
+        columns.extend(row);
+        offsets.push(columns.len());
+    }
+    VertexAdjacency {
+        offsets,
+        columns,
+        keys,
+    }
+}
+
+pub fn laplacian_matrix<G>(
+    graph: &MeshGraph<G>,
+    kind: LaplacianKind,
+) -> Result<SparseMatrix<Scalar<VertexPosition<G>>>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+{
+    let adjacency = vertex_adjacency(graph);
+    let rows = adjacency
+        .keys
+        .iter()
+        .enumerate()
+        .map(|(row, key)| (*key, row))
+        .collect::<HashMap<_, _>>();
+    let mut weights = HashMap::with_capacity(adjacency.columns.len());
+    match kind {
+        LaplacianKind::Uniform => {
+            for (row, column) in (0..adjacency.len()).flat_map(|row| {
+                adjacency
+                    .columns(row)
+                    .iter()
+                    .map(move |column| (row, *column))
+            }) {
+                weights.insert((row, column), One::one());
+            }
+        }
+        LaplacianKind::Cotangent => {
+            let two = Scalar::<VertexPosition<G>>::one() + One::one();
+            for face in graph.faces() {
+                let vertices = face
+                    .adjacent_vertices()
+                    .map(|vertex| (rows[&vertex.key()], *vertex.position()))
+                    .collect::<SmallVec<[_; 3]>>();
+                if vertices.len() != 3 {
+                    return Err(GraphError::ArityConflict {
+                        expected: 3,
+                        actual: vertices.len(),
+                    });
+                }
+                // Weight the edge opposite each corner of the triangle.
+                for k in 0..3 {
+                    let (i, j) = ((k + 1) % 3, (k + 2) % 3);
+                    let u = vertices[i].1 - vertices[k].1;
+                    let v = vertices[j].1 - vertices[k].1;
+                    let dot = u.dot(v);
+                    let squared = (u.dot(u) * v.dot(v)) - (dot * dot);
+                    if squared <= Zero::zero() {
+                        return Err(GraphError::Geometry);
+                    }
+                    let cotangent = dot / Real::sqrt(squared) / two;
+                    let (i, j) = (vertices[i].0, vertices[j].0);
+                    for key in &[(i, j), (j, i)] {
+                        let weight = weights.entry(*key).or_insert_with(Zero::zero);
+                        *weight = *weight + cotangent;
+                    }
+                }
+            }
+        }
+    }
+    let mut offsets = Vec::with_capacity(adjacency.len() + 1);
+    let mut columns = Vec::with_capacity(adjacency.columns.len() + adjacency.len());
+    let mut values = Vec::with_capacity(adjacency.columns.len() + adjacency.len());
+    offsets.push(0);
+    for row in 0..adjacency.len() {
+        let mut diagonal = Zero::zero();
+        let mut elements = adjacency
+            .columns(row)
+            .iter()
+            .map(|column| {
+                let weight = weights
+                    .get(&(row, *column))
+                    .cloned()
+                    .unwrap_or_else(Zero::zero);
+                diagonal = diagonal + weight;
+                (*column, -weight)
+            })
+            .collect::<SmallVec<[_; 8]>>();
+        elements.push((row, diagonal));
+        elements.sort_unstable_by_key(|(column, _)| *column);
+        for (column, value) in elements {
+            columns.push(column);
+            values.push(value);
+        }
+        offsets.push(columns.len());
+    }
+    Ok(SparseMatrix {
+        offsets,
+        columns,
+        values,
+        keys: adjacency.keys,
+    })
+}
+
+// Solves the linear system formed by the given rows and columns of a symmetric
+// positive-definite matrix via the conjugate gradient method. `unknowns` maps
+// rows to their indices in the solution.
+pub fn conjugate_gradient<T>(
+    matrix: &SparseMatrix<T>,
+    rows: &[usize],
+    unknowns: &HashMap<usize, usize>,
+    b: &[T],
+) -> Vec<T>
+where
+    T: Copy + NumCast + Real,
+{
+    let dot = |a: &[T], b: &[T]| {
+        a.iter()
+            .zip(b)
+            .fold(T::zero(), |sum, (a, b)| sum + (*a * *b))
+    };
+    let product = |x: &[T]| {
+        rows.iter()
+            .map(|row| {
+                matrix
+                    .columns(*row)
+                    .iter()
+                    .zip(matrix.values(*row))
+                    .filter_map(|(column, value)| {
+                        unknowns.get(column).map(|index| *value * x[*index])
+                    })
+                    .fold(T::zero(), |sum, value| sum + value)
+            })
+            .collect::<Vec<_>>()
+    };
+    let epsilon = <T as NumCast>::from(1e-12).unwrap_or_else(T::zero);
+    let threshold = dot(b, b) * epsilon * epsilon;
+    let mut x = vec![T::zero(); b.len()];
+    let mut r = b.to_vec();
+    let mut p = r.clone();
+    let mut rr = dot(&r, &r);
+    for _ in 0..(10 * b.len()) {
+        if rr <= threshold {
+            break;
+        }
+        let q = product(&p);
+        let pq = dot(&p, &q);
+        if pq <= T::zero() {
+            break;
+        }
+        let alpha = rr / pq;
+        for (x, p) in x.iter_mut().zip(p.iter()) {
+            *x = *x + (alpha * *p);
+        }
+        for (r, q) in r.iter_mut().zip(q.iter()) {
+            *r = *r - (alpha * *q);
+        }
+        let next = dot(&r, &r);
+        let beta = next / rr;
+        rr = next;
+        for (p, r) in p.iter_mut().zip(r.iter()) {
+            *p = *r + (beta * *p);
+        }
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point2, Point3};
+    use num::Zero;
+
+    use crate::graph::{GraphError, LaplacianKind, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::NGon;
+
+    type E2 = Point2<R64>;
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn vertex_adjacency_csr() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let adjacency = graph.vertex_adjacency_csr();
+
+        assert_eq!(8, adjacency.len());
+        assert_eq!(9, adjacency.offsets.len());
+        assert_eq!(24, adjacency.columns.len());
+        for vertex in graph.vertices() {
+            let row = adjacency.row(vertex.key()).unwrap();
+            let mut expected = vertex
+                .adjacent_vertices()
+                .map(|vertex| adjacency.row(vertex.key()).unwrap())
+                .collect::<Vec<_>>();
+            expected.sort_unstable();
+            assert_eq!(expected.as_slice(), adjacency.columns(row));
+        }
+        let dense = adjacency.to_dense();
+        assert_eq!(24, dense.iter().filter(|adjacent| **adjacent).count());
+        for row in 0..8 {
+            assert!(!dense[(row * 8) + row]);
+        }
+    }
+
+    #[test]
+    fn laplacian_matrix() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let laplacian = graph.laplacian_matrix(LaplacianKind::Uniform).unwrap();
+
+        assert_eq!(8, laplacian.len());
+        assert_eq!(32, laplacian.triplets().count());
+        for row in 0..laplacian.len() {
+            assert_eq!(Some(R64::from_inner(3.0)), laplacian.get(row, row));
+            assert_eq!(
+                R64::zero(),
+                laplacian
+                    .values(row)
+                    .iter()
+                    .fold(R64::zero(), |sum, value| sum + *value)
+            );
+        }
+        assert_eq!(
+            Err(GraphError::ArityConflict {
+                expected: 3,
+                actual: 4
+            }),
+            graph.laplacian_matrix(LaplacianKind::Cotangent)
+        );
+
+        // A right triangle with a right angle at the first vertex.
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2])],
+            vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        let laplacian = graph.laplacian_matrix(LaplacianKind::Cotangent).unwrap();
+        let row = |x: f64, y: f64| {
+            let position = E2::new(R64::from_inner(x), R64::from_inner(y));
+            let key = graph
+                .vertices()
+                .find(|vertex| *vertex.position() == position)
+                .unwrap()
+                .key();
+            laplacian.row(key).unwrap()
+        };
+        let (a, b, c) = (row(0.0, 0.0), row(1.0, 0.0), row(0.0, 1.0));
+        let half = R64::from_inner(0.5);
+        assert_eq!(Some(-half), laplacian.get(a, b));
+        assert_eq!(Some(-half), laplacian.get(a, c));
+        assert_eq!(Some(R64::from_inner(1.0)), laplacian.get(a, a));
+        assert_eq!(Some(half), laplacian.get(b, b));
+    }
+}
@@ -1,13 +1,14 @@
 use derivative::Derivative;
 use fool::BoolExt;
+use num::Zero;
 use slotmap::DefaultKey;
 use smallvec::SmallVec;
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
-use std::mem;
 use std::ops::{Deref, DerefMut};
-use theon::space::Vector;
-use theon::AsPosition;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::{AsPosition, AsPositionMut};
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
 use crate::entity::dijkstra;
@@ -20,11 +21,11 @@ use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
 use crate::graph::face::{Face, FaceKey, FaceOrphan, FaceView};
 use crate::graph::geometry::{VertexCentroid, VertexNormal, VertexPosition};
-use crate::graph::mutation::vertex::{self, VertexRemoveCache};
+use crate::graph::mutation::vertex::{self, VertexChamferCache, VertexRemoveCache};
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::path::Path;
 use crate::graph::{GraphError, OptionExt as _, ResultExt as _};
-use crate::transact::{Mutate, Transact};
+use crate::transact::Transact;
 use crate::IteratorExt as _;
 
 /// Vertex entity.
@@ -189,6 +190,31 @@ where
         self.to_ref().into_outgoing_arc()
     }
 
+    /// Converts the vertex into its outgoing (leading) arc.
+    ///
+    /// Unlike [`into_outgoing_arc`], this function does not panic if the arc
+    /// cannot be found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc cannot be found, which indicates that the
+    /// graph is malformed.
+    ///
+    /// [`into_outgoing_arc`]: crate::graph::VertexView::into_outgoing_arc
+    pub fn try_into_outgoing_arc(self) -> Result<ArcView<B>, GraphError> {
+        self.into_reachable_outgoing_arc()
+            .ok_or_else(|| GraphError::TopologyMalformed)
+    }
+
+    /// Gets the outgoing (leading) arc of the vertex.
+    ///
+    /// See [`try_into_outgoing_arc`].
+    ///
+    /// [`try_into_outgoing_arc`]: crate::graph::VertexView::try_into_outgoing_arc
+    pub fn try_outgoing_arc(&self) -> Result<ArcView<&M>, GraphError> {
+        self.to_ref().try_into_outgoing_arc()
+    }
+
     pub fn shortest_path(&self, key: VertexKey) -> Result<Path<&M>, GraphError> {
         self.to_ref().into_shortest_path(key)
     }
@@ -337,6 +363,36 @@ where
         self.to_ref().into_adjacent_vertices()
     }
 
+    /// Gets an iterator over the keys of the one-ring of the vertex.
+    ///
+    /// The one-ring is formed from the adjacent vertices. Keys are yielded in
+    /// the same rotational order as [`adjacent_vertices`], which includes
+    /// vertices connected by boundary arcs.
+    ///
+    /// [`adjacent_vertices`]: crate::graph::VertexView::adjacent_vertices
+    pub fn one_ring_keys(&self) -> impl Clone + Iterator<Item = VertexKey> + '_ {
+        self.adjacent_vertices().map(|vertex| vertex.key())
+    }
+
+    /// Gets an iterator over the positions of the one-ring of the vertex.
+    ///
+    /// Positions are yielded in the same order as [`one_ring_keys`].
+    ///
+    /// [`one_ring_keys`]: crate::graph::VertexView::one_ring_keys
+    pub fn one_ring_positions(&self) -> impl Clone + Iterator<Item = &VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+    {
+        self.adjacent_vertices().map(|vertex| {
+            let (storage, key) = vertex.unbind();
+            AsStorage::<Vertex<G>>::as_storage(storage)
+                .get(&key)
+                .expect_consistent()
+                .data
+                .as_position()
+        })
+    }
+
     /// Gets an iterator of views over the incoming arcs of the vertex.
     ///
     /// The ordering of arcs is deterministic and is based on the leading arc of
@@ -413,7 +469,15 @@ where
     G: 'a + GraphData,
 {
     pub fn into_adjacent_vertex_orphans(self) -> impl Iterator<Item = VertexOrphan<'a, G>> {
-        VertexCirculator::from(ArcCirculator::<TraceFirst<_>, _>::from(self))
+        let keys = self
+            .to_ref()
+            .adjacent_vertices()
+            .keys()
+            .collect::<SmallVec<[_; 8]>>();
+        let (storage, _) = self.unbind();
+        Orphan::bind_disjoint_into(storage, &keys)
+            .expect_consistent()
+            .into_iter()
     }
 }
 
@@ -433,7 +497,15 @@ where
     G: 'a + GraphData,
 {
     pub fn into_incoming_arc_orphans(self) -> impl Iterator<Item = ArcOrphan<'a, G>> {
-        ArcCirculator::<TraceFirst<_>, _>::from(self)
+        let keys = self
+            .to_ref()
+            .incoming_arcs()
+            .keys()
+            .collect::<SmallVec<[_; 8]>>();
+        let (storage, _) = self.unbind();
+        Orphan::bind_disjoint_into(storage, &keys)
+            .expect_consistent()
+            .into_iter()
     }
 }
 
@@ -461,7 +533,15 @@ where
     G: 'a + GraphData,
 {
     pub fn into_adjacent_face_orphans(self) -> impl Iterator<Item = FaceOrphan<'a, G>> {
-        FaceCirculator::from(ArcCirculator::<TraceFirst<_>, _>::from(self))
+        let keys = self
+            .to_ref()
+            .adjacent_faces()
+            .keys()
+            .collect::<SmallVec<[_; 8]>>();
+        let (storage, _) = self.unbind();
+        Orphan::bind_disjoint_into(storage, &keys)
+            .expect_consistent()
+            .into_iter()
     }
 }
 
@@ -489,7 +569,6 @@ where
         + AsStorage<Edge<G>>
         + AsStorage<Face<G>>
         + AsStorage<Vertex<G>>
-        + Default
         + Mutable<Data = G>,
     G: GraphData,
 {
@@ -532,11 +611,117 @@ where
         // This should never fail here.
         let cache = VertexRemoveCache::from_vertex(self.to_ref()).expect_consistent();
         let (storage, _) = self.unbind();
-        Mutation::replace(storage, Default::default())
+        Mutation::from_graph(storage)
             .commit_with(|mutation| vertex::remove(mutation, cache))
             .map(|_| ())
             .expect_consistent()
     }
+
+    /// Truncates the vertex by replacing it with a face.
+    ///
+    /// A vertex is inserted along each edge connected to the vertex at a
+    /// distance of `amount` from the vertex. These vertices form the perimeter
+    /// of a face that replaces the vertex, so chamfering a vertex with valence
+    /// $n$ inserts an $n$-gon. Each face that was connected to the vertex
+    /// gains an edge.
+    ///
+    /// The data of the inserted vertices is blended from the data of the
+    /// vertices of their edges by [`GraphData::blend_vertices`].
+    ///
+    /// Returns the inserted face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `amount` is not positive or is not less than the
+    /// length of every edge connected to the vertex or if the vertex is on a
+    /// boundary. These conditions are checked before the graph is modified, so
+    /// the graph is not modified in these cases.
+    ///
+    /// # Examples
+    ///
+    /// Truncating a corner of a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.vertices().nth(0).unwrap().key();
+    /// let face = graph
+    ///     .vertex_mut(key)
+    ///     .unwrap()
+    ///     .chamfer(R64::from_inner(0.25))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(3, face.arity());
+    /// ```
+    ///
+    /// [`GraphData::blend_vertices`]: crate::graph::GraphData::blend_vertices
+    pub fn chamfer<T>(self, amount: T) -> Result<FaceView<&'a mut M>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let amount = amount.into();
+        if !(amount > Zero::zero()) {
+            return Err(GraphError::Geometry);
+        }
+        let source = self.data;
+        let a = *self.position();
+        for b in self.adjacent_vertices() {
+            if !(amount < (*b.position() - a).magnitude()) {
+                return Err(GraphError::Geometry);
+            }
+        }
+        self.chamfer_with(move |arc| {
+            let destination = arc.destination_vertex();
+            let ab = *destination.position() - a;
+            let mut geometry = G::blend_vertices(&[source, destination.data]);
+            *geometry.as_position_mut() = a + (ab * (amount / ab.magnitude()));
+            geometry
+        })
+    }
+
+    /// Truncates the vertex by replacing it with a face using the given vertex
+    /// data.
+    ///
+    /// A vertex is inserted along each edge connected to the vertex with data
+    /// computed from the outgoing arc of that edge by the given function. See
+    /// [`chamfer`].
+    ///
+    /// Returns the inserted face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex is on a boundary. This is checked before
+    /// the graph is modified, so the graph is not modified in this case.
+    ///
+    /// [`chamfer`]: crate::graph::VertexView::chamfer
+    pub fn chamfer_with<F>(self, mut f: F) -> Result<FaceView<&'a mut M>, GraphError>
+    where
+        F: FnMut(ArcView<&M>) -> G::Vertex,
+    {
+        let cache = VertexChamferCache::from_vertex(self.to_ref())?;
+        let data = self
+            .outgoing_arcs()
+            .map(|arc| (arc.destination_vertex().key(), f(arc)))
+            .collect::<HashMap<_, _>>();
+        let (storage, _) = self.unbind();
+        Mutation::from_graph(storage)
+            .commit_with(|mutation| vertex::chamfer_with(mutation, cache, |b| data[&b]))
+            .map(|(storage, face)| Bind::bind(storage, face).expect_consistent())
+    }
 }
 
 impl<B, M, G> Adjacency for VertexView<B>
@@ -857,23 +1042,6 @@ where
     }
 }
 
-impl<'a, P, M, G> Iterator for VertexCirculator<P, &'a mut M>
-where
-    P: Trace<ArcKey>,
-    M: AsStorage<Arc<G>> + AsStorageMut<Vertex<G>> + Parametric<Data = G>,
-    G: 'a + GraphData,
-{
-    type Item = VertexOrphan<'a, G>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        VertexCirculator::next(self).map(|key| {
-            let vertex = self.inner.storage.as_storage_mut().get_mut(&key).unwrap();
-            let vertex = unsafe { mem::transmute::<&'_ mut Vertex<G>, &'a mut Vertex<G>>(vertex) };
-            Orphan::bind_unchecked(vertex, key).into()
-        })
-    }
-}
-
 pub struct ArcCirculator<P, B>
 where
     P: Trace<ArcKey>,
@@ -958,23 +1126,6 @@ where
     }
 }
 
-impl<'a, P, M, G> Iterator for ArcCirculator<P, &'a mut M>
-where
-    P: Trace<ArcKey>,
-    M: AsStorageMut<Arc<G>> + Parametric<Data = G>,
-    G: 'a + GraphData,
-{
-    type Item = ArcOrphan<'a, G>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        ArcCirculator::next(self).map(|key| {
-            let arc = self.storage.as_storage_mut().get_mut(&key).unwrap();
-            let arc = unsafe { mem::transmute::<&'_ mut Arc<G>, &'a mut Arc<G>>(arc) };
-            Orphan::bind_unchecked(arc, key).into()
-        })
-    }
-}
-
 pub struct FaceCirculator<P, B>
 where
     P: Trace<ArcKey>,
@@ -1052,27 +1203,11 @@ where
     }
 }
 
-impl<'a, P, M, G> Iterator for FaceCirculator<P, &'a mut M>
-where
-    P: Trace<ArcKey>,
-    M: AsStorage<Arc<G>> + AsStorageMut<Face<G>> + Parametric<Data = G>,
-    G: 'a + GraphData,
-{
-    type Item = FaceOrphan<'a, G>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        FaceCirculator::next(self).map(|key| {
-            let face = self.inner.storage.as_storage_mut().get_mut(&key).unwrap();
-            let face = unsafe { mem::transmute::<&'_ mut Face<G>, &'a mut Face<G>>(face) };
-            Orphan::bind_unchecked(face, key).into()
-        })
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
+    use theon::space::InnerSpace;
 
     use crate::graph::MeshGraph;
     use crate::prelude::*;
@@ -1083,6 +1218,46 @@ mod tests {
 
     type E3 = Point3<R64>;
 
+    #[test]
+    fn chamfer() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.vertices().nth(0).unwrap().key();
+        let position = *graph.vertex(key).unwrap().position();
+        let face = graph
+            .vertex_mut(key)
+            .unwrap()
+            .chamfer(R64::from_inner(0.25))
+            .unwrap()
+            .key();
+
+        assert_eq!(10, graph.vertex_count());
+        assert_eq!(15, graph.edge_count());
+        assert_eq!(7, graph.face_count());
+        assert!(graph.is_closed());
+        assert!(graph.vertex(key).is_none());
+        let face = graph.face(face).unwrap();
+        assert_eq!(3, face.arity());
+        assert!(face.normal().unwrap().dot(&face.centroid().coords) > R64::from_inner(0.0));
+        assert_eq!(3, graph.faces().filter(|face| face.arity() == 5).count());
+        for vertex in face.adjacent_vertices() {
+            let distance = (*vertex.position() - position).magnitude();
+            assert!((distance - R64::from_inner(0.25)).abs() < R64::from_inner(1e-9));
+        }
+    }
+
+    #[test]
+    fn chamfer_boundary_vertex() {
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(-1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (1.0, 0.0, 0.0)],
+        )
+        .unwrap();
+        let key = graph.vertices().nth(0).unwrap().key();
+
+        assert!(graph.vertex_mut(key).unwrap().chamfer(0.25).is_err());
+        assert_eq!(3, graph.vertex_count());
+    }
+
     #[test]
     fn circulate_over_arcs() {
         let graph: MeshGraph<E3> = UvSphere::new(4, 2)
@@ -1096,6 +1271,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn one_ring() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        for vertex in graph.vertices() {
+            let keys = vertex.one_ring_keys().collect::<Vec<_>>();
+            let positions = vertex.one_ring_positions().collect::<Vec<_>>();
+            assert_eq!(3, keys.len());
+            for (key, position) in keys.into_iter().zip(positions) {
+                assert_eq!(graph.vertex(key).unwrap().position(), position);
+            }
+        }
+    }
+
     #[test]
     fn path() {
         let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
@@ -0,0 +1,300 @@
+use decorum::cmp::IntrinsicOrd;
+use decorum::Real;
+use derivative::Derivative;
+use num::{NumCast, One, ToPrimitive, Zero};
+use smallvec::SmallVec;
+use std::fmt::Debug;
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use theon::AsPosition;
+use typenum::U3;
+
+use crate::graph::data::GraphData;
+use crate::graph::geometry::VertexPosition;
+use crate::graph::MeshGraph;
+
+/// Voxelization of a [`MeshGraph`].
+///
+/// See [`MeshGraph::voxelize`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::voxelize`]: crate::graph::MeshGraph::voxelize
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VoxelKind {
+    /// Cells are occupied if their centers are inside of the volume enclosed
+    /// by the graph (see [`MeshGraph::contains_point`]).
+    ///
+    /// [`MeshGraph::contains_point`]: crate::graph::MeshGraph::contains_point
+    Solid,
+    /// Cells are occupied if they intersect a face of the graph.
+    Surface,
+}
+
+/// Dense occupancy grid of cubic cells.
+///
+/// Cells are indexed by their integer coordinates $(x,y,z)$ and stored with
+/// $x$ varying fastest. The cell at $(x,y,z)$ spans from `origin` plus
+/// $(x,y,z)$ times `cell_size` to the same point plus `cell_size` along each
+/// axis.
+///
+/// See [`MeshGraph::voxelize`].
+///
+/// [`MeshGraph::voxelize`]: crate::graph::MeshGraph::voxelize
+#[derive(Derivative)]
+#[derivative(Clone(bound = "S: Clone"), Debug(bound = "S: Debug, Scalar<S>: Debug"))]
+pub struct VoxelGrid<S>
+where
+    S: EuclideanSpace,
+{
+    /// The minimum corner of the grid.
+    pub origin: S,
+    /// The width of each cell.
+    pub cell_size: Scalar<S>,
+    /// The number of cells along each axis.
+    pub dimensions: [usize; 3],
+    /// Occupancy of each cell.
+    pub cells: Vec<bool>,
+}
+
+impl<S> VoxelGrid<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    /// Gets the occupancy of the cell at the given coordinates.
+    ///
+    /// Returns `None` if the coordinates are out of bounds.
+    pub fn get(&self, [x, y, z]: [usize; 3]) -> Option<bool> {
+        let [nx, ny, nz] = self.dimensions;
+        if x < nx && y < ny && z < nz {
+            Some(self.cells[x + (nx * (y + (ny * z)))])
+        }
+        else {
+            None
+        }
+    }
+
+    /// Gets the center of the cell at the given coordinates.
+    pub fn cell_center(&self, [x, y, z]: [usize; 3]) -> S
+    where
+        Scalar<S>: NumCast,
+    {
+        let half = self.cell_size / (Scalar::<S>::one() + One::one());
+        let coordinate =
+            |index: usize| (<Scalar<S> as NumCast>::from(index).unwrap() * self.cell_size) + half;
+        let (ox, oy, oz) = self.origin.into_xyz();
+        S::from_xyz(ox + coordinate(x), oy + coordinate(y), oz + coordinate(z))
+    }
+
+    /// Gets the number of occupied cells.
+    pub fn occupied_count(&self) -> usize {
+        self.cells.iter().filter(|cell| **cell).count()
+    }
+}
+
+pub fn voxelize<G>(
+    graph: &MeshGraph<G>,
+    resolution: usize,
+    kind: VoxelKind,
+) -> VoxelGrid<VertexPosition<G>>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    Scalar<VertexPosition<G>>: IntrinsicOrd + NumCast,
+{
+    let zero = Scalar::<VertexPosition<G>>::zero();
+    let one = Scalar::<VertexPosition<G>>::one();
+    if graph.vertex_count() == 0 {
+        return VoxelGrid {
+            origin: EuclideanSpace::origin(),
+            cell_size: one,
+            dimensions: [0, 0, 0],
+            cells: vec![],
+        };
+    }
+    let resolution = resolution.max(1);
+    let aabb = graph.aabb();
+    let (ex, ey, ez) = aabb.extent.into_xyz();
+    let extent = [ex, ey, ez].iter().fold(
+        zero,
+        |max, extent| if *extent > max { *extent } else { max },
+    );
+    let cell_size = if extent > zero {
+        extent / <Scalar<VertexPosition<G>> as NumCast>::from(resolution).unwrap()
+    }
+    else {
+        one
+    };
+    let count = |extent: Scalar<VertexPosition<G>>| {
+        Real::ceil(extent / cell_size)
+            .to_usize()
+            .unwrap_or(1)
+            .max(1)
+            .min(resolution)
+    };
+    let dimensions = [count(ex), count(ey), count(ez)];
+    let [nx, ny, nz] = dimensions;
+    let mut grid = VoxelGrid {
+        origin: aabb.origin,
+        cell_size,
+        dimensions,
+        cells: vec![false; nx * ny * nz],
+    };
+    match kind {
+        VoxelKind::Solid => {
+            for z in 0..nz {
+                for y in 0..ny {
+                    for x in 0..nx {
+                        let center = grid.cell_center([x, y, z]);
+                        grid.cells[x + (nx * (y + (ny * z)))] = graph.contains_point(&center);
+                    }
+                }
+            }
+        }
+        VoxelKind::Surface => {
+            let (ox, oy, oz) = aabb.origin.into_xyz();
+            let origin = [ox, oy, oz];
+            // Gets the range of cells along an axis that overlap the given
+            // interval.
+            let range = |axis: usize, min, max| {
+                let index = |value: Scalar<VertexPosition<G>>| {
+                    Real::floor((value - origin[axis]) / cell_size)
+                        .to_usize()
+                        .unwrap_or(0)
+                        .min(dimensions[axis] - 1)
+                };
+                index(min)..=index(max)
+            };
+            for face in graph.faces() {
+                let positions = face
+                    .adjacent_vertices()
+                    .map(|vertex| vertex.position().into_xyz())
+                    .collect::<SmallVec<[_; 4]>>();
+                for index in 1..positions.len().saturating_sub(1) {
+                    let triangle = [positions[0], positions[index], positions[index + 1]];
+                    let (x, y, z) = triangle[0];
+                    let (mut min, mut max) = ([x, y, z], [x, y, z]);
+                    for &(x, y, z) in &triangle[1..] {
+                        for (axis, value) in [x, y, z].iter().enumerate() {
+                            if *value < min[axis] {
+                                min[axis] = *value;
+                            }
+                            if *value > max[axis] {
+                                max[axis] = *value;
+                            }
+                        }
+                    }
+                    for z in range(2, min[2], max[2]) {
+                        for y in range(1, min[1], max[1]) {
+                            for x in range(0, min[0], max[0]) {
+                                let (cx, cy, cz) = grid.cell_center([x, y, z]).into_xyz();
+                                let triangle = [
+                                    (triangle[0].0 - cx, triangle[0].1 - cy, triangle[0].2 - cz),
+                                    (triangle[1].0 - cx, triangle[1].1 - cy, triangle[1].2 - cz),
+                                    (triangle[2].0 - cx, triangle[2].1 - cy, triangle[2].2 - cz),
+                                ];
+                                if triangle_intersects_cube(triangle, cell_size / (one + one)) {
+                                    grid.cells[x + (nx * (y + (ny * z)))] = true;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    grid
+}
+
+// Determines if a triangle intersects an axis-aligned cube centered at the
+// origin with the given half-width via the separating axis theorem. See
+// Akenine-Möller (2001).
+fn triangle_intersects_cube<T>(triangle: [(T, T, T); 3], half: T) -> bool
+where
+    T: Real,
+{
+    let abs = |x: T| if x < T::zero() { -x } else { x };
+    let sub = |a: (T, T, T), b: (T, T, T)| (a.0 - b.0, a.1 - b.1, a.2 - b.2);
+    let dot = |a: (T, T, T), b: (T, T, T)| (a.0 * b.0) + (a.1 * b.1) + (a.2 * b.2);
+    let cross = |a: (T, T, T), b: (T, T, T)| {
+        (
+            (a.1 * b.2) - (a.2 * b.1),
+            (a.2 * b.0) - (a.0 * b.2),
+            (a.0 * b.1) - (a.1 * b.0),
+        )
+    };
+    // Determines if the given axis separates the triangle and cube.
+    let is_separating = |axis: (T, T, T)| {
+        let radius = half * (abs(axis.0) + abs(axis.1) + abs(axis.2));
+        let projections = [
+            dot(axis, triangle[0]),
+            dot(axis, triangle[1]),
+            dot(axis, triangle[2]),
+        ];
+        let (min, max) = projections.iter().skip(1).fold(
+            (projections[0], projections[0]),
+            |(min, max), projection| {
+                (
+                    if *projection < min { *projection } else { min },
+                    if *projection > max { *projection } else { max },
+                )
+            },
+        );
+        min > radius || max < -radius
+    };
+    let (zero, one) = (T::zero(), T::one());
+    let basis = [(one, zero, zero), (zero, one, zero), (zero, zero, one)];
+    let edges = [
+        sub(triangle[1], triangle[0]),
+        sub(triangle[2], triangle[1]),
+        sub(triangle[0], triangle[2]),
+    ];
+    if basis.iter().any(|axis| is_separating(*axis)) {
+        return false;
+    }
+    if is_separating(cross(edges[0], edges[1])) {
+        return false;
+    }
+    !edges
+        .iter()
+        .any(|edge| basis.iter().any(|axis| is_separating(cross(*edge, *axis))))
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::{MeshGraph, VoxelKind};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn voxelize() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        // The centers of all cells are inside of the cube.
+        let grid = graph.voxelize(4, VoxelKind::Solid);
+        assert_eq!([4, 4, 4], grid.dimensions);
+        assert_eq!(R64::from_inner(0.25), grid.cell_size);
+        assert_eq!(64, grid.occupied_count());
+
+        // Only the interior cells do not intersect the faces of the cube.
+        let grid = graph.voxelize(4, VoxelKind::Surface);
+        assert_eq!(56, grid.occupied_count());
+        assert_eq!(Some(true), grid.get([0, 1, 2]));
+        assert_eq!(Some(false), grid.get([1, 2, 1]));
+        assert_eq!(None, grid.get([4, 0, 0]));
+
+        let graph: MeshGraph<E3> = UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+        let grid = graph.voxelize(6, VoxelKind::Solid);
+        assert_eq!(Some(true), grid.get([3, 3, 3]));
+        assert_eq!(Some(false), grid.get([0, 0, 0]));
+        assert!(graph.contains_point(&grid.cell_center([2, 2, 2])));
+    }
+}
@@ -36,6 +36,12 @@
 //! (using a [`HashIndexer`] by default). A specific [`Indexer`] can be
 //! configured using the [`CollectWithIndexer`] trait.
 //!
+//! When only positional data is needed, polytopes in the [`primitive`] module
+//! can produce raw buffers without an [`Indexer`] via
+//! [`Generator::vertices_with_position`] and
+//! [`Generator::indices_for_position`]. These functions use the intrinsic
+//! indexing of the polytope, so no vertex data is hashed or compared.
+//!
 //! # Examples
 //!
 //! Indexing data for a cube to create raw buffers and a [`MeshBuffer`]:
@@ -70,6 +76,8 @@
 //! [`CollectWithIndexer`]: crate::index::CollectWithIndexer
 //! [`Flat`]: crate::index::Flat
 //! [`FromIndexer`]: crate::index::FromIndexer
+//! [`Generator::indices_for_position`]: crate::primitive::generate::Generator::indices_for_position
+//! [`Generator::vertices_with_position`]: crate::primitive::generate::Generator::vertices_with_position
 //! [`HashIndexer`]: crate::index::HashIndexer
 //! [`Indexer`]: crate::index::Indexer
 //! [`IndexVertices`]: crate::index::IndexVertices
@@ -84,14 +92,14 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::marker::PhantomData;
 use theon::adjunct::Map;
-use typenum::{NonZero, U3, U4};
+use typenum::{NonZero, U2, U3, U4};
 
 use crate::primitive::decompose::IntoVertices;
 use crate::primitive::Topological;
 use crate::{Monomorphic, StaticArity};
 
-pub(in crate) type BufferOf<R> = Vec<<R as Grouping>::Group>;
-pub(in crate) type IndexOf<R> = <BufferOf<R> as IndexBuffer<R>>::Index;
+pub(crate) type BufferOf<R> = Vec<<R as Grouping>::Group>;
+pub(crate) type IndexOf<R> = <BufferOf<R> as IndexBuffer<R>>::Index;
 
 // Note that it isn't possible for `IndexBuffer` types to implement
 // `DynamicArity`, because they are typically parameterized by `R` (see
@@ -238,6 +246,13 @@ where
     const ARITY: Self::Static = A::USIZE;
 }
 
+/// Alias for a flat index buffer of line segments.
+///
+/// Each pair of indices forms a line segment. See
+/// [`MeshGraph::to_line_buffer`].
+///
+/// [`MeshGraph::to_line_buffer`]: crate::graph::MeshGraph::to_line_buffer
+pub type Flat2<N = usize> = Flat<U2, N>;
 /// Alias for a flat and triangular index buffer.
 pub type Flat3<N = usize> = Flat<U3, N>;
 /// Alias for a flat and quadrilateral index buffer.
@@ -42,4 +42,5 @@ pub mod cgmath;
 pub mod glam;
 pub mod mint;
 pub mod nalgebra;
+pub mod petgraph;
 pub mod ultraviolet;
@@ -0,0 +1,70 @@
+#![cfg(feature = "graph-petgraph")]
+
+use ::petgraph::graph::{NodeIndex, UnGraph};
+use std::collections::HashMap;
+
+use crate::graph::{ClosedView, EdgeKey, FaceKey, GraphData, MeshGraph};
+
+#[doc(hidden)]
+pub use ::petgraph::*;
+
+impl<G> MeshGraph<G>
+where
+    G: GraphData,
+{
+    /// Gets the face adjacency of the graph as a [`petgraph`] graph.
+    ///
+    /// Each face is a node with its key as a weight and each edge that is
+    /// shared by two faces is an edge with its key as a weight. That is, the
+    /// returned graph is the dual of the graph's faces. Boundary edges are
+    /// not included. Nodes are inserted in the order in which faces are
+    /// yielded by [`MeshGraph::faces`].
+    ///
+    /// # Examples
+    ///
+    /// Coloring the faces of a cube such that no adjacent faces share a color:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::integration::petgraph::visit::EdgeRef;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let dual = graph.face_adjacency_petgraph();
+    ///
+    /// assert_eq!(6, dual.node_count());
+    /// assert_eq!(12, dual.edge_count());
+    /// for edge in dual.edge_references() {
+    ///     let (a, b) = (dual[edge.source()], dual[edge.target()]);
+    ///     assert!(graph.edge(*edge.weight()).unwrap().faces().all(|face| {
+    ///         face.key() == a || face.key() == b
+    ///     }));
+    /// }
+    /// ```
+    ///
+    /// [`MeshGraph::faces`]: crate::graph::MeshGraph::faces
+    /// [`petgraph`]: https://crates.io/crates/petgraph
+    pub fn face_adjacency_petgraph(&self) -> UnGraph<FaceKey, EdgeKey> {
+        let mut graph = UnGraph::with_capacity(self.face_count(), self.edge_count());
+        let nodes = self
+            .faces()
+            .map(|face| (face.key(), graph.add_node(face.key())))
+            .collect::<HashMap<FaceKey, NodeIndex>>();
+        for edge in self.edges() {
+            if let (Some(left), Some(right)) = (edge.left_face(), edge.right_face()) {
+                graph.add_edge(nodes[&left.key()], nodes[&right.key()], edge.key());
+            }
+        }
+        graph
+    }
+}
@@ -65,7 +65,7 @@ pub mod prelude {
     //! [`primitive`]: crate::primitive
 
     pub use crate::buffer::{
-        FromRawBuffers as _, FromRawBuffersWithArity as _, IntoFlatIndex as _,
+        FromMeshBuffer as _, FromRawBuffers as _, FromRawBuffersWithArity as _, IntoFlatIndex as _,
         IntoStructuredIndex as _,
     };
     pub use crate::builder::{FacetBuilder as _, MeshBuilder as _, SurfaceBuilder as _};
@@ -73,12 +73,13 @@ pub mod prelude {
     pub use crate::graph::{ClosedView as _, Rebind as _, Selector};
     pub use crate::index::{CollectWithIndexer as _, IndexVertices as _};
     pub use crate::primitive::decompose::{
-        Edges as _, IntoEdges as _, IntoSubdivisions as _, IntoTetrahedrons as _, IntoTrigons as _,
-        IntoVertices as _, Subdivide as _, Tetrahedrons as _, Triangulate as _, Vertices as _,
+        Edges as _, IntoEdges as _, IntoSubdivisions as _, IntoTessellations as _,
+        IntoTetrahedrons as _, IntoTrigons as _, IntoVertices as _, Subdivide as _,
+        Tessellate as _, Tetrahedrons as _, Triangulate as _, Vertices as _,
     };
     pub use crate::primitive::generate::Generator as _;
     pub use crate::primitive::{
-        IntoPolygons as _, MapVertices as _, Polygonal as _, Topological as _,
+        IntoPolygons as _, MapVertices as _, Polygonal as _, Topological as _, WithFaceData as _,
     };
     pub use crate::DynamicArity as _;
     pub use crate::IteratorExt as _;
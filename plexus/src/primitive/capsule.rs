@@ -0,0 +1,349 @@
+//! Capsule primitives.
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of a capsule.
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::index::HashIndexer;
+//! use plexus::prelude::*;
+//! use plexus::primitive::capsule::Capsule;
+//! use plexus::primitive::generate::Position;
+//!
+//! type E3 = Point3<N64>;
+//!
+//! let mut graph = Capsule::new(16, 4)
+//!     .polygons::<Position<E3>>()
+//!     .collect_with_indexer::<MeshGraph<E3>, _>(HashIndexer::default())
+//!     .unwrap();
+//! ```
+
+use decorum::Real;
+use num::{NumCast, One, ToPrimitive, Zero};
+use std::cmp;
+use theon::adjunct::Map;
+use theon::query::Unit;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position, Winding,
+};
+use crate::primitive::{BoundedPolygon, Tetragon, Trigon};
+
+#[derive(Clone, Copy)]
+pub struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    radius: Scalar<S>,
+    length: Scalar<S>,
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    /// Creates bounds with the given radius of the caps and length of the
+    /// cylinder between them.
+    ///
+    /// The total length of the capsule along its axis is the length of the
+    /// cylinder plus twice the radius.
+    pub fn new(radius: Scalar<S>, length: Scalar<S>) -> Self {
+        Bounds { radius, length }
+    }
+
+    pub fn unit_radius() -> Self {
+        Self::new(One::one(), One::one())
+    }
+}
+
+impl<S> Default for Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Self::unit_radius()
+    }
+}
+
+/// Capsule.
+///
+/// A capsule is a cylinder along the $z$-axis with hemispherical caps. Each
+/// cap is formed from rings of polygons like a [$uv$-sphere][`UvSphere`] and
+/// the cylinder is formed from a single ring of quadrilaterals between the
+/// caps.
+///
+/// [`UvSphere`]: crate::primitive::sphere::UvSphere
+#[derive(Clone, Copy)]
+pub struct Capsule {
+    nu: usize, // Meridians.
+    nc: usize, // Parallels per cap.
+    winding: Winding,
+}
+
+impl Capsule {
+    pub fn new(radial_segments: usize, cap_rings: usize) -> Self {
+        Capsule {
+            nu: cmp::max(3, radial_segments),
+            nc: cmp::max(1, cap_rings),
+            winding: Winding::default(),
+        }
+    }
+
+    /// Sets the winding of the generated polygons.
+    ///
+    /// See [`Winding`].
+    ///
+    /// [`Winding`]: crate::primitive::generate::Winding
+    pub fn with_winding(self, winding: Winding) -> Self {
+        Capsule { winding, ..self }
+    }
+
+    // Parallels of polygons. The cylinder contributes one parallel between the
+    // caps.
+    fn nv(&self) -> usize {
+        (2 * self.nc) + 1
+    }
+
+    fn vertex_with_position_from<S>(
+        &self,
+        radius: Scalar<S>,
+        length: Scalar<S>,
+        u: usize,
+        v: usize,
+    ) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let one = Scalar::<S>::one();
+        let pi = Real::PI;
+        let half = pi / (one + one);
+        let offset = length / (one + one);
+        // Parallels up to and including `nc` form the upper cap. The remaining
+        // parallels form the lower cap.
+        let (v, z) = if v <= self.nc {
+            (
+                (into_scalar::<_, S>(v) / into_scalar::<_, S>(self.nc)) * half,
+                offset,
+            )
+        }
+        else {
+            (
+                half + ((into_scalar::<_, S>(v - self.nc - 1) / into_scalar::<_, S>(self.nc))
+                    * half),
+                -offset,
+            )
+        };
+        let u = (into_scalar::<_, S>(u) / into_scalar::<_, S>(self.nu)) * pi * (one + one);
+        S::from_xyz(
+            radius * u.cos() * v.sin(),
+            radius * u.sin() * v.sin(),
+            (radius * v.cos()) + z,
+        )
+    }
+
+    fn map_vertex_index(&self, index: usize) -> (usize, usize) {
+        if index == 0 {
+            (0, 0)
+        }
+        else if index == ((self.nv() - 1) * self.nu) + 1 {
+            (0, self.nv())
+        }
+        else {
+            let index = index - 1;
+            (index % self.nu, (index / self.nu) + 1)
+        }
+    }
+
+    fn index_for_position(&self, u: usize, v: usize) -> usize {
+        if v == 0 {
+            0
+        }
+        else if v == self.nv() {
+            ((self.nv() - 1) * self.nu) + 1
+        }
+        else {
+            ((v - 1) * self.nu) + (u % self.nu) + 1
+        }
+    }
+
+    fn map_polygon_index(&self, index: usize) -> (usize, usize) {
+        (index % self.nu, index / self.nu)
+    }
+}
+
+impl Default for Capsule {
+    fn default() -> Self {
+        Capsule::new(16, 8)
+    }
+}
+
+impl PolygonGenerator for Capsule {
+    fn polygon_count(&self) -> usize {
+        self.nu * self.nv()
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        (self.nv() - 1) * self.nu + 2
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        // Normals are the positions of a unit capsule with no cylinder.
+        let (u, v) = self.map_vertex_index(index);
+        let position = self.vertex_with_position_from::<S>(One::one(), Zero::zero(), u, v);
+        self.winding.orient::<S>(
+            Unit::try_from_inner(position.into_coordinates()).expect("non-zero vector"),
+        )
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Normal<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<Normal<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Normal<S>> for Capsule {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        (self.nv() - 1) * self.nu + 2
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let (u, v) = self.map_vertex_index(index);
+        self.vertex_with_position_from::<S>(state.radius, state.length, u, v)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Capsule
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        // Positions are always computed from the normalized `(u, v)` of each
+        // vertex index, so shared vertices are bitwise identical. See
+        // `UvSphere`.
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<Position<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Capsule {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let (u, v) = self.map_polygon_index(index);
+        let (p, q) = (u + 1, v + 1);
+
+        let low = self.index_for_position(u, v);
+        let high = self.index_for_position(p, q);
+        let polygon: BoundedPolygon<_> = if v == 0 {
+            Trigon::new(low, self.index_for_position(u, q), high).into()
+        }
+        else if v == self.nv() - 1 {
+            Trigon::new(high, self.index_for_position(p, v), low).into()
+        }
+        else {
+            Tetragon::new(
+                low,
+                self.index_for_position(u, q),
+                high,
+                self.index_for_position(p, v),
+            )
+            .into()
+        };
+        self.winding.wind(polygon)
+    }
+}
+
+impl Generator for Capsule {}
+
+fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::N64;
+    use nalgebra::Point3;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::capsule::Capsule;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<N64>;
+
+    #[test]
+    fn vertex_count() {
+        assert_eq!(
+            18,
+            Capsule::new(4, 2)
+                .vertices::<Position<E3>>() // 18 conjoint vertices.
+                .count()
+        );
+    }
+
+    #[test]
+    fn collect_closed_graph() {
+        let graph: MeshGraph<E3> = Capsule::new(8, 3).polygons::<Position<E3>>().collect();
+
+        assert_eq!(50, graph.vertex_count());
+        assert_eq!(56, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+    }
+}
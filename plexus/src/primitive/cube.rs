@@ -19,7 +19,9 @@
 //!     .collect::<MeshGraph<Point3<N64>>>();
 //! ```
 
-use num::One;
+use decorum::Real;
+use num::{NumCast, One, ToPrimitive, Zero};
+use std::cmp;
 use theon::adjunct::{Converged, Map};
 use theon::query::Unit;
 use theon::space::{Basis, EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
@@ -27,7 +29,7 @@ use typenum::U3;
 
 use crate::primitive::generate::{
     Attribute, AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
-    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position, Winding,
 };
 use crate::primitive::Tetragon;
 
@@ -105,11 +107,24 @@ where
 }
 
 #[derive(Clone, Copy)]
-pub struct Cube;
+pub struct Cube {
+    winding: Winding,
+}
 
 impl Cube {
     pub fn new() -> Self {
-        Cube
+        Cube {
+            winding: Winding::default(),
+        }
+    }
+
+    /// Sets the winding of the generated polygons.
+    ///
+    /// See [`Winding`].
+    ///
+    /// [`Winding`]: crate::primitive::generate::Winding
+    pub fn with_winding(self, winding: Winding) -> Self {
+        Cube { winding, ..self }
     }
 }
 
@@ -143,7 +158,9 @@ where
     }
 
     fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
-        AttributeVertexGenerator::<Plane>::vertex_from(self, &(), index).normal::<Vector<S>>()
+        self.winding.orient::<S>(
+            AttributeVertexGenerator::<Plane>::vertex_from(self, &(), index).normal::<Vector<S>>(),
+        )
     }
 }
 
@@ -224,7 +241,7 @@ impl<S> IndexingPolygonGenerator<Position<S>> for Cube {
     type Output = Tetragon<usize>;
 
     fn indexing_polygon(&self, index: usize) -> Self::Output {
-        match index {
+        self.winding.wind(match index {
             0 => Tetragon::new(5, 7, 3, 1), // front
             1 => Tetragon::new(6, 7, 5, 4), // right
             2 => Tetragon::new(3, 7, 6, 2), // top
@@ -232,7 +249,7 @@ impl<S> IndexingPolygonGenerator<Position<S>> for Cube {
             4 => Tetragon::new(4, 5, 1, 0), // bottom
             5 => Tetragon::new(0, 2, 6, 4), // back
             _ => panic!(),
-        }
+        })
     }
 }
 
@@ -286,3 +303,286 @@ impl IndexingPolygonGenerator<Plane> for Cube {
 }
 
 impl Generator for Cube {}
+
+/// Cube with rounded edges and corners.
+///
+/// Each face of the cube is divided into a grid of quadrilaterals with the
+/// given number of segments along each axis. Vertices are then projected onto
+/// a rounded surface: the radius is given as a fraction of the half-width of
+/// the cube, where `0.0` yields a (subdivided) cube and `1.0` yields a sphere.
+/// Vertices along the seams between faces are shared.
+#[derive(Clone, Copy)]
+pub struct RoundedCube {
+    n: usize,
+    radius: f64,
+    winding: Winding,
+}
+
+impl RoundedCube {
+    pub fn new(segments: usize, radius: f64) -> Self {
+        RoundedCube {
+            n: cmp::max(1, segments),
+            radius: radius.max(0.0).min(1.0),
+            winding: Winding::default(),
+        }
+    }
+
+    /// Sets the winding of the generated polygons.
+    ///
+    /// See [`Winding`].
+    ///
+    /// [`Winding`]: crate::primitive::generate::Winding
+    pub fn with_winding(self, winding: Winding) -> Self {
+        RoundedCube { winding, ..self }
+    }
+
+    fn vertex_with_position_from<S>(&self, state: &Bounds<S>, [i, j, k]: [usize; 3]) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let width = state.upper - state.lower;
+        let half = width / (Scalar::<S>::one() + One::one());
+        let center = state.lower + half;
+        let radius = half * into_scalar::<_, S>(self.radius);
+        let inner = half - radius;
+        let clamp = |x: Scalar<S>| {
+            if x > inner {
+                inner
+            }
+            else if x < -inner {
+                -inner
+            }
+            else {
+                x
+            }
+        };
+        // Compute positions relative to the center of the cube and project
+        // them onto a sphere about the nearest point of the inner box.
+        let n = into_scalar::<_, S>(self.n);
+        let coordinate =
+            |index: usize| (state.lower - center) + ((into_scalar::<_, S>(index) / n) * width);
+        let (x, y, z) = (coordinate(i), coordinate(j), coordinate(k));
+        let (p, q, r) = (clamp(x), clamp(y), clamp(z));
+        let (dx, dy, dz) = (x - p, y - q, z - r);
+        let distance = ((dx * dx) + (dy * dy) + (dz * dz)).sqrt();
+        if distance > Zero::zero() {
+            let scale = radius / distance;
+            S::from_xyz(
+                center + p + (dx * scale),
+                center + q + (dy * scale),
+                center + r + (dz * scale),
+            )
+        }
+        else {
+            S::from_xyz(center + x, center + y, center + z)
+        }
+    }
+
+    // Maps a point on the surface of the `(n + 1)^3` lattice to its index.
+    // Points are ordered lexicographically by `(i, j, k)`.
+    fn index_for_lattice(&self, [i, j, k]: [usize; 3]) -> usize {
+        let n = self.n;
+        let full = (n + 1) * (n + 1);
+        let ring = 4 * n;
+        if i == 0 {
+            (j * (n + 1)) + k
+        }
+        else if i == n {
+            full + ((n - 1) * ring) + (j * (n + 1)) + k
+        }
+        else {
+            let offset = full + ((i - 1) * ring);
+            if j == 0 {
+                offset + k
+            }
+            else if j == n {
+                offset + (n + 1) + ((n - 1) * 2) + k
+            }
+            else {
+                offset + (n + 1) + ((j - 1) * 2) + if k == 0 { 0 } else { 1 }
+            }
+        }
+    }
+
+    fn lattice_from_index(&self, index: usize) -> [usize; 3] {
+        let n = self.n;
+        let full = (n + 1) * (n + 1);
+        let ring = 4 * n;
+        if index < full {
+            [0, index / (n + 1), index % (n + 1)]
+        }
+        else if index < full + ((n - 1) * ring) {
+            let index = index - full;
+            let i = (index / ring) + 1;
+            let index = index % ring;
+            if index < n + 1 {
+                [i, 0, index]
+            }
+            else if index >= ring - (n + 1) {
+                [i, n, index - (ring - (n + 1))]
+            }
+            else {
+                let index = index - (n + 1);
+                [i, (index / 2) + 1, if index % 2 == 0 { 0 } else { n }]
+            }
+        }
+        else {
+            let index = index - full - ((n - 1) * ring);
+            [n, index / (n + 1), index % (n + 1)]
+        }
+    }
+
+    // Maps a point `(a, b)` in the grid of a face to the lattice. The axes of
+    // each face are chosen such that polygons are wound outward.
+    fn lattice_for_face(&self, face: usize, a: usize, b: usize) -> [usize; 3] {
+        let n = self.n;
+        match face {
+            0 => [a, b, n], // front
+            1 => [n, a, b], // right
+            2 => [b, n, a], // top
+            3 => [0, b, a], // left
+            4 => [a, 0, b], // bottom
+            5 => [b, a, 0], // back
+            _ => panic!(),
+        }
+    }
+}
+
+impl Default for RoundedCube {
+    fn default() -> Self {
+        RoundedCube::new(4, 0.25)
+    }
+}
+
+impl PolygonGenerator for RoundedCube {
+    fn polygon_count(&self) -> usize {
+        6 * self.n * self.n
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for RoundedCube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for RoundedCube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        let (n, m) = (self.n + 1, self.n - 1);
+        (n * n * n) - (m * m * m)
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_position_from(state, self.lattice_from_index(index))
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for RoundedCube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Tetragon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<Position<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for RoundedCube {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        assert!(index < self.polygon_count());
+        let n = self.n;
+        let (face, index) = (index / (n * n), index % (n * n));
+        let (a, b) = (index % n, index / n);
+        let index = |a, b| self.index_for_lattice(self.lattice_for_face(face, a, b));
+        self.winding.wind(Tetragon::new(
+            index(a, b),
+            index(a + 1, b),
+            index(a + 1, b + 1),
+            index(a, b + 1),
+        ))
+    }
+}
+
+impl Generator for RoundedCube {}
+
+fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::N64;
+    use nalgebra::Point3;
+    use std::collections::BTreeSet;
+    use std::iter::FromIterator;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cube::{Cube, RoundedCube};
+    use crate::primitive::generate::{Position, Winding};
+
+    type E3 = Point3<N64>;
+
+    #[test]
+    fn rounded_cube_vertex_count() {
+        assert_eq!(
+            26,
+            RoundedCube::new(2, 0.5).vertices::<Position<E3>>().count()
+        );
+        // Every lattice index is reachable from the polygons.
+        assert_eq!(
+            98,
+            BTreeSet::from_iter(
+                RoundedCube::new(4, 0.5)
+                    .indexing_polygons::<Position>()
+                    .vertices()
+            )
+            .len()
+        );
+    }
+
+    #[test]
+    fn rounded_cube_collect_closed_graph() {
+        let graph: MeshGraph<E3> = RoundedCube::new(3, 0.5)
+            .polygons::<Position<E3>>()
+            .collect();
+
+        assert_eq!(56, graph.vertex_count());
+        assert_eq!(54, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+    }
+
+    #[test]
+    fn inward_winding_reverses_polygons() {
+        let outward = Cube::new().indexing_polygons::<Position>();
+        let inward = Cube::new()
+            .with_winding(Winding::Inward)
+            .indexing_polygons::<Position>();
+        for (outward, inward) in outward.zip(inward) {
+            let mut outward = outward.into_iter().collect::<Vec<_>>();
+            outward.reverse();
+            assert_eq!(outward, inward.into_iter().collect::<Vec<_>>());
+        }
+
+        let graph: MeshGraph<E3> = Cube::new()
+            .with_winding(Winding::Inward)
+            .polygons::<Position<E3>>()
+            .collect();
+        assert_eq!(6, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+    }
+}
@@ -200,6 +200,21 @@ pub trait IntoSubdivisions: Polygonal {
     fn into_subdivisions(self) -> Self::Output;
 }
 
+/// Splits a polygon into four polygons of the same arity at the midpoints of
+/// its edges.
+///
+/// Unlike [`IntoSubdivisions`], which bisects [`Trigon`]s, tessellation
+/// uniformly refines both [`Trigon`]s and [`Tetragon`]s.
+///
+/// [`IntoSubdivisions`]: crate::primitive::decompose::IntoSubdivisions
+/// [`Tetragon`]: crate::primitive::Tetragon
+/// [`Trigon`]: crate::primitive::Trigon
+pub trait IntoTessellations: Polygonal {
+    type Output: IntoIterator<Item = Self>;
+
+    fn into_tessellations(self) -> Self::Output;
+}
+
 pub trait IntoTetrahedrons: Polygonal {
     fn into_tetrahedrons(self) -> ArrayVec<[Trigon<Self::Vertex>; 4]>;
 }
@@ -342,6 +357,37 @@ where
     }
 }
 
+impl<T> IntoTessellations for Trigon<T>
+where
+    T: Clone + Interpolate<Output = T>,
+{
+    type Output = ArrayVec<[Trigon<Self::Vertex>; 4]>;
+
+    fn into_tessellations(self) -> Self::Output {
+        let [a, b, c] = self.into_array();
+        let ab = a.clone().midpoint(b.clone());
+        let bc = b.clone().midpoint(c.clone());
+        let ca = c.clone().midpoint(a.clone());
+        ArrayVec::from([
+            Trigon::new(a, ab.clone(), ca.clone()),
+            Trigon::new(ab.clone(), b, bc.clone()),
+            Trigon::new(ca.clone(), bc.clone(), c),
+            Trigon::new(ab, bc, ca),
+        ])
+    }
+}
+
+impl<T> IntoTessellations for Tetragon<T>
+where
+    T: Clone + Interpolate<Output = T>,
+{
+    type Output = ArrayVec<[Tetragon<Self::Vertex>; 4]>;
+
+    fn into_tessellations(self) -> Self::Output {
+        self.into_subdivisions()
+    }
+}
+
 impl<T> IntoTetrahedrons for Tetragon<T>
 where
     T: Clone + Interpolate<Output = T>,
@@ -380,6 +426,28 @@ where
     }
 }
 
+impl<T> IntoTessellations for BoundedPolygon<T>
+where
+    T: Clone + Interpolate<Output = T>,
+{
+    type Output = Vec<Self>;
+
+    fn into_tessellations(self) -> Self::Output {
+        match self {
+            BoundedPolygon::N3(trigon) => trigon
+                .into_tessellations()
+                .into_iter()
+                .map(|trigon| trigon.into())
+                .collect(),
+            BoundedPolygon::N4(tetragon) => tetragon
+                .into_tessellations()
+                .into_iter()
+                .map(|tetragon| tetragon.into())
+                .collect(),
+        }
+    }
+}
+
 pub trait Vertices<P>: Sized
 where
     P: IntoVertices,
@@ -449,6 +517,23 @@ where
     }
 }
 
+pub trait Tessellate<P>: Sized
+where
+    P: IntoTessellations,
+{
+    fn tessellate(self) -> Decompose<Self, P, P, P::Output>;
+}
+
+impl<I, P> Tessellate<P> for I
+where
+    I: Iterator<Item = P>,
+    P: IntoTessellations,
+{
+    fn tessellate(self) -> Decompose<Self, P, P, P::Output> {
+        Decompose::new(self, P::into_tessellations)
+    }
+}
+
 pub trait Tetrahedrons<T>: Sized {
     #[allow(clippy::type_complexity)]
     fn tetrahedrons(self) -> Decompose<Self, Tetragon<T>, Trigon<T>, ArrayVec<[Trigon<T>; 4]>>;
@@ -477,3 +562,80 @@ where
     }
     ngons
 }
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::{BoundedPolygon, Trigon};
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn vertices_and_edges() {
+        assert_eq!(
+            24,
+            Cube::new().polygons::<Position<E3>>().vertices().count()
+        );
+        assert_eq!(24, Cube::new().polygons::<Position<E3>>().edges().count());
+    }
+
+    #[test]
+    fn triangulate_and_subdivide() {
+        assert_eq!(
+            12,
+            Cube::new().polygons::<Position<E3>>().triangulate().count()
+        );
+        assert_eq!(
+            24,
+            Cube::new().polygons::<Position<E3>>().subdivide().count()
+        );
+        assert_eq!(
+            96,
+            Cube::new()
+                .polygons::<Position<E3>>()
+                .subdivide()
+                .remap(1)
+                .count()
+        );
+    }
+
+    #[test]
+    fn tessellate() {
+        let trigons = vec![Trigon::new(
+            E3::new(0.0, 0.0, 0.0),
+            E3::new(2.0, 0.0, 0.0),
+            E3::new(0.0, 2.0, 0.0),
+        )];
+        let trigons = trigons.into_iter().tessellate().collect::<Vec<_>>();
+
+        assert_eq!(4, trigons.len());
+        // The central trigon is formed from the midpoints of the edges.
+        assert_eq!(
+            [
+                E3::new(1.0, 0.0, 0.0),
+                E3::new(1.0, 1.0, 0.0),
+                E3::new(0.0, 1.0, 0.0),
+            ],
+            trigons[3].clone().into_array()
+        );
+
+        let polygons = vec![
+            BoundedPolygon::from([
+                E3::new(0.0, 0.0, 0.0),
+                E3::new(2.0, 0.0, 0.0),
+                E3::new(0.0, 2.0, 0.0),
+            ]),
+            BoundedPolygon::from([
+                E3::new(0.0, 0.0, 0.0),
+                E3::new(2.0, 0.0, 0.0),
+                E3::new(2.0, 2.0, 0.0),
+                E3::new(0.0, 2.0, 0.0),
+            ]),
+        ];
+        assert_eq!(8, polygons.into_iter().tessellate().count());
+    }
+}
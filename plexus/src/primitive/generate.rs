@@ -44,10 +44,16 @@
 //! [`Generator`]: crate::primitive::generate::Generator
 //! [`UvSphere`]: crate::primitive::sphere::UvSphere
 
+use num::NumCast;
 use std::marker::PhantomData;
 use std::ops::Range;
+use theon::adjunct::Map;
+use theon::query::Unit;
+use theon::space::{EuclideanSpace, Vector};
 
-use crate::primitive::Polygonal;
+use crate::index::{Grouping, IndexBuffer, Push};
+use crate::primitive::decompose::IntoTrigons;
+use crate::primitive::{Polygonal, Trigon};
 
 /// Geometric attribute.
 ///
@@ -135,6 +141,70 @@ pub struct Position<S = ()> {
 
 impl<S> Attribute for Position<S> {}
 
+/// Winding of the polygons of a polytope.
+///
+/// By default, generators wind polygons such that their normals face outward
+/// from the polytope. `Inward` reverses the order of the vertices in each
+/// polygon and negates surface normals, which is useful for geometry that is
+/// viewed from within, such as a skybox.
+///
+/// # Examples
+///
+/// Generating an inward-facing cube:
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::graph::MeshGraph;
+/// use plexus::prelude::*;
+/// use plexus::primitive::cube::Cube;
+/// use plexus::primitive::generate::{Position, Winding};
+///
+/// type E3 = Point3<R64>;
+///
+/// let graph: MeshGraph<E3> = Cube::new()
+///     .with_winding(Winding::Inward)
+///     .polygons::<Position<E3>>()
+///     .collect();
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Winding {
+    Outward,
+    Inward,
+}
+
+impl Winding {
+    pub(in crate::primitive) fn wind<P>(self, mut polygon: P) -> P
+    where
+        P: Polygonal,
+    {
+        if let Winding::Inward = self {
+            polygon.as_mut().reverse();
+        }
+        polygon
+    }
+
+    pub(in crate::primitive) fn orient<S>(self, normal: Unit<Vector<S>>) -> Unit<Vector<S>>
+    where
+        S: EuclideanSpace,
+    {
+        match self {
+            Winding::Outward => normal,
+            Winding::Inward => Unit::try_from_inner(-normal.into_inner()).expect("non-zero vector"),
+        }
+    }
+}
+
+impl Default for Winding {
+    fn default() -> Self {
+        Winding::Outward
+    }
+}
+
 /// Iterator that generates topology and geometric attributes.
 pub struct Generate<'a, G, S, P>
 where
@@ -363,4 +433,75 @@ pub trait Generator: Sized {
             generator.indexing_polygon(index)
         })
     }
+
+    /// Gets a vertex buffer of the unique positions of the polytope.
+    ///
+    /// This buffer is indexed by [`indices_for_position`].
+    ///
+    /// [`indices_for_position`]: crate::primitive::generate::Generator::indices_for_position
+    fn vertices_with_position<S>(&self) -> Vec<S>
+    where
+        Self: AttributeVertexGenerator<Position<S>, Output = S>,
+    {
+        self.vertices::<Position<S>>().collect()
+    }
+
+    /// Gets a triangular index buffer into the unique positions of the
+    /// polytope.
+    ///
+    /// Indices are taken directly from the [indexing
+    /// polygons][`indexing_polygons`] of the polytope, which are triangulated.
+    /// Unlike an [`Indexer`], positions are never hashed or compared, so this
+    /// is both cheaper and exact. The grouping `R` must describe triangles,
+    /// such as [`Flat3`] or [`Trigon`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index cannot be represented by the index type of `R`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::index::Flat3;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let sphere = UvSphere::new(16, 16);
+    /// let buffer = MeshBuffer::<Flat3, E3>::from_raw_buffers(
+    ///     sphere.indices_for_position::<Flat3>(),
+    ///     sphere.vertices_with_position::<E3>(),
+    /// )
+    /// .unwrap();
+    /// ```
+    ///
+    /// [`Flat3`]: crate::index::Flat3
+    /// [`Indexer`]: crate::index::Indexer
+    /// [`indexing_polygons`]: crate::primitive::generate::Generator::indexing_polygons
+    /// [`Trigon`]: crate::primitive::Trigon
+    fn indices_for_position<R>(&self) -> Vec<R::Group>
+    where
+        Self: IndexingPolygonGenerator<Position>,
+        <Self as IndexingPolygonGenerator<Position>>::Output: IntoTrigons,
+        R: Grouping,
+        Vec<R::Group>: Push<R, Trigon<<Vec<R::Group> as IndexBuffer<R>>::Index>>,
+        <Vec<R::Group> as IndexBuffer<R>>::Index: NumCast,
+    {
+        let mut indices = Vec::new();
+        for polygon in self.indexing_polygons::<Position>() {
+            for trigon in polygon.into_trigons() {
+                Push::<R, _>::push(
+                    &mut indices,
+                    trigon.map(|index| NumCast::from(index).unwrap()),
+                );
+            }
+        }
+        indices
+    }
 }
@@ -80,9 +80,12 @@
 //! [`DynamicArity`]: crate::DynamicArity
 //! [`StaticArity`]: crate::StaticArity
 
+pub mod capsule;
 pub mod cube;
 pub mod decompose;
 pub mod generate;
+pub mod platonic;
+pub mod prism;
 pub mod sphere;
 
 use arrayvec::{Array, ArrayVec};
@@ -408,6 +411,94 @@ where
     }
 }
 
+/// Pairs polygons in an iterator with face data.
+///
+/// Collecting the resulting iterator of `(polygon, data)` pairs into a
+/// [`MeshGraph`] populates the data of the face inserted for each polygon.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::graph::{GraphData, MeshGraph};
+/// use plexus::prelude::*;
+/// use plexus::primitive::cube::Cube;
+/// use plexus::primitive::generate::Position;
+///
+/// #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+/// pub struct Tagged;
+///
+/// impl GraphData for Tagged {
+///     type Vertex = Point3<R64>;
+///     type Arc = ();
+///     type Edge = ();
+///     type Face = usize;
+/// }
+///
+/// let mut material = 0usize;
+/// let graph: MeshGraph<Tagged> = Cube::new()
+///     .polygons::<Position<Point3<R64>>>()
+///     .with_face_data(|_| {
+///         material += 1;
+///         material
+///     })
+///     .collect();
+/// assert!(graph.faces().all(|face| face.data > 0));
+/// ```
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub trait WithFaceData<P>: Sized
+where
+    P: Polygonal,
+{
+    fn with_face_data<T, F>(self, f: F) -> FaceDataMap<Self, F>
+    where
+        F: FnMut(&P) -> T;
+}
+
+impl<I, P> WithFaceData<P> for I
+where
+    I: Iterator<Item = P>,
+    P: Polygonal,
+{
+    fn with_face_data<T, F>(self, f: F) -> FaceDataMap<Self, F>
+    where
+        F: FnMut(&P) -> T,
+    {
+        FaceDataMap { input: self, f }
+    }
+}
+
+pub struct FaceDataMap<I, F> {
+    input: I,
+    f: F,
+}
+
+impl<I, F, T> Iterator for FaceDataMap<I, F>
+where
+    I: Iterator,
+    I::Item: Polygonal,
+    F: FnMut(&I::Item) -> T,
+{
+    type Item = (I::Item, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.input.next().map(|polygon| {
+            let data = (self.f)(&polygon);
+            (polygon, data)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.input.size_hint()
+    }
+}
+
 /// Monomorphic $n$-gon.
 ///
 /// `NGon` represents a polygonal structure as an array. Each array element
@@ -0,0 +1,414 @@
+//! Platonic solid primitives.
+//!
+//! This module provides the [tetrahedron][`Tetrahedron`],
+//! [octahedron][`Octahedron`], [dodecahedron][`Dodecahedron`], and
+//! [icosahedron][`Icosahedron`]. The remaining platonic solid is provided by
+//! [`Cube`]. Each solid is centered at the origin and its vertices lie on a
+//! sphere with the radius given by [`Bounds`].
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of an icosahedron:
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::platonic::Icosahedron;
+//!
+//! type E3 = Point3<N64>;
+//!
+//! let graph = Icosahedron::new()
+//!     .polygons::<Position<E3>>()
+//!     .collect::<MeshGraph<E3>>();
+//! ```
+//!
+//! [`Bounds`]: crate::primitive::platonic::Bounds
+//! [`Cube`]: crate::primitive::cube::Cube
+//! [`Dodecahedron`]: crate::primitive::platonic::Dodecahedron
+//! [`Icosahedron`]: crate::primitive::platonic::Icosahedron
+//! [`Octahedron`]: crate::primitive::platonic::Octahedron
+//! [`Tetrahedron`]: crate::primitive::platonic::Tetrahedron
+
+use num::{NumCast, One, ToPrimitive};
+use theon::adjunct::Map;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, PolygonGenerator, Position, Winding,
+};
+use crate::primitive::NGon;
+
+const PHI: f64 = 1.618_033_988_749_895;
+const INVERSE_PHI: f64 = PHI - 1.0;
+
+const TETRAHEDRON_POSITIONS: [[f64; 3]; 4] = [
+    [1.0, 1.0, 1.0],
+    [1.0, -1.0, -1.0],
+    [-1.0, 1.0, -1.0],
+    [-1.0, -1.0, 1.0],
+];
+const TETRAHEDRON_FACES: [[usize; 3]; 4] = [[2, 0, 1], [1, 0, 3], [3, 0, 2], [2, 1, 3]];
+
+const OCTAHEDRON_POSITIONS: [[f64; 3]; 6] = [
+    [1.0, 0.0, 0.0],
+    [-1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, -1.0, 0.0],
+    [0.0, 0.0, 1.0],
+    [0.0, 0.0, -1.0],
+];
+const OCTAHEDRON_FACES: [[usize; 3]; 8] = [
+    [4, 0, 2],
+    [2, 0, 5],
+    [3, 0, 4],
+    [5, 0, 3],
+    [2, 1, 4],
+    [5, 1, 2],
+    [4, 1, 3],
+    [3, 1, 5],
+];
+
+const DODECAHEDRON_POSITIONS: [[f64; 3]; 20] = [
+    [1.0, 1.0, 1.0],
+    [1.0, 1.0, -1.0],
+    [1.0, -1.0, 1.0],
+    [1.0, -1.0, -1.0],
+    [-1.0, 1.0, 1.0],
+    [-1.0, 1.0, -1.0],
+    [-1.0, -1.0, 1.0],
+    [-1.0, -1.0, -1.0],
+    [0.0, INVERSE_PHI, PHI],
+    [0.0, INVERSE_PHI, -PHI],
+    [0.0, -INVERSE_PHI, PHI],
+    [0.0, -INVERSE_PHI, -PHI],
+    [INVERSE_PHI, PHI, 0.0],
+    [INVERSE_PHI, -PHI, 0.0],
+    [-INVERSE_PHI, PHI, 0.0],
+    [-INVERSE_PHI, -PHI, 0.0],
+    [PHI, 0.0, INVERSE_PHI],
+    [PHI, 0.0, -INVERSE_PHI],
+    [-PHI, 0.0, INVERSE_PHI],
+    [-PHI, 0.0, -INVERSE_PHI],
+];
+const DODECAHEDRON_FACES: [[usize; 5]; 12] = [
+    [1, 12, 0, 16, 17],
+    [2, 16, 0, 8, 10],
+    [4, 8, 0, 12, 14],
+    [11, 9, 1, 17, 3],
+    [14, 12, 1, 9, 5],
+    [17, 16, 2, 13, 3],
+    [15, 13, 2, 10, 6],
+    [7, 11, 3, 13, 15],
+    [19, 18, 4, 14, 5],
+    [10, 8, 4, 18, 6],
+    [7, 19, 5, 9, 11],
+    [7, 15, 6, 18, 19],
+];
+
+const ICOSAHEDRON_POSITIONS: [[f64; 3]; 12] = [
+    [0.0, 1.0, PHI],
+    [0.0, 1.0, -PHI],
+    [0.0, -1.0, PHI],
+    [0.0, -1.0, -PHI],
+    [1.0, PHI, 0.0],
+    [1.0, -PHI, 0.0],
+    [-1.0, PHI, 0.0],
+    [-1.0, -PHI, 0.0],
+    [PHI, 0.0, 1.0],
+    [PHI, 0.0, -1.0],
+    [-PHI, 0.0, 1.0],
+    [-PHI, 0.0, -1.0],
+];
+const ICOSAHEDRON_FACES: [[usize; 3]; 20] = [
+    [8, 0, 2],
+    [2, 0, 10],
+    [6, 0, 4],
+    [4, 0, 8],
+    [10, 0, 6],
+    [3, 1, 9],
+    [11, 1, 3],
+    [4, 1, 6],
+    [9, 1, 4],
+    [6, 1, 11],
+    [5, 2, 7],
+    [8, 2, 5],
+    [7, 2, 10],
+    [7, 3, 5],
+    [5, 3, 9],
+    [11, 3, 7],
+    [9, 4, 8],
+    [8, 5, 9],
+    [10, 6, 11],
+    [11, 7, 10],
+];
+
+#[derive(Clone, Copy)]
+pub struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    radius: Scalar<S>,
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    /// Creates bounds with the given circumradius.
+    ///
+    /// All vertices of a solid lie on the sphere with this radius.
+    pub fn with_radius(radius: Scalar<S>) -> Self {
+        Bounds { radius }
+    }
+
+    pub fn unit_radius() -> Self {
+        Self::with_radius(One::one())
+    }
+}
+
+impl<S> Default for Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Self::unit_radius()
+    }
+}
+
+// Implements generators for a platonic solid from a table of positions and a
+// table of faces. Faces are wound counter-clockwise when viewed from outside
+// of the solid.
+macro_rules! impl_platonic {
+    (solid => $t:ident, arity => $n:expr, positions => $positions:expr, faces => $faces:expr $(,)?) => {
+        impl $t {
+            pub fn new() -> Self {
+                $t {
+                    winding: Winding::default(),
+                }
+            }
+
+            /// Sets the winding of the generated polygons.
+            ///
+            /// See [`Winding`].
+            ///
+            /// [`Winding`]: crate::primitive::generate::Winding
+            pub fn with_winding(self, winding: Winding) -> Self {
+                $t { winding }
+            }
+        }
+
+        impl Default for $t {
+            fn default() -> Self {
+                $t::new()
+            }
+        }
+
+        impl PolygonGenerator for $t {
+            fn polygon_count(&self) -> usize {
+                $faces.len()
+            }
+        }
+
+        impl<S> AttributeGenerator<Position<S>> for $t
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type State = Bounds<S>;
+        }
+
+        impl<S> AttributeVertexGenerator<Position<S>> for $t
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type Output = S;
+
+            fn vertex_count(&self) -> usize {
+                $positions.len()
+            }
+
+            fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+                vertex_with_position_from(state, $positions[index])
+            }
+        }
+
+        impl<S> AttributePolygonGenerator<Position<S>> for $t
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type Output = NGon<[S; $n]>;
+
+            fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+                IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index).map(
+                    |index| {
+                        AttributeVertexGenerator::<Position<S>>::vertex_from(self, state, index)
+                    },
+                )
+            }
+        }
+
+        impl<S> IndexingPolygonGenerator<Position<S>> for $t {
+            type Output = NGon<[usize; $n]>;
+
+            fn indexing_polygon(&self, index: usize) -> Self::Output {
+                assert!(index < self.polygon_count());
+                self.winding.wind(NGon($faces[index]))
+            }
+        }
+
+        impl Generator for $t {}
+    };
+}
+
+/// Tetrahedron.
+///
+/// A tetrahedron has four vertices and four triangular faces.
+#[derive(Clone, Copy)]
+pub struct Tetrahedron {
+    winding: Winding,
+}
+impl_platonic!(
+    solid => Tetrahedron,
+    arity => 3,
+    positions => TETRAHEDRON_POSITIONS,
+    faces => TETRAHEDRON_FACES,
+);
+
+/// Octahedron.
+///
+/// An octahedron has six vertices and eight triangular faces.
+#[derive(Clone, Copy)]
+pub struct Octahedron {
+    winding: Winding,
+}
+impl_platonic!(
+    solid => Octahedron,
+    arity => 3,
+    positions => OCTAHEDRON_POSITIONS,
+    faces => OCTAHEDRON_FACES,
+);
+
+/// Dodecahedron.
+///
+/// A dodecahedron has twenty vertices and twelve pentagonal faces.
+#[derive(Clone, Copy)]
+pub struct Dodecahedron {
+    winding: Winding,
+}
+impl_platonic!(
+    solid => Dodecahedron,
+    arity => 5,
+    positions => DODECAHEDRON_POSITIONS,
+    faces => DODECAHEDRON_FACES,
+);
+
+/// Icosahedron.
+///
+/// An icosahedron has twelve vertices and twenty triangular faces.
+#[derive(Clone, Copy)]
+pub struct Icosahedron {
+    winding: Winding,
+}
+impl_platonic!(
+    solid => Icosahedron,
+    arity => 3,
+    positions => ICOSAHEDRON_POSITIONS,
+    faces => ICOSAHEDRON_FACES,
+);
+
+fn vertex_with_position_from<S>(state: &Bounds<S>, [x, y, z]: [f64; 3]) -> S
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    // Project the position onto the bounding sphere.
+    let norm = ((x * x) + (y * y) + (z * z)).sqrt();
+    S::from_xyz(
+        state.radius * into_scalar::<_, S>(x / norm),
+        state.radius * into_scalar::<_, S>(y / norm),
+        state.radius * into_scalar::<_, S>(z / norm),
+    )
+}
+
+fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::N64;
+    use nalgebra::Point3;
+    use num::Zero;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::{Position, Winding};
+    use crate::primitive::platonic::{Dodecahedron, Icosahedron, Octahedron, Tetrahedron};
+
+    type E3 = Point3<N64>;
+
+    fn assert_closed_with_outward_normals(graph: &MeshGraph<E3>) {
+        assert!(graph.is_closed());
+        for face in graph.faces() {
+            let normal = face.normal().unwrap();
+            let centroid = face.centroid();
+            assert!(normal.dot(&centroid.coords) > Zero::zero());
+        }
+    }
+
+    #[test]
+    fn collect_platonic_solids() {
+        let graph: MeshGraph<E3> = Tetrahedron::new().polygons::<Position<E3>>().collect();
+        assert_eq!(
+            (4, 6, 4),
+            (graph.vertex_count(), graph.edge_count(), graph.face_count())
+        );
+        assert_closed_with_outward_normals(&graph);
+
+        let graph: MeshGraph<E3> = Octahedron::new().polygons::<Position<E3>>().collect();
+        assert_eq!(
+            (6, 12, 8),
+            (graph.vertex_count(), graph.edge_count(), graph.face_count())
+        );
+        assert_closed_with_outward_normals(&graph);
+
+        let graph: MeshGraph<E3> = Dodecahedron::new().polygons::<Position<E3>>().collect();
+        assert_eq!(
+            (20, 30, 12),
+            (graph.vertex_count(), graph.edge_count(), graph.face_count())
+        );
+        assert!(graph.faces().all(|face| face.arity() == 5));
+        assert_closed_with_outward_normals(&graph);
+
+        let graph: MeshGraph<E3> = Icosahedron::new().polygons::<Position<E3>>().collect();
+        assert_eq!(
+            (12, 30, 20),
+            (graph.vertex_count(), graph.edge_count(), graph.face_count())
+        );
+        assert_closed_with_outward_normals(&graph);
+    }
+
+    #[test]
+    fn inward_winding_reverses_polygons() {
+        let graph: MeshGraph<E3> = Icosahedron::new()
+            .with_winding(Winding::Inward)
+            .polygons::<Position<E3>>()
+            .collect();
+        assert!(graph.is_closed());
+        for face in graph.faces() {
+            let normal = face.normal().unwrap();
+            assert!(normal.dot(&face.centroid().coords) < Zero::zero());
+        }
+    }
+}
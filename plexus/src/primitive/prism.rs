@@ -0,0 +1,361 @@
+//! Prism and antiprism primitives.
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of a hexagonal prism:
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::prism::Prism;
+//!
+//! type E3 = Point3<N64>;
+//!
+//! let graph = Prism::new(6)
+//!     .polygons::<Position<E3>>()
+//!     .collect::<MeshGraph<E3>>();
+//! ```
+
+use decorum::Real;
+use num::{NumCast, One, ToPrimitive};
+use std::cmp;
+use theon::adjunct::{FromItems, Map};
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, PolygonGenerator, Position, Winding,
+};
+use crate::primitive::UnboundedPolygon;
+
+#[derive(Clone, Copy)]
+pub struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    radius: Scalar<S>,
+    height: Scalar<S>,
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    /// Creates bounds with the given circumradius of the caps and height
+    /// between them.
+    pub fn new(radius: Scalar<S>, height: Scalar<S>) -> Self {
+        Bounds { radius, height }
+    }
+
+    pub fn unit_radius() -> Self {
+        Self::new(One::one(), One::one())
+    }
+}
+
+impl<S> Default for Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Self::unit_radius()
+    }
+}
+
+/// Prism.
+///
+/// A prism is formed from two regular $n$-gon caps about the $z$-axis joined
+/// by a ring of $n$ quadrilaterals. The upper cap is at $z=h/2$ and the lower
+/// cap is at $z=-h/2$, where $h$ is the height given by [`Bounds`].
+///
+/// Caps are generated as [`UnboundedPolygon`]s.
+///
+/// [`Bounds`]: crate::primitive::prism::Bounds
+/// [`UnboundedPolygon`]: crate::primitive::UnboundedPolygon
+#[derive(Clone, Copy)]
+pub struct Prism {
+    n: usize,
+    winding: Winding,
+}
+
+impl Prism {
+    pub fn new(n: usize) -> Self {
+        Prism {
+            n: cmp::max(3, n),
+            winding: Winding::default(),
+        }
+    }
+
+    /// Sets the winding of the generated polygons.
+    ///
+    /// See [`Winding`].
+    ///
+    /// [`Winding`]: crate::primitive::generate::Winding
+    pub fn with_winding(self, winding: Winding) -> Self {
+        Prism { winding, ..self }
+    }
+}
+
+impl Default for Prism {
+    fn default() -> Self {
+        Prism::new(6)
+    }
+}
+
+impl PolygonGenerator for Prism {
+    fn polygon_count(&self) -> usize {
+        self.n + 2
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Prism
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Prism
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        2 * self.n
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        // Vertices of the lower cap are followed by vertices of the upper cap.
+        let (u, upper) = (index % self.n, index >= self.n);
+        vertex_with_position_from(state, self.n, 2 * u, upper)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Prism
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = UnboundedPolygon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<Position<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Prism {
+    type Output = UnboundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        assert!(index < self.polygon_count());
+        let n = self.n;
+        self.winding.wind(if index < n {
+            let (u, p) = (index, (index + 1) % n);
+            UnboundedPolygon::tetragon(u, p, p + n, u + n)
+        }
+        else {
+            caps(n, index - n)
+        })
+    }
+}
+
+impl Generator for Prism {}
+
+/// Antiprism.
+///
+/// An antiprism is formed from two regular $n$-gon caps about the $z$-axis
+/// joined by a ring of $2n$ triangles. The upper cap is rotated about the
+/// $z$-axis by $\pi/n$ with respect to the lower cap. The upper cap is at
+/// $z=h/2$ and the lower cap is at $z=-h/2$, where $h$ is the height given by
+/// [`Bounds`].
+///
+/// Caps are generated as [`UnboundedPolygon`]s.
+///
+/// [`Bounds`]: crate::primitive::prism::Bounds
+/// [`UnboundedPolygon`]: crate::primitive::UnboundedPolygon
+#[derive(Clone, Copy)]
+pub struct Antiprism {
+    n: usize,
+    winding: Winding,
+}
+
+impl Antiprism {
+    pub fn new(n: usize) -> Self {
+        Antiprism {
+            n: cmp::max(3, n),
+            winding: Winding::default(),
+        }
+    }
+
+    /// Sets the winding of the generated polygons.
+    ///
+    /// See [`Winding`].
+    ///
+    /// [`Winding`]: crate::primitive::generate::Winding
+    pub fn with_winding(self, winding: Winding) -> Self {
+        Antiprism { winding, ..self }
+    }
+}
+
+impl Default for Antiprism {
+    fn default() -> Self {
+        Antiprism::new(6)
+    }
+}
+
+impl PolygonGenerator for Antiprism {
+    fn polygon_count(&self) -> usize {
+        (2 * self.n) + 2
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Antiprism
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Antiprism
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        2 * self.n
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        // Vertices of the lower cap are followed by vertices of the upper cap,
+        // which are offset by half of a segment.
+        let (u, upper) = (index % self.n, index >= self.n);
+        let u = (2 * u) + if upper { 1 } else { 0 };
+        vertex_with_position_from(state, self.n, u, upper)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Antiprism
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = UnboundedPolygon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<Position<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Antiprism {
+    type Output = UnboundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        assert!(index < self.polygon_count());
+        let n = self.n;
+        self.winding.wind(if index < 2 * n {
+            // Triangles alternate between pointing up (with a base on the
+            // lower cap) and pointing down (with a base on the upper cap).
+            let (u, p) = (index / 2, ((index / 2) + 1) % n);
+            if index % 2 == 0 {
+                UnboundedPolygon::trigon(u, p, u + n)
+            }
+            else {
+                UnboundedPolygon::trigon(u + n, p, p + n)
+            }
+        }
+        else {
+            caps(n, index - (2 * n))
+        })
+    }
+}
+
+impl Generator for Antiprism {}
+
+// Gets the upper (`0`) or lower (`1`) cap of a prism or antiprism. Vertices of
+// the lower cap are followed by vertices of the upper cap.
+fn caps(n: usize, index: usize) -> UnboundedPolygon<usize> {
+    let polygon = if index == 0 {
+        UnboundedPolygon::from_items(n..(2 * n))
+    }
+    else {
+        UnboundedPolygon::from_items((0..n).rev())
+    };
+    polygon.expect("polygon arity")
+}
+
+// Computes the position of a vertex at the given angle in half segments about
+// the $z$-axis.
+fn vertex_with_position_from<S>(state: &Bounds<S>, n: usize, u: usize, upper: bool) -> S
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let one = Scalar::<S>::one();
+    let half = state.height / (one + one);
+    let u = (into_scalar::<_, S>(u) / into_scalar::<_, S>(2 * n)) * Real::PI * (one + one);
+    S::from_xyz(
+        state.radius * u.cos(),
+        state.radius * u.sin(),
+        if upper { half } else { -half },
+    )
+}
+
+fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::N64;
+    use nalgebra::Point3;
+    use num::Zero;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::prism::{Antiprism, Prism};
+
+    type E3 = Point3<N64>;
+
+    #[test]
+    fn collect_prism() {
+        let graph: MeshGraph<E3> = Prism::new(5).polygons::<Position<E3>>().collect();
+
+        assert_eq!(10, graph.vertex_count());
+        assert_eq!(15, graph.edge_count());
+        assert_eq!(7, graph.face_count());
+        assert!(graph.is_closed());
+        assert_eq!(2, graph.faces().filter(|face| face.arity() == 5).count());
+        for face in graph.faces() {
+            let normal = face.normal().unwrap();
+            assert!(normal.dot(&face.centroid().coords) > Zero::zero());
+        }
+    }
+
+    #[test]
+    fn collect_antiprism() {
+        let graph: MeshGraph<E3> = Antiprism::new(5).polygons::<Position<E3>>().collect();
+
+        assert_eq!(10, graph.vertex_count());
+        assert_eq!(20, graph.edge_count());
+        assert_eq!(12, graph.face_count());
+        assert!(graph.is_closed());
+        assert_eq!(10, graph.faces().filter(|face| face.arity() == 3).count());
+        for face in graph.faces() {
+            let normal = face.normal().unwrap();
+            assert!(normal.dot(&face.centroid().coords) > Zero::zero());
+        }
+    }
+}
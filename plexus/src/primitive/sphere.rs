@@ -35,7 +35,7 @@ use typenum::U3;
 
 use crate::primitive::generate::{
     AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
-    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position, Winding,
 };
 use crate::primitive::{BoundedPolygon, Tetragon, Trigon};
 
@@ -81,6 +81,7 @@ where
 pub struct UvSphere {
     nu: usize, // Meridians.
     nv: usize, // Parallels.
+    winding: Winding,
 }
 
 impl UvSphere {
@@ -88,9 +89,19 @@ impl UvSphere {
         UvSphere {
             nu: cmp::max(3, nu),
             nv: cmp::max(2, nv),
+            winding: Winding::default(),
         }
     }
 
+    /// Sets the winding of the generated polygons.
+    ///
+    /// See [`Winding`].
+    ///
+    /// [`Winding`]: crate::primitive::generate::Winding
+    pub fn with_winding(self, winding: Winding) -> Self {
+        UvSphere { winding, ..self }
+    }
+
     fn vertex_with_position_from<S>(
         &self,
         state: &<Self as AttributeGenerator<Position<S>>>::State,
@@ -161,7 +172,9 @@ where
     fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
         let position =
             AttributeVertexGenerator::<Position<S>>::vertex_from(self, &Default::default(), index);
-        Unit::try_from_inner(position.into_coordinates()).expect("non-zero vector")
+        self.winding.orient::<S>(
+            Unit::try_from_inner(position.into_coordinates()).expect("non-zero vector"),
+        )
     }
 }
 
@@ -174,7 +187,9 @@ where
     fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
         AttributePolygonGenerator::<Position<S>>::polygon_from(self, &Default::default(), index)
             .map(|position| {
-                Unit::try_from_inner(position.into_coordinates()).expect("non-zero vector")
+                self.winding.orient::<S>(
+                    Unit::try_from_inner(position.into_coordinates()).expect("non-zero vector"),
+                )
             })
     }
 }
@@ -247,7 +262,7 @@ where
         // lower bound of `(u, v)` is always used, so compute that in advance
         // (`lower`). Emit triangles at the poles, otherwise quadrilaterals.
         let lower = self.vertex_with_position_from(state, u, v);
-        if v == 0 {
+        let polygon: BoundedPolygon<_> = if v == 0 {
             Trigon::new(
                 lower,
                 self.vertex_with_position_from(state, u, q),
@@ -273,7 +288,8 @@ where
                 self.vertex_with_position_from(state, p, v),
             )
             .into()
-        }
+        };
+        self.winding.wind(polygon)
     }
 }
 
@@ -286,7 +302,7 @@ impl<S> IndexingPolygonGenerator<Position<S>> for UvSphere {
 
         let low = self.index_for_position(u, v);
         let high = self.index_for_position(p, q);
-        if v == 0 {
+        let polygon: BoundedPolygon<_> = if v == 0 {
             Trigon::new(low, self.index_for_position(u, q), high).into()
         }
         else if v == self.nv - 1 {
@@ -300,7 +316,8 @@ impl<S> IndexingPolygonGenerator<Position<S>> for UvSphere {
                 self.index_for_position(p, v),
             )
             .into()
-        }
+        };
+        self.winding.wind(polygon)
     }
 }
 
@@ -320,6 +337,7 @@ mod tests {
     use std::collections::BTreeSet;
     use std::iter::FromIterator;
 
+    use crate::index::Flat3;
     use crate::prelude::*;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
@@ -359,4 +377,15 @@ mod tests {
             .len()
         )
     }
+
+    #[test]
+    fn position_index_buffer() {
+        let sphere = UvSphere::new(3, 2);
+        let positions = sphere.vertices_with_position::<E3>();
+        let indices = sphere.indices_for_position::<Flat3>();
+
+        assert_eq!(5, positions.len());
+        assert_eq!(18, indices.len()); // 6 triangles, 18 indices.
+        assert!(indices.iter().all(|index| *index < positions.len()));
+    }
 }
@@ -22,6 +22,14 @@ pub trait Transact<T = ()>: Sized {
     }
 
     fn abort(self) {}
+
+    /// Aborts the transaction and recovers its input as mutated so far.
+    ///
+    /// By default, nothing is recovered and this is equivalent to `abort`.
+    fn abort_into(self) -> Option<T> {
+        self.abort();
+        None
+    }
 }
 
 pub trait TransactFrom<T>: From<T> + Transact<T> {}
@@ -108,8 +116,10 @@ where
     }
 
     fn drain_and_abort(&mut self) {
-        let (_, inner) = self.drain();
-        inner.abort();
+        let (target, inner) = self.drain();
+        if let Some(mutant) = inner.abort_into() {
+            *target = mutant;
+        }
     }
 }
 